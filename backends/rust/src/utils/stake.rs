@@ -1,10 +1,15 @@
 use crate::errors::AccessError;
 use {
-    access_protocol::state::{StakeAccount, StakePool},
+    access_protocol::state::{AccessDelegation, StakeAccount, StakePool, Tag},
     borsh::BorshDeserialize,
     dotenv,
     lazy_static::lazy_static,
-    solana_client::rpc_client::RpcClient,
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_client::RpcClient,
+        rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    },
     solana_program::pubkey::Pubkey,
     std::cmp::min,
     std::str::FromStr,
@@ -24,13 +29,29 @@ pub async fn check_stake_account(staker: Pubkey) -> Result<(), AccessError> {
     println!("{}", stake_key);
 
     let connection = RpcClient::new(ENDPOINT.to_owned());
+
+    // Members of an access delegation do not need a stake account of their own
+    match check_stake_amount(&connection, &stake_key) {
+        Err(AccessError::RpcError) | Err(AccessError::NotEnoughStake) => {
+            check_delegated_access(&connection, &staker)
+        }
+        result => result,
+    }
+}
+
+fn check_stake_amount(connection: &RpcClient, stake_key: &Pubkey) -> Result<(), AccessError> {
     let account_data = connection
-        .get_account_data(&stake_key)
+        .get_account_data(stake_key)
         .map_err(|_| AccessError::RpcError)?;
 
     let stake_account =
         StakeAccount::deserialize(&mut &account_data[..]).map_err(|_| AccessError::BorshError)?;
 
+    // Closed and frozen stake accounts do not give access
+    if stake_account.tag != Tag::StakeAccount || stake_account.stake_pool != *STAKE_POOL {
+        return Err(AccessError::NotEnoughStake);
+    }
+
     let stake_pool_data = connection
         .get_account_data(&stake_account.stake_pool)
         .map_err(|_| AccessError::RpcError)?;
@@ -47,3 +68,45 @@ pub async fn check_stake_account(staker: Pubkey) -> Result<(), AccessError> {
 
     Ok(())
 }
+
+fn check_delegated_access(connection: &RpcClient, member: &Pubkey) -> Result<(), AccessError> {
+    // Access delegations start with their tag followed by the stake pool key
+    let tag_filter = RpcFilterType::Memcmp(Memcmp {
+        offset: 0,
+        bytes: MemcmpEncodedBytes::Bytes(vec![Tag::AccessDelegation as u8]),
+        encoding: None,
+    });
+    let stake_pool_filter = RpcFilterType::Memcmp(Memcmp {
+        offset: 1,
+        bytes: MemcmpEncodedBytes::Bytes(STAKE_POOL.to_bytes().to_vec()),
+        encoding: None,
+    });
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![tag_filter, stake_pool_filter]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    let delegations = connection
+        .get_program_accounts_with_config(&PROGRAM_ID, config)
+        .map_err(|_| AccessError::RpcError)?;
+
+    for (_, account) in delegations {
+        let delegation = match AccessDelegation::deserialize(&mut &account.data[..]) {
+            Ok(delegation) => delegation,
+            Err(_) => continue,
+        };
+        if !delegation.is_member(member) {
+            continue;
+        }
+        if check_stake_amount(connection, &delegation.stake_account).is_ok() {
+            return Ok(());
+        }
+    }
+
+    Err(AccessError::NotEnoughStake)
+}
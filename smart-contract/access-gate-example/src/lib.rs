@@ -0,0 +1,42 @@
+//! Sample program only executing its instruction for ACCESS subscribers of a stake pool
+//!
+//! | Index | Writable | Signer | Description                  |
+//! | -------------------------------------------------------- |
+//! | 0     | ❌        | ✅      | The subscriber wallet        |
+//! | 1     | ❌        | ❌      | The stake pool account       |
+//! | 2     | ❌        | ❌      | The stake account            |
+//! | 3     | ❌        | ❌      | Optional bond account        |
+use access_protocol::cpi::access::assert_has_access;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[cfg(not(feature = "no-entrypoint"))]
+solana_program::entrypoint!(process_instruction);
+
+/// Checks that the signer has access to the stake pool
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let subscriber = next_account_info(accounts_iter)?;
+    let stake_pool = next_account_info(accounts_iter)?;
+    let stake_account = next_account_info(accounts_iter)?;
+    let bond_account = next_account_info(accounts_iter).ok();
+
+    if !subscriber.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    assert_has_access(subscriber.key, stake_pool, stake_account, bond_account)?;
+
+    msg!("Access granted to {}", subscriber.key);
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use access_protocol::state::{
+    StakeAccount, StakePool, StakePoolHeader, Tag, STAKE_BUFFER_LEN,
+};
+use access_gate_example::process_instruction;
+use borsh::BorshSerialize;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{processor, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+const POOL_MINIMUM: u64 = 10_000;
+
+// Adds an active stake pool and a stake account of `staker` holding `stake_amount` tokens
+fn add_subscription(program_test: &mut ProgramTest, staker: &Pubkey, stake_amount: u64) -> (Pubkey, Pubkey) {
+    let pool_owner = Pubkey::new_unique();
+    let (stake_pool_key, nonce) = StakePool::find_key(&pool_owner, &access_protocol::ID);
+    let mut header = StakePoolHeader::new(pool_owner, nonce, Pubkey::new_unique(), POOL_MINIMUM).unwrap();
+    header.tag = Tag::StakePool as u8;
+    header.total_staked = stake_amount;
+    let mut pool_data = bytemuck::bytes_of(&header).to_vec();
+    pool_data.extend(vec![0; STAKE_BUFFER_LEN as usize * 32]);
+    program_test.add_account(
+        stake_pool_key,
+        Account {
+            lamports: 1_000_000_000,
+            data: pool_data,
+            owner: access_protocol::ID,
+            ..Account::default()
+        },
+    );
+
+    let (stake_account_key, _) = StakeAccount::find_key(staker, &stake_pool_key, &access_protocol::ID);
+    let mut stake_account = StakeAccount::new(*staker, stake_pool_key, POOL_MINIMUM);
+    stake_account.stake_amount = stake_amount;
+    program_test.add_account(
+        stake_account_key,
+        Account {
+            lamports: 1_000_000_000,
+            data: stake_account.try_to_vec().unwrap(),
+            owner: access_protocol::ID,
+            ..Account::default()
+        },
+    );
+
+    (stake_pool_key, stake_account_key)
+}
+
+async fn call_gate(stake_amount: u64) -> bool {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = ProgramTest::new(
+        "access_gate_example",
+        program_id,
+        processor!(process_instruction),
+    );
+    let staker = Keypair::new();
+    let (stake_pool_key, stake_account_key) = add_subscription(&mut program_test, &staker.pubkey(), stake_amount);
+
+    let mut ctx = program_test.start_with_context().await;
+    let ix = Instruction::new_with_bytes(
+        program_id,
+        &[],
+        vec![
+            AccountMeta::new_readonly(staker.pubkey(), true),
+            AccountMeta::new_readonly(stake_pool_key, false),
+            AccountMeta::new_readonly(stake_account_key, false),
+        ],
+    );
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&ctx.payer.pubkey()));
+    transaction.partial_sign(&[&ctx.payer, &staker], ctx.last_blockhash);
+    ctx.banks_client.process_transaction(transaction).await.is_ok()
+}
+
+#[tokio::test]
+async fn subscriber_has_access() {
+    assert!(call_gate(POOL_MINIMUM).await);
+}
+
+#[tokio::test]
+async fn staker_under_the_minimum_has_no_access() {
+    assert!(!call_gate(POOL_MINIMUM - 1).await);
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum ProgramError {
+    Usage,
+    InvalidProgramId,
+    Rpc,
+    InvalidAccountData,
+}
+
+impl Display for ProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProgramError::Usage => write!(f, "Usage: access-admin-log <rpc_url> <program_id>"),
+            ProgramError::InvalidProgramId => write!(f, "Invalid program id"),
+            ProgramError::Rpc => write!(f, "RPC error"),
+            ProgramError::InvalidAccountData => write!(f, "The account is not an admin log"),
+        }
+    }
+}
+
+impl std::error::Error for ProgramError {}
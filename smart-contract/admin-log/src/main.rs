@@ -0,0 +1,80 @@
+mod error;
+
+use std::str::FromStr;
+
+use access_protocol::state::{AdminAction, AdminLog, AdminLogEntry, Tag};
+use borsh::BorshDeserialize;
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+
+use crate::error::ProgramError;
+
+fn to_hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn action_name(action: AdminAction) -> &'static str {
+    match action {
+        AdminAction::Freeze => "freeze",
+        AdminAction::Unfreeze => "unfreeze",
+        AdminAction::Mint => "mint",
+        AdminAction::ChangeInflation => "change_inflation",
+        AdminAction::ChangeCentralStateAuthority => "change_central_state_authority",
+        AdminAction::ActivateStakePool => "activate_stake_pool",
+        AdminAction::EditMetadata => "edit_metadata",
+    }
+}
+
+fn render(entry: &AdminLogEntry) -> String {
+    format!(
+        "{:>12}  {:<32}  {:<44}  {}",
+        entry.slot,
+        action_name(entry.action),
+        entry.target.to_string(),
+        to_hex(&entry.params_hash)
+    )
+}
+
+fn process() -> Result<(), ProgramError> {
+    let args = std::env::args().collect::<Vec<_>>();
+    let (rpc_url, program_id) = match &args[..] {
+        [_, rpc_url, program_id] => (rpc_url, program_id),
+        _ => return Err(ProgramError::Usage),
+    };
+    let program_id = Pubkey::from_str(program_id).map_err(|_| ProgramError::InvalidProgramId)?;
+
+    let (admin_log_key, _) = AdminLog::find_key(&program_id);
+    let connection = RpcClient::new(rpc_url.as_str());
+    let acc = connection
+        .get_account(&admin_log_key)
+        .map_err(|_| ProgramError::Rpc)?;
+    if acc.data.first() != Some(&(Tag::AdminLog as u8)) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let log =
+        AdminLog::deserialize(&mut &acc.data[..]).map_err(|_| ProgramError::InvalidAccountData)?;
+
+    let entries = log.entries_in_order();
+    println!("Admin log {}", admin_log_key);
+    println!(
+        "{} actions recorded, showing the last {}",
+        log.total_entries,
+        entries.len()
+    );
+    println!(
+        "{:>12}  {:<32}  {:<44}  {}",
+        "Slot", "Action", "Target", "Parameters hash"
+    );
+    for entry in entries {
+        println!("{}", render(entry));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = process() {
+        println!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
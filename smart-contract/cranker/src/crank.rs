@@ -7,19 +7,24 @@ use {
 };
 
 use crate::{
-    settings::PAYER,
+    settings::{BOUNTY_DESTINATION, PAYER},
     utils::{no_op_filter, retry},
 };
 use crate::settings::PROGRAM_ID;
 
-pub async fn crank_pool(stake_pool: Pubkey, central_state: Pubkey) {
+pub async fn crank_pool(stake_pool: Pubkey, central_state: Pubkey, mint: Pubkey) {
     let connection = RpcClient::new(crate::settings::RPC_URL.as_str());
 
+    // The bounty is only claimed when a destination is configured
+    let bounty_destination = BOUNTY_DESTINATION.as_ref();
     let ix = crank(
         *PROGRAM_ID,
         crank::Accounts {
             stake_pool: &stake_pool,
             central_state: &central_state,
+            bounty_destination,
+            mint: bounty_destination.map(|_| &mint),
+            spl_token_program: bounty_destination.map(|_| &spl_token::ID),
         },
         crank::Params {},
     );
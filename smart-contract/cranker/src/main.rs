@@ -33,8 +33,9 @@ fn process() -> Result<(), error::ProgramError> {
     let all_pools = pools::get_all_pools(connection, central_state.creation_time as u64)?;
     let mut join_handles = Vec::with_capacity(all_pools.len());
 
+    let mint = central_state.token_mint;
     for pool in all_pools {
-        let handle = task::spawn(async move { crank::crank_pool(pool, central_key).await });
+        let handle = task::spawn(async move { crank::crank_pool(pool, central_key, mint).await });
         join_handles.push(handle)
     }
 
@@ -4,6 +4,8 @@ lazy_static! {
     pub static ref RPC_URL: String = dotenv::var("RPC_URL").unwrap();
     pub static ref PAYER: Keypair = Keypair::from_base58_string(&dotenv::var("PAYER").unwrap());
     pub static ref PROGRAM_ID: Pubkey = Pubkey::from_str(&dotenv::var("PROGRAM_ID").unwrap()).unwrap();
+    // Optional ACCESS token account receiving the crank bounties
+    pub static ref BOUNTY_DESTINATION: Option<Pubkey> = dotenv::var("BOUNTY_DESTINATION").ok().map(|k| Pubkey::from_str(&k).unwrap());
 }
 
 pub const CYCLE_INTERVAL: u64 = 60 * 60;
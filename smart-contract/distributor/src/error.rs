@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub enum DistributorError {
+    Usage,
+    Io(std::io::Error),
+    InvalidLine(usize),
+    Empty,
+    Overflow,
+}
+
+impl Display for DistributorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistributorError::Usage => {
+                write!(f, "Usage: access-distributor <input.csv> <output.json>")
+            }
+            DistributorError::Io(e) => write!(f, "IO error: {}", e),
+            DistributorError::InvalidLine(l) => {
+                write!(f, "Invalid CSV line {}, expected <recipient>,<amount>", l)
+            }
+            DistributorError::Empty => write!(f, "The CSV file has no recipient"),
+            DistributorError::Overflow => write!(f, "Overflow"),
+        }
+    }
+}
+
+impl std::error::Error for DistributorError {}
+
+impl From<std::io::Error> for DistributorError {
+    fn from(e: std::io::Error) -> Self {
+        DistributorError::Io(e)
+    }
+}
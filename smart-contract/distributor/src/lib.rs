@@ -0,0 +1,3 @@
+//! Merkle trees of the distributions, built off-chain and verified by the `claim_distribution` instruction
+pub mod error;
+pub mod tree;
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use access_protocol::state::Distributor;
+use solana_program::pubkey::Pubkey;
+
+use access_distributor::error::DistributorError;
+use access_distributor::tree::MerkleTree;
+
+struct Claim {
+    recipient: Pubkey,
+    amount: u64,
+}
+
+fn to_hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Each line of the CSV file is `<recipient>,<amount>`, an optional header line is skipped
+fn parse_csv(content: &str) -> Result<Vec<Claim>, DistributorError> {
+    let mut claims = vec![];
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut fields = line.split(',').map(str::trim);
+        let (recipient, amount) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(r), Some(a), None) => (r, a),
+            _ => return Err(DistributorError::InvalidLine(i + 1)),
+        };
+        match (Pubkey::from_str(recipient), amount.parse::<u64>()) {
+            (Ok(recipient), Ok(amount)) => claims.push(Claim { recipient, amount }),
+            _ if i == 0 => continue,
+            _ => return Err(DistributorError::InvalidLine(i + 1)),
+        }
+    }
+    if claims.is_empty() {
+        return Err(DistributorError::Empty);
+    }
+    Ok(claims)
+}
+
+fn process() -> Result<(), DistributorError> {
+    let args = std::env::args().collect::<Vec<_>>();
+    let (input, output) = match &args[..] {
+        [_, input, output] => (input, output),
+        _ => return Err(DistributorError::Usage),
+    };
+
+    let claims = parse_csv(&std::fs::read_to_string(input)?)?;
+    let leaves = claims
+        .iter()
+        .enumerate()
+        .map(|(i, c)| Distributor::leaf(i as u64, &c.recipient, c.amount))
+        .collect::<Vec<_>>();
+    let max_total_claim = claims
+        .iter()
+        .try_fold(0u64, |acc, c| acc.checked_add(c.amount))
+        .ok_or(DistributorError::Overflow)?;
+    let tree = MerkleTree::new(leaves);
+    let root = tree.root();
+
+    let mut entries = Vec::with_capacity(claims.len());
+    for (i, c) in claims.iter().enumerate() {
+        let proof = tree
+            .proof(i)
+            .iter()
+            .map(|p| format!("\"{}\"", to_hex(p)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        entries.push(format!(
+            "    {{\"index\": {}, \"recipient\": \"{}\", \"amount\": {}, \"proof\": [{}]}}",
+            i, c.recipient, c.amount, proof
+        ));
+    }
+
+    let json = format!(
+        "{{\n  \"merkle_root\": \"{}\",\n  \"max_total_claim\": {},\n  \"num_nodes\": {},\n  \"claims\": [\n{}\n  ]\n}}\n",
+        to_hex(&root),
+        max_total_claim,
+        claims.len(),
+        entries.join(",\n")
+    );
+    std::fs::write(output, json)?;
+
+    println!("Merkle root: {}", to_hex(&root));
+    println!("Recipients: {}", claims.len());
+    println!("Total amount: {}", max_total_claim);
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = process() {
+        println!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
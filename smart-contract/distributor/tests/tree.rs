@@ -0,0 +1,42 @@
+use access_distributor::tree::MerkleTree;
+use access_protocol::state::Distributor;
+use access_protocol::verify_merkle_proof;
+use solana_program::pubkey::Pubkey;
+
+// Leaves of a distribution of `nb_recipients` recipients, as built from the CSV file
+fn leaves(nb_recipients: u64) -> Vec<[u8; 32]> {
+    (0..nb_recipients)
+        .map(|i| Distributor::leaf(i, &Pubkey::new_unique(), 1_000 * (i + 1)))
+        .collect()
+}
+
+#[test]
+fn proofs_are_accepted_by_the_program() {
+    // Odd sizes carry a node without a sibling to the next level
+    for nb_recipients in 1..=9 {
+        let leaves = leaves(nb_recipients);
+        let tree = MerkleTree::new(leaves.clone());
+        let root = tree.root();
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(verify_merkle_proof(&tree.proof(i), &root, *leaf));
+        }
+    }
+}
+
+#[test]
+fn proofs_are_bound_to_their_leaf() {
+    let leaves = leaves(5);
+    let tree = MerkleTree::new(leaves.clone());
+    let root = tree.root();
+    // The proof of a leaf does not prove its neighbour
+    assert!(!verify_merkle_proof(&tree.proof(0), &root, leaves[1]));
+    // A leaf with another amount is rejected
+    let recipient = Pubkey::new_unique();
+    let tree = MerkleTree::new(vec![
+        Distributor::leaf(0, &recipient, 1_000),
+        Distributor::leaf(1, &Pubkey::new_unique(), 2_000),
+    ]);
+    let forged_leaf = Distributor::leaf(0, &recipient, 2_000);
+    let root = tree.root();
+    assert!(!verify_merkle_proof(&tree.proof(0), &root, forged_leaf));
+}
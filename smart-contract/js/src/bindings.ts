@@ -21,9 +21,16 @@ import {
   adminFreezeInstruction,
   changePoolMultiplierInstruction,
   changeCentralStateAuthorityInstruction,
+  VestingSchedule,
 } from "./raw_instructions.js";
 import { Connection, PublicKey, SystemProgram } from "@solana/web3.js";
-import { CentralState, StakePool, BondAccount, StakeAccount } from "./state.js";
+import {
+  CentralState,
+  StakePool,
+  BondAccount,
+  StakeAccount,
+  FreezeRecord,
+} from "./state.js";
 import BN from "bn.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -33,6 +40,30 @@ import {
 } from "@solana/spl-token";
 import { getBondAccounts } from "./secondary_bindings.js";
 
+/**
+ * Returns the (reward campaign, campaign position) pairs of a stake account
+ * @param stakeAccount The key of the stake account
+ * @param campaigns The reward campaigns the stake account is registered in
+ * @param programId The ACCESS program ID
+ * @returns
+ */
+const getCampaignPositions = (
+  stakeAccount: PublicKey,
+  campaigns: PublicKey[],
+  programId: PublicKey
+) =>
+  campaigns.flatMap((campaign) => [
+    campaign,
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign_position"),
+        campaign.toBuffer(),
+        stakeAccount.toBuffer(),
+      ],
+      programId
+    )[0],
+  ]);
+
 /**
  * This function can be used to update the inflation schedule of the central state
  * @param connection The Solana RPC connection
@@ -50,7 +81,12 @@ export const changeInflation = async (
 
   const ix = new changeInflationInstruction({
     dailyInflation: newInflation,
-  }).getInstruction(programId, centralKey, centralState.authority);
+  }).getInstruction(
+    programId,
+    centralKey,
+    centralState.authority,
+    centralState.getAdminLog()
+  );
 
   return ix;
 };
@@ -97,7 +133,8 @@ export const activateStakePool = async (
     programId,
     centralState.authority,
     stakePoolKey,
-    centralKey
+    centralKey,
+    centralState.getAdminLog()
   );
 
   return ix;
@@ -163,7 +200,7 @@ export const claimBond = async (
   const [centralKey] = await CentralState.getKey(programId);
   const centralState = await CentralState.retrieve(connection, centralKey);
 
-  const ix = new claimBondInstruction().getInstruction(
+  const ix = new claimBondInstruction({ hasQuoteMint: 1 }).getInstruction(
     programId,
     bondAccount,
     buyer,
@@ -173,6 +210,8 @@ export const claimBond = async (
     centralState.tokenMint,
     stakePool.vault,
     centralKey,
+    TOKEN_PROGRAM_ID,
+    bond.quoteMint,
     TOKEN_PROGRAM_ID
   );
 
@@ -338,6 +377,8 @@ export const crank = async (
  * @param stakePool The stake pool key
  * @param sellerIndex The seller index in the array of authorized sellers
  * @param programId The ACCESS program ID
+ * @param vesting The optional cliff and linear vesting schedule, replaces the periodic unlocks
+ * @param expiryDate The date after which the bond can be cancelled, 0 if the bond never expires
  * @returns
  */
 export const createBond = async (
@@ -352,7 +393,9 @@ export const createBond = async (
   unlockAmount: number,
   stakePool: PublicKey,
   sellerIndex: number,
-  programId: PublicKey
+  programId: PublicKey,
+  vesting: VestingSchedule | null = null,
+  expiryDate = 0
 ) => {
   const [bondAccount] = await BondAccount.getKey(
     programId,
@@ -370,6 +413,8 @@ export const createBond = async (
     unlockPeriod: new BN(unlockPeriod),
     unlockAmount: new BN(unlockAmount),
     sellerIndex: new BN(sellerIndex),
+    vesting,
+    expiryDate: new BN(expiryDate),
   }).getInstruction(
     programId,
     seller,
@@ -532,6 +577,7 @@ export const signBond = async (
  * @param sourceToken The token account from which the ACCESS tokens are sent to the stake account
  * @param amount The raw amount of tokens to stake
  * @param programId The ACCESS program ID
+ * @param campaigns The reward campaigns the stake account is registered in
  * @returns
  */
 export const stake = async (
@@ -539,7 +585,8 @@ export const stake = async (
   stakeAccount: PublicKey,
   sourceToken: PublicKey,
   amount: number,
-  programId: PublicKey
+  programId: PublicKey,
+  campaigns: PublicKey[] = []
 ) => {
   const stake = await StakeAccount.retrieve(connection, stakeAccount);
   const stakePool = await StakePool.retrieve(connection, stake.stakePool);
@@ -567,6 +614,10 @@ export const stake = async (
 
   const ix = new stakeInstruction({
     amount: new BN(amount),
+    hasBondAccount: bondAccountKey ? 1 : 0,
+    hasMint: 1,
+    hasPoolMembership: 0,
+    hasReferrerToken: 0,
   }).getInstruction(
     programId,
     centralKey,
@@ -577,7 +628,11 @@ export const stake = async (
     TOKEN_PROGRAM_ID,
     stakePool.vault,
     feesAta,
-    bondAccountKey
+    bondAccountKey,
+    centralState.tokenMint,
+    undefined,
+    undefined,
+    getCampaignPositions(stakeAccount, campaigns, programId)
   );
 
   return ix;
@@ -624,6 +679,7 @@ export const unlockBondTokens = async (
  * @param destinationToken The token account receiving the ACCESS tokens
  * @param amount The amount of tokens to unstake
  * @param programId The ACCESS program ID
+ * @param campaigns The reward campaigns the stake account is registered in
  * @returns
  */
 export const unstake = async (
@@ -631,11 +687,13 @@ export const unstake = async (
   stakeAccount: PublicKey,
   destinationToken: PublicKey,
   amount: number,
-  programId: PublicKey
+  programId: PublicKey,
+  campaigns: PublicKey[] = []
 ) => {
   const stake = await StakeAccount.retrieve(connection, stakeAccount);
   const stakePool = await StakePool.retrieve(connection, stake.stakePool);
   const [centralKey] = await CentralState.getKey(programId);
+  const centralState = await CentralState.retrieve(connection, centralKey);
   const bondAccounts = await getBondAccounts(
     connection,
     stake.owner,
@@ -647,8 +705,16 @@ export const unstake = async (
       stake.stakePool.toBase58()
   )?.pubkey;
 
+  // The receipt is burnt if the stake goes below the minimum
+  const [receiptMint] = StakeAccount.getReceiptMintKey(programId, stakeAccount);
+  const receiptToken = await getAssociatedTokenAddress(receiptMint, stake.owner);
+  const hasReceipt = stake.hasReceipt !== 0;
+
   const ix = new unstakeInstruction({
     amount: new BN(amount),
+    hasBondAccount: bondAccountKey ? 1 : 0,
+    hasMint: 1,
+    hasReceipt: hasReceipt ? 1 : 0,
   }).getInstruction(
     programId,
     centralKey,
@@ -658,7 +724,12 @@ export const unstake = async (
     destinationToken,
     TOKEN_PROGRAM_ID,
     stakePool.vault,
-    bondAccountKey
+    bondAccountKey,
+    centralState.tokenMint,
+    hasReceipt ? receiptMint : undefined,
+    hasReceipt ? receiptToken : undefined,
+    hasReceipt ? TOKEN_PROGRAM_ID : undefined,
+    getCampaignPositions(stakeAccount, campaigns, programId)
   );
 
   return ix;
@@ -689,32 +760,56 @@ export const adminMint = async (
     centralState.tokenMint,
     destinationToken,
     centralKey,
-    TOKEN_PROGRAM_ID
+    TOKEN_PROGRAM_ID,
+    centralState.getAdminLog()
   );
 
   return ix;
 };
 
 /**
- * This instruction can be used by the central state authority to freeze or unfreeze an account
+ * This instruction can be used by the central state authority to freeze or unfreeze accounts
  * @param connection The Solana RPC connection
- * @param accountToFreeze The account to freeze
+ * @param accountToFreeze The account to freeze or unfreeze
  * @param programId The ACCESS program ID
+ * @param reason The reason code of the freeze
+ * @param expiry The timestamp after which anyone can unfreeze the accounts, 0 if the freeze never expires
+ * @param otherAccounts Other accounts to freeze or unfreeze in the same instruction
  * @returns
  */
 export const adminFreeze = async (
   connection: Connection,
   accountToFreeze: PublicKey,
-  programId: PublicKey
+  programId: PublicKey,
+  reason = 0,
+  expiry = 0,
+  otherAccounts: PublicKey[] = []
 ) => {
   const [centralKey] = await CentralState.getKey(programId);
   const centralState = await CentralState.retrieve(connection, centralKey);
 
-  const ix = new adminFreezeInstruction().getInstruction(
+  const [freezeRecord] = FreezeRecord.getKey(programId, accountToFreeze);
+  const accounts: PublicKey[] = [];
+  for (const account of otherAccounts) {
+    const [otherFreezeRecord] = FreezeRecord.getKey(programId, account);
+    accounts.push(account, otherFreezeRecord);
+  }
+
+  const adminLog = centralState.getAdminLog();
+  const ix = new adminFreezeInstruction({
+    reason,
+    expiry: new BN(expiry),
+    hasAdminLog: adminLog ? 1 : 0,
+  }).getInstruction(
     programId,
     centralState.authority,
     accountToFreeze,
-    centralKey
+    centralKey,
+    adminLog,
+    SystemProgram.programId,
+    centralState.authority,
+    freezeRecord,
+    accounts
   );
 
   return ix;
@@ -760,7 +855,12 @@ export const changeCentralStateAuthority = async (
 
   const ix = new changeCentralStateAuthorityInstruction({
     newAuthority: newAuthority.toBytes(),
-  }).getInstruction(programId, centralKey, centralState.authority);
+  }).getInstruction(
+    programId,
+    centralKey,
+    centralState.authority,
+    centralState.getAdminLog()
+  );
 
   return ix;
 };
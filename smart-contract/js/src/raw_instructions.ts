@@ -8,6 +8,16 @@ export interface AccountKey {
   isSigner: boolean;
   isWritable: boolean;
 }
+export class VestingSchedule {
+  cliffDate: BN;
+  cliffAmount: BN;
+  linearEndDate: BN;
+  constructor(obj: { cliffDate: BN; cliffAmount: BN; linearEndDate: BN }) {
+    this.cliffDate = obj.cliffDate;
+    this.cliffAmount = obj.cliffAmount;
+    this.linearEndDate = obj.linearEndDate;
+  }
+}
 export class closeStakePoolInstruction {
   tag: number;
   static schema: Schema = new Map([
@@ -29,7 +39,10 @@ export class closeStakePoolInstruction {
     programId: PublicKey,
     stakePoolAccount: PublicKey,
     poolVault: PublicKey,
-    owner: PublicKey
+    owner: PublicKey,
+    depositDestination?: PublicKey,
+    splTokenProgram?: PublicKey,
+    mint?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -41,13 +54,34 @@ export class closeStakePoolInstruction {
     keys.push({
       pubkey: poolVault,
       isSigner: false,
-      isWritable: false,
+      isWritable: true,
     });
     keys.push({
       pubkey: owner,
       isSigner: true,
       isWritable: true,
     });
+    if (depositDestination) {
+      keys.push({
+        pubkey: depositDestination,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (splTokenProgram) {
+      keys.push({
+        pubkey: splTokenProgram,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (mint) {
+      keys.push({
+        pubkey: mint,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -80,7 +114,8 @@ export class changeInflationInstruction {
   getInstruction(
     programId: PublicKey,
     centralState: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    adminLog?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -94,6 +129,13 @@ export class changeInflationInstruction {
       isSigner: true,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -180,17 +222,28 @@ export class unlockBondTokensInstruction {
 }
 export class adminFreezeInstruction {
   tag: number;
+  reason: number;
+  expiry: BN;
+  hasAdminLog: number;
   static schema: Schema = new Map([
     [
       adminFreezeInstruction,
       {
         kind: "struct",
-        fields: [["tag", "u8"]],
+        fields: [
+          ["tag", "u8"],
+          ["reason", "u8"],
+          ["expiry", "u64"],
+          ["hasAdminLog", "u8"],
+        ],
       },
     ],
   ]);
-  constructor() {
+  constructor(obj: { reason: number; expiry: BN; hasAdminLog: number }) {
     this.tag = 19;
+    this.reason = obj.reason;
+    this.expiry = obj.expiry.fromTwos(64);
+    this.hasAdminLog = obj.hasAdminLog;
   }
   serialize(): Uint8Array {
     return serialize(adminFreezeInstruction.schema, this);
@@ -199,7 +252,12 @@ export class adminFreezeInstruction {
     programId: PublicKey,
     authority: PublicKey,
     accountToFreeze: PublicKey,
-    centralState: PublicKey
+    centralState: PublicKey,
+    adminLog: PublicKey | undefined,
+    systemProgram: PublicKey | undefined,
+    feePayer: PublicKey | undefined,
+    freezeRecord: PublicKey | undefined,
+    accounts: PublicKey[]
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -218,6 +276,41 @@ export class adminFreezeInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (systemProgram) {
+      keys.push({
+        pubkey: systemProgram,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (feePayer) {
+      keys.push({
+        pubkey: feePayer,
+        isSigner: true,
+        isWritable: true,
+      });
+    }
+    if (freezeRecord) {
+      keys.push({
+        pubkey: freezeRecord,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    for (const k of accounts) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -412,7 +505,8 @@ export class claimRewardsInstruction {
     rewardsDestination: PublicKey,
     centralState: PublicKey,
     mint: PublicKey,
-    splTokenProgram: PublicKey
+    splTokenProgram: PublicKey,
+    referrerRewardsDestination?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -451,6 +545,13 @@ export class claimRewardsInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (referrerRewardsDestination) {
+      keys.push({
+        pubkey: referrerRewardsDestination,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -461,6 +562,10 @@ export class claimRewardsInstruction {
 export class stakeInstruction {
   tag: number;
   amount: BN;
+  hasBondAccount: number;
+  hasMint: number;
+  hasPoolMembership: number;
+  hasReferrerToken: number;
   static schema: Schema = new Map([
     [
       stakeInstruction,
@@ -469,13 +574,27 @@ export class stakeInstruction {
         fields: [
           ["tag", "u8"],
           ["amount", "u64"],
+          ["hasBondAccount", "u8"],
+          ["hasMint", "u8"],
+          ["hasPoolMembership", "u8"],
+          ["hasReferrerToken", "u8"],
         ],
       },
     ],
   ]);
-  constructor(obj: { amount: BN }) {
+  constructor(obj: {
+    amount: BN;
+    hasBondAccount: number;
+    hasMint: number;
+    hasPoolMembership: number;
+    hasReferrerToken: number;
+  }) {
     this.tag = 4;
     this.amount = obj.amount;
+    this.hasBondAccount = obj.hasBondAccount;
+    this.hasMint = obj.hasMint;
+    this.hasPoolMembership = obj.hasPoolMembership;
+    this.hasReferrerToken = obj.hasReferrerToken;
   }
   serialize(): Uint8Array {
     return serialize(stakeInstruction.schema, this);
@@ -490,7 +609,11 @@ export class stakeInstruction {
     splTokenProgram: PublicKey,
     vault: PublicKey,
     feeAccount: PublicKey,
-    bondAccount?: PublicKey
+    bondAccount: PublicKey | undefined,
+    mint: PublicKey | undefined,
+    poolMembership: PublicKey | undefined,
+    referrerToken: PublicKey | undefined,
+    campaignPositions: PublicKey[]
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -541,6 +664,34 @@ export class stakeInstruction {
         isWritable: false,
       });
     }
+    if (mint) {
+      keys.push({
+        pubkey: mint,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (poolMembership) {
+      keys.push({
+        pubkey: poolMembership,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (referrerToken) {
+      keys.push({
+        pubkey: referrerToken,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    for (const k of campaignPositions) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -675,7 +826,8 @@ export class closeStakeAccountInstruction {
   getInstruction(
     programId: PublicKey,
     stakeAccount: PublicKey,
-    owner: PublicKey
+    owner: PublicKey,
+    userIndex?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -689,6 +841,13 @@ export class closeStakeAccountInstruction {
       isSigner: true,
       isWritable: true,
     });
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -716,7 +875,10 @@ export class crankInstruction {
   getInstruction(
     programId: PublicKey,
     stakePool: PublicKey,
-    centralState: PublicKey
+    centralState: PublicKey,
+    bountyDestination?: PublicKey,
+    mint?: PublicKey,
+    splTokenProgram?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -730,6 +892,27 @@ export class crankInstruction {
       isSigner: false,
       isWritable: true,
     });
+    if (bountyDestination) {
+      keys.push({
+        pubkey: bountyDestination,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (mint) {
+      keys.push({
+        pubkey: mint,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (splTokenProgram) {
+      keys.push({
+        pubkey: splTokenProgram,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -739,17 +922,22 @@ export class crankInstruction {
 }
 export class claimBondInstruction {
   tag: number;
+  hasQuoteMint: number;
   static schema: Schema = new Map([
     [
       claimBondInstruction,
       {
         kind: "struct",
-        fields: [["tag", "u8"]],
+        fields: [
+          ["tag", "u8"],
+          ["hasQuoteMint", "u8"],
+        ],
       },
     ],
   ]);
-  constructor() {
+  constructor(obj: { hasQuoteMint: number }) {
     this.tag = 15;
+    this.hasQuoteMint = obj.hasQuoteMint;
   }
   serialize(): Uint8Array {
     return serialize(claimBondInstruction.schema, this);
@@ -764,7 +952,10 @@ export class claimBondInstruction {
     accessMint: PublicKey,
     poolVault: PublicKey,
     centralState: PublicKey,
-    splTokenProgram: PublicKey
+    splTokenProgram: PublicKey,
+    quoteMint?: PublicKey,
+    quoteTokenProgram?: PublicKey,
+    userIndex?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -775,7 +966,7 @@ export class claimBondInstruction {
     });
     keys.push({
       pubkey: buyer,
-      isSigner: false,
+      isSigner: true,
       isWritable: false,
     });
     keys.push({
@@ -813,6 +1004,27 @@ export class claimBondInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (quoteMint) {
+      keys.push({
+        pubkey: quoteMint,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (quoteTokenProgram) {
+      keys.push({
+        pubkey: quoteTokenProgram,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -853,7 +1065,8 @@ export class editMetadataInstruction {
     centralState: PublicKey,
     authority: PublicKey,
     metadata: PublicKey,
-    metadataProgram: PublicKey
+    metadataProgram: PublicKey,
+    adminLog?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -877,6 +1090,13 @@ export class editMetadataInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -914,7 +1134,9 @@ export class createStakeAccountInstruction {
     stakeAccount: PublicKey,
     systemProgram: PublicKey,
     stakePool: PublicKey,
-    feePayer: PublicKey
+    feePayer: PublicKey,
+    poolMembership?: PublicKey,
+    userIndex?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -938,6 +1160,20 @@ export class createStakeAccountInstruction {
       isSigner: true,
       isWritable: true,
     });
+    if (poolMembership) {
+      keys.push({
+        pubkey: poolMembership,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -1027,7 +1263,9 @@ export class createBondInstruction {
   unlockPeriod: BN;
   unlockAmount: BN;
   sellerIndex: BN;
-  static schema: Schema = new Map([
+  vesting: VestingSchedule | null;
+  expiryDate: BN;
+  static schema: Schema = new Map<any, any>([
     [
       createBondInstruction,
       {
@@ -1043,6 +1281,19 @@ export class createBondInstruction {
           ["unlockPeriod", "u64"],
           ["unlockAmount", "u64"],
           ["sellerIndex", "u64"],
+          ["vesting", { kind: "option", type: VestingSchedule }],
+          ["expiryDate", "u64"],
+        ],
+      },
+    ],
+    [
+      VestingSchedule,
+      {
+        kind: "struct",
+        fields: [
+          ["cliffDate", "u64"],
+          ["cliffAmount", "u64"],
+          ["linearEndDate", "u64"],
         ],
       },
     ],
@@ -1057,6 +1308,8 @@ export class createBondInstruction {
     unlockPeriod: BN;
     unlockAmount: BN;
     sellerIndex: BN;
+    vesting: VestingSchedule | null;
+    expiryDate: BN;
   }) {
     this.tag = 12;
     this.buyer = obj.buyer;
@@ -1068,6 +1321,8 @@ export class createBondInstruction {
     this.unlockPeriod = obj.unlockPeriod.fromTwos(64);
     this.unlockAmount = obj.unlockAmount;
     this.sellerIndex = obj.sellerIndex;
+    this.vesting = obj.vesting;
+    this.expiryDate = obj.expiryDate.fromTwos(64);
   }
   serialize(): Uint8Array {
     return serialize(createBondInstruction.schema, this);
@@ -1117,6 +1372,9 @@ export class createBondInstruction {
 export class unstakeInstruction {
   tag: number;
   amount: BN;
+  hasBondAccount: number;
+  hasMint: number;
+  hasReceipt: number;
   static schema: Schema = new Map([
     [
       unstakeInstruction,
@@ -1125,13 +1383,24 @@ export class unstakeInstruction {
         fields: [
           ["tag", "u8"],
           ["amount", "u64"],
+          ["hasBondAccount", "u8"],
+          ["hasMint", "u8"],
+          ["hasReceipt", "u8"],
         ],
       },
     ],
   ]);
-  constructor(obj: { amount: BN }) {
+  constructor(obj: {
+    amount: BN;
+    hasBondAccount: number;
+    hasMint: number;
+    hasReceipt: number;
+  }) {
     this.tag = 5;
     this.amount = obj.amount;
+    this.hasBondAccount = obj.hasBondAccount;
+    this.hasMint = obj.hasMint;
+    this.hasReceipt = obj.hasReceipt;
   }
   serialize(): Uint8Array {
     return serialize(unstakeInstruction.schema, this);
@@ -1145,7 +1414,12 @@ export class unstakeInstruction {
     destinationToken: PublicKey,
     splTokenProgram: PublicKey,
     vault: PublicKey,
-    bondAccount?: PublicKey
+    bondAccount: PublicKey | undefined,
+    mint: PublicKey | undefined,
+    receiptMint: PublicKey | undefined,
+    receiptToken: PublicKey | undefined,
+    receiptTokenProgram: PublicKey | undefined,
+    campaignPositions: PublicKey[]
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -1191,6 +1465,41 @@ export class unstakeInstruction {
         isWritable: false,
       });
     }
+    if (mint) {
+      keys.push({
+        pubkey: mint,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    if (receiptMint) {
+      keys.push({
+        pubkey: receiptMint,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (receiptToken) {
+      keys.push({
+        pubkey: receiptToken,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    if (receiptTokenProgram) {
+      keys.push({
+        pubkey: receiptTokenProgram,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    for (const k of campaignPositions) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -1284,7 +1593,8 @@ export class changeCentralStateAuthorityInstruction {
   getInstruction(
     programId: PublicKey,
     centralState: PublicKey,
-    authority: PublicKey
+    authority: PublicKey,
+    adminLog?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -1298,6 +1608,13 @@ export class changeCentralStateAuthorityInstruction {
       isSigner: true,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -1333,7 +1650,8 @@ export class adminMintInstruction {
     mint: PublicKey,
     accessTokenDestination: PublicKey,
     centralState: PublicKey,
-    splTokenProgram: PublicKey
+    splTokenProgram: PublicKey,
+    adminLog?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -1362,6 +1680,13 @@ export class adminMintInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
     return new TransactionInstruction({
       keys,
       programId,
@@ -1390,7 +1715,8 @@ export class activateStakePoolInstruction {
     programId: PublicKey,
     authority: PublicKey,
     stakePool: PublicKey,
-    centralState: PublicKey
+    centralState: PublicKey,
+    adminLog?: PublicKey
   ): TransactionInstruction {
     const data = Buffer.from(this.serialize());
     const keys: AccountKey[] = [];
@@ -1409,6 +1735,2946 @@ export class activateStakePoolInstruction {
       isSigner: false,
       isWritable: false,
     });
+    if (adminLog) {
+      keys.push({
+        pubkey: adminLog,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class setPoolWeightInstruction {
+  tag: number;
+  newWeight: BN;
+  static schema: Schema = new Map([
+    [
+      setPoolWeightInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["newWeight", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { newWeight: BN }) {
+    this.tag = 23;
+    this.newWeight = obj.newWeight;
+  }
+  serialize(): Uint8Array {
+    return serialize(setPoolWeightInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey,
+    stakePool: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createRewardCampaignInstruction {
+  tag: number;
+  dailyRate: BN;
+  endTime: BN;
+  static schema: Schema = new Map([
+    [
+      createRewardCampaignInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["dailyRate", "u64"],
+          ["endTime", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { dailyRate: BN; endTime: BN }) {
+    this.tag = 24;
+    this.dailyRate = obj.dailyRate;
+    this.endTime = obj.endTime.fromTwos(64);
+  }
+  serialize(): Uint8Array {
+    return serialize(createRewardCampaignInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    owner: PublicKey,
+    rewardCampaign: PublicKey,
+    vault: PublicKey,
+    mint: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: rewardCampaign,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: vault,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class fundRewardCampaignInstruction {
+  tag: number;
+  amount: BN;
+  static schema: Schema = new Map([
+    [
+      fundRewardCampaignInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["amount", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { amount: BN }) {
+    this.tag = 25;
+    this.amount = obj.amount;
+  }
+  serialize(): Uint8Array {
+    return serialize(fundRewardCampaignInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    rewardCampaign: PublicKey,
+    owner: PublicKey,
+    sourceToken: PublicKey,
+    vault: PublicKey,
+    splTokenProgram: PublicKey,
+    mint: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: rewardCampaign,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: sourceToken,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: vault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class extendRewardCampaignInstruction {
+  tag: number;
+  newEndTime: BN;
+  newDailyRate: BN;
+  static schema: Schema = new Map([
+    [
+      extendRewardCampaignInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["newEndTime", "u64"],
+          ["newDailyRate", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { newEndTime: BN; newDailyRate: BN }) {
+    this.tag = 26;
+    this.newEndTime = obj.newEndTime.fromTwos(64);
+    this.newDailyRate = obj.newDailyRate;
+  }
+  serialize(): Uint8Array {
+    return serialize(extendRewardCampaignInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    rewardCampaign: PublicKey,
+    stakePool: PublicKey,
+    owner: PublicKey,
+    vault: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: rewardCampaign,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: vault,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class reclaimCampaignRewardsInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      reclaimCampaignRewardsInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 27;
+  }
+  serialize(): Uint8Array {
+    return serialize(reclaimCampaignRewardsInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    rewardCampaign: PublicKey,
+    stakePool: PublicKey,
+    owner: PublicKey,
+    vault: PublicKey,
+    destinationToken: PublicKey,
+    splTokenProgram: PublicKey,
+    mint: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: rewardCampaign,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: vault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: destinationToken,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class claimCampaignRewardsInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      claimCampaignRewardsInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 28;
+  }
+  serialize(): Uint8Array {
+    return serialize(claimCampaignRewardsInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    rewardCampaign: PublicKey,
+    stakePool: PublicKey,
+    stakeAccount: PublicKey,
+    owner: PublicKey,
+    campaignPosition: PublicKey,
+    vault: PublicKey,
+    rewardsDestination: PublicKey,
+    splTokenProgram: PublicKey,
+    mint: PublicKey,
+    systemProgram: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: rewardCampaign,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: campaignPosition,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: vault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: rewardsDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class changeCrankBountyInstruction {
+  tag: number;
+  crankBounty: BN;
+  static schema: Schema = new Map([
+    [
+      changeCrankBountyInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["crankBounty", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { crankBounty: BN }) {
+    this.tag = 29;
+    this.crankBounty = obj.crankBounty;
+  }
+  serialize(): Uint8Array {
+    return serialize(changeCrankBountyInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class getClaimableRewardsInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      getClaimableRewardsInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 30;
+  }
+  serialize(): Uint8Array {
+    return serialize(getClaimableRewardsInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    stakeAccount: PublicKey,
+    centralState: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class getPoolClaimableRewardsInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      getPoolClaimableRewardsInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 31;
+  }
+  serialize(): Uint8Array {
+    return serialize(getPoolClaimableRewardsInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    centralState: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class getBondUnlockableInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      getBondUnlockableInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 32;
+  }
+  serialize(): Uint8Array {
+    return serialize(getBondUnlockableInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAccount: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class hasAccessInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      hasAccessInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 33;
+  }
+  serialize(): Uint8Array {
+    return serialize(hasAccessInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    stakeAccount: PublicKey,
+    bondAccount?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: false,
+    });
+    if (bondAccount) {
+      keys.push({
+        pubkey: bondAccount,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class mintReceiptInstruction {
+  tag: number;
+  uri: string;
+  static schema: Schema = new Map([
+    [
+      mintReceiptInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["uri", "string"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { uri: string }) {
+    this.tag = 34;
+    this.uri = obj.uri;
+  }
+  serialize(): Uint8Array {
+    return serialize(mintReceiptInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    stakePool: PublicKey,
+    stakeAccount: PublicKey,
+    owner: PublicKey,
+    receiptMint: PublicKey,
+    receiptToken: PublicKey,
+    metadata: PublicKey,
+    metadataProgram: PublicKey,
+    splTokenProgram: PublicKey,
+    associatedTokenProgram: PublicKey,
+    systemProgram: PublicKey,
+    rentSysvar: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: receiptMint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: receiptToken,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: metadata,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: metadataProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: associatedTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: rentSysvar,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class burnReceiptInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      burnReceiptInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 35;
+  }
+  serialize(): Uint8Array {
+    return serialize(burnReceiptInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    stakePool: PublicKey,
+    stakeAccount: PublicKey,
+    receiptMint: PublicKey,
+    receiptToken: PublicKey,
+    splTokenProgram: PublicKey,
+    owner?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: receiptMint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: receiptToken,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    if (owner) {
+      keys.push({
+        pubkey: owner,
+        isSigner: true,
+        isWritable: false,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createAccessDelegationInstruction {
+  tag: number;
+  members: Uint8Array[];
+  static schema: Schema = new Map([
+    [
+      createAccessDelegationInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["members", [[32]]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { members: Uint8Array[] }) {
+    this.tag = 36;
+    this.members = obj.members;
+  }
+  serialize(): Uint8Array {
+    return serialize(createAccessDelegationInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakeAccount: PublicKey,
+    accessDelegation: PublicKey,
+    owner: PublicKey,
+    systemProgram: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakeAccount,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: accessDelegation,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class editAccessDelegationInstruction {
+  tag: number;
+  members: Uint8Array[];
+  static schema: Schema = new Map([
+    [
+      editAccessDelegationInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["members", [[32]]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { members: Uint8Array[] }) {
+    this.tag = 37;
+    this.members = obj.members;
+  }
+  serialize(): Uint8Array {
+    return serialize(editAccessDelegationInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    accessDelegation: PublicKey,
+    owner: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: accessDelegation,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class closeAccessDelegationInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      closeAccessDelegationInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 38;
+  }
+  serialize(): Uint8Array {
+    return serialize(closeAccessDelegationInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    accessDelegation: PublicKey,
+    owner: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: accessDelegation,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class setPoolAllowlistInstruction {
+  tag: number;
+  allowlisted: number;
+  static schema: Schema = new Map([
+    [
+      setPoolAllowlistInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["allowlisted", "u8"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { allowlisted: number }) {
+    this.tag = 39;
+    this.allowlisted = obj.allowlisted;
+  }
+  serialize(): Uint8Array {
+    return serialize(setPoolAllowlistInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    stakePoolOwner: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePoolOwner,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class addPoolMemberInstruction {
+  tag: number;
+  member: Uint8Array;
+  static schema: Schema = new Map([
+    [
+      addPoolMemberInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["member", [32]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { member: Uint8Array }) {
+    this.tag = 40;
+    this.member = obj.member;
+  }
+  serialize(): Uint8Array {
+    return serialize(addPoolMemberInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    stakePoolOwner: PublicKey,
+    poolMembership: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePoolOwner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: poolMembership,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class removePoolMemberInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      removePoolMemberInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 41;
+  }
+  serialize(): Uint8Array {
+    return serialize(removePoolMemberInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    stakePoolOwner: PublicKey,
+    poolMembership: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePoolOwner,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: poolMembership,
+      isSigner: false,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class changeReferralRatesInstruction {
+  tag: number;
+  referralFeeRate: BN;
+  referralRewardShare: BN;
+  static schema: Schema = new Map([
+    [
+      changeReferralRatesInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["referralFeeRate", "u64"],
+          ["referralRewardShare", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { referralFeeRate: BN; referralRewardShare: BN }) {
+    this.tag = 42;
+    this.referralFeeRate = obj.referralFeeRate;
+    this.referralRewardShare = obj.referralRewardShare;
+  }
+  serialize(): Uint8Array {
+    return serialize(changeReferralRatesInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class transferBondInstruction {
+  tag: number;
+  newOwner: Uint8Array;
+  static schema: Schema = new Map([
+    [
+      transferBondInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["newOwner", [32]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { newOwner: Uint8Array }) {
+    this.tag = 43;
+    this.newOwner = obj.newOwner;
+  }
+  serialize(): Uint8Array {
+    return serialize(transferBondInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAccount: PublicKey,
+    bondOwner: PublicKey,
+    stakePool: PublicKey,
+    centralState: PublicKey,
+    mint: PublicKey,
+    rewardsDestination: PublicKey,
+    splTokenProgram: PublicKey,
+    userIndexes: PublicKey[]
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondOwner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: rewardsDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    for (const k of userIndexes) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class listBondInstruction {
+  tag: number;
+  price: BN;
+  static schema: Schema = new Map([
+    [
+      listBondInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["price", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { price: BN }) {
+    this.tag = 44;
+    this.price = obj.price;
+  }
+  serialize(): Uint8Array {
+    return serialize(listBondInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAccount: PublicKey,
+    seller: PublicKey,
+    bondListing: PublicKey,
+    sellerTokenAccount: PublicKey,
+    systemProgram: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondListing,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: sellerTokenAccount,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class cancelBondListingInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      cancelBondListingInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 45;
+  }
+  serialize(): Uint8Array {
+    return serialize(cancelBondListingInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondListing: PublicKey,
+    bondAccount: PublicKey,
+    seller: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondListing,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class fillBondListingInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      fillBondListingInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 46;
+  }
+  serialize(): Uint8Array {
+    return serialize(fillBondListingInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondListing: PublicKey,
+    bondAccount: PublicKey,
+    buyer: PublicKey,
+    buyerTokenAccount: PublicKey,
+    seller: PublicKey,
+    sellerTokenAccount: PublicKey,
+    sellerRewardsDestination: PublicKey,
+    stakePool: PublicKey,
+    centralState: PublicKey,
+    mint: PublicKey,
+    splTokenProgram: PublicKey,
+    quoteMint: PublicKey,
+    quoteTokenProgram: PublicKey,
+    userIndexes: PublicKey[]
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondListing,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: buyer,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: buyerTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: seller,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: sellerTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: sellerRewardsDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteMint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    for (const k of userIndexes) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class cancelBondInstruction {
+  tag: number;
+  sellerIndex: BN;
+  static schema: Schema = new Map([
+    [
+      cancelBondInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["sellerIndex", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { sellerIndex: BN }) {
+    this.tag = 47;
+    this.sellerIndex = obj.sellerIndex;
+  }
+  serialize(): Uint8Array {
+    return serialize(cancelBondInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    seller: PublicKey,
+    bondAccount: PublicKey,
+    feePayer: PublicKey,
+    userIndex?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: false,
+      isWritable: true,
+    });
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class changeEarlyUnlockPenaltyInstruction {
+  tag: number;
+  earlyUnlockPenalty: BN;
+  static schema: Schema = new Map([
+    [
+      changeEarlyUnlockPenaltyInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["earlyUnlockPenalty", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { earlyUnlockPenalty: BN }) {
+    this.tag = 48;
+    this.earlyUnlockPenalty = obj.earlyUnlockPenalty;
+  }
+  serialize(): Uint8Array {
+    return serialize(changeEarlyUnlockPenaltyInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class earlyUnlockBondInstruction {
+  tag: number;
+  amount: BN;
+  static schema: Schema = new Map([
+    [
+      earlyUnlockBondInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["amount", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { amount: BN }) {
+    this.tag = 49;
+    this.amount = obj.amount;
+  }
+  serialize(): Uint8Array {
+    return serialize(earlyUnlockBondInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAccount: PublicKey,
+    bondOwner: PublicKey,
+    mint: PublicKey,
+    accessTokenDestination: PublicKey,
+    feeAccount: PublicKey,
+    centralState: PublicKey,
+    stakePool: PublicKey,
+    poolVault: PublicKey,
+    splTokenProgram: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondOwner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: accessTokenDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: feeAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: poolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createBondAuctionInstruction {
+  tag: number;
+  totalAmount: BN;
+  quoteMint: Uint8Array;
+  sellerTokenAccount: Uint8Array;
+  startPrice: BN;
+  floorPrice: BN;
+  startTime: BN;
+  endTime: BN;
+  unlockStartDate: BN;
+  unlockPeriod: BN;
+  unlockPeriods: BN;
+  vesting: VestingSchedule | null;
+  sellerIndex: BN;
+  static schema: Schema = new Map<any, any>([
+    [
+      createBondAuctionInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["totalAmount", "u64"],
+          ["quoteMint", [32]],
+          ["sellerTokenAccount", [32]],
+          ["startPrice", "u64"],
+          ["floorPrice", "u64"],
+          ["startTime", "u64"],
+          ["endTime", "u64"],
+          ["unlockStartDate", "u64"],
+          ["unlockPeriod", "u64"],
+          ["unlockPeriods", "u64"],
+          ["vesting", { kind: "option", type: VestingSchedule }],
+          ["sellerIndex", "u64"],
+        ],
+      },
+    ],
+    [
+      VestingSchedule,
+      {
+        kind: "struct",
+        fields: [
+          ["cliffDate", "u64"],
+          ["cliffAmount", "u64"],
+          ["linearEndDate", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: {
+    totalAmount: BN;
+    quoteMint: Uint8Array;
+    sellerTokenAccount: Uint8Array;
+    startPrice: BN;
+    floorPrice: BN;
+    startTime: BN;
+    endTime: BN;
+    unlockStartDate: BN;
+    unlockPeriod: BN;
+    unlockPeriods: BN;
+    vesting: VestingSchedule | null;
+    sellerIndex: BN;
+  }) {
+    this.tag = 50;
+    this.totalAmount = obj.totalAmount;
+    this.quoteMint = obj.quoteMint;
+    this.sellerTokenAccount = obj.sellerTokenAccount;
+    this.startPrice = obj.startPrice;
+    this.floorPrice = obj.floorPrice;
+    this.startTime = obj.startTime.fromTwos(64);
+    this.endTime = obj.endTime.fromTwos(64);
+    this.unlockStartDate = obj.unlockStartDate.fromTwos(64);
+    this.unlockPeriod = obj.unlockPeriod.fromTwos(64);
+    this.unlockPeriods = obj.unlockPeriods;
+    this.vesting = obj.vesting;
+    this.sellerIndex = obj.sellerIndex;
+  }
+  serialize(): Uint8Array {
+    return serialize(createBondAuctionInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    seller: PublicKey,
+    bondAuction: PublicKey,
+    stakePool: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: bondAuction,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class fillBondAuctionInstruction {
+  tag: number;
+  amount: BN;
+  maxQuoteAmount: BN;
+  static schema: Schema = new Map([
+    [
+      fillBondAuctionInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["amount", "u64"],
+          ["maxQuoteAmount", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { amount: BN; maxQuoteAmount: BN }) {
+    this.tag = 51;
+    this.amount = obj.amount;
+    this.maxQuoteAmount = obj.maxQuoteAmount;
+  }
+  serialize(): Uint8Array {
+    return serialize(fillBondAuctionInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAuction: PublicKey,
+    bondAccount: PublicKey,
+    buyer: PublicKey,
+    quoteTokenSource: PublicKey,
+    quoteTokenDestination: PublicKey,
+    stakePool: PublicKey,
+    accessMint: PublicKey,
+    poolVault: PublicKey,
+    centralState: PublicKey,
+    splTokenProgram: PublicKey,
+    quoteMint: PublicKey,
+    quoteTokenProgram: PublicKey,
+    systemProgram: PublicKey,
+    userIndex?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAuction,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: buyer,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: quoteTokenSource,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: quoteTokenDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: accessMint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: poolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteMint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class moveBondStakeInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      moveBondStakeInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 52;
+  }
+  serialize(): Uint8Array {
+    return serialize(moveBondStakeInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondAccount: PublicKey,
+    bondOwner: PublicKey,
+    sourceStakePool: PublicKey,
+    sourcePoolVault: PublicKey,
+    destinationStakePool: PublicKey,
+    destinationPoolVault: PublicKey,
+    centralState: PublicKey,
+    splTokenProgram: PublicKey,
+    mint: PublicKey,
+    poolMembership?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondOwner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: sourceStakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: sourcePoolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: destinationStakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: destinationPoolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    if (poolMembership) {
+      keys.push({
+        pubkey: poolMembership,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createBondRoundInstruction {
+  tag: number;
+  merkleRoot: Uint8Array;
+  quoteMint: Uint8Array;
+  sellerTokenAccount: Uint8Array;
+  unlockStartDate: BN;
+  unlockPeriod: BN;
+  unlockPeriods: BN;
+  sellerIndex: BN;
+  numNodes: BN;
+  static schema: Schema = new Map([
+    [
+      createBondRoundInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["merkleRoot", [32]],
+          ["quoteMint", [32]],
+          ["sellerTokenAccount", [32]],
+          ["unlockStartDate", "u64"],
+          ["unlockPeriod", "u64"],
+          ["unlockPeriods", "u64"],
+          ["sellerIndex", "u64"],
+          ["numNodes", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: {
+    merkleRoot: Uint8Array;
+    quoteMint: Uint8Array;
+    sellerTokenAccount: Uint8Array;
+    unlockStartDate: BN;
+    unlockPeriod: BN;
+    unlockPeriods: BN;
+    sellerIndex: BN;
+    numNodes: BN;
+  }) {
+    this.tag = 53;
+    this.merkleRoot = obj.merkleRoot;
+    this.quoteMint = obj.quoteMint;
+    this.sellerTokenAccount = obj.sellerTokenAccount;
+    this.unlockStartDate = obj.unlockStartDate.fromTwos(64);
+    this.unlockPeriod = obj.unlockPeriod.fromTwos(64);
+    this.unlockPeriods = obj.unlockPeriods;
+    this.sellerIndex = obj.sellerIndex;
+    this.numNodes = obj.numNodes;
+  }
+  serialize(): Uint8Array {
+    return serialize(createBondRoundInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    seller: PublicKey,
+    bondRound: PublicKey,
+    stakePool: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: bondRound,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class signBondRoundInstruction {
+  tag: number;
+  sellerIndex: BN;
+  static schema: Schema = new Map([
+    [
+      signBondRoundInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["sellerIndex", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { sellerIndex: BN }) {
+    this.tag = 54;
+    this.sellerIndex = obj.sellerIndex;
+  }
+  serialize(): Uint8Array {
+    return serialize(signBondRoundInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    seller: PublicKey,
+    bondRound: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: seller,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: bondRound,
+      isSigner: false,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class claimRoundBondInstruction {
+  tag: number;
+  index: BN;
+  totalAmountSold: BN;
+  totalQuoteAmount: BN;
+  proof: Uint8Array[];
+  static schema: Schema = new Map([
+    [
+      claimRoundBondInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["index", "u64"],
+          ["totalAmountSold", "u64"],
+          ["totalQuoteAmount", "u64"],
+          ["proof", [[32]]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: {
+    index: BN;
+    totalAmountSold: BN;
+    totalQuoteAmount: BN;
+    proof: Uint8Array[];
+  }) {
+    this.tag = 55;
+    this.index = obj.index;
+    this.totalAmountSold = obj.totalAmountSold;
+    this.totalQuoteAmount = obj.totalQuoteAmount;
+    this.proof = obj.proof;
+  }
+  serialize(): Uint8Array {
+    return serialize(claimRoundBondInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    bondRound: PublicKey,
+    bondAccount: PublicKey,
+    buyer: PublicKey,
+    quoteTokenSource: PublicKey,
+    quoteTokenDestination: PublicKey,
+    stakePool: PublicKey,
+    accessMint: PublicKey,
+    poolVault: PublicKey,
+    centralState: PublicKey,
+    splTokenProgram: PublicKey,
+    quoteMint: PublicKey,
+    quoteTokenProgram: PublicKey,
+    systemProgram: PublicKey,
+    userIndex?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: bondRound,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: bondAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: buyer,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: quoteTokenSource,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: quoteTokenDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: accessMint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: poolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteMint,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: quoteTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    if (userIndex) {
+      keys.push({
+        pubkey: userIndex,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createDistributorInstruction {
+  tag: number;
+  merkleRoot: Uint8Array;
+  maxTotalClaim: BN;
+  numNodes: BN;
+  static schema: Schema = new Map([
+    [
+      createDistributorInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["merkleRoot", [32]],
+          ["maxTotalClaim", "u64"],
+          ["numNodes", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: {
+    merkleRoot: Uint8Array;
+    maxTotalClaim: BN;
+    numNodes: BN;
+  }) {
+    this.tag = 56;
+    this.merkleRoot = obj.merkleRoot;
+    this.maxTotalClaim = obj.maxTotalClaim;
+    this.numNodes = obj.numNodes;
+  }
+  serialize(): Uint8Array {
+    return serialize(createDistributorInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey,
+    distributor: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: distributor,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class claimDistributionInstruction {
+  tag: number;
+  index: BN;
+  amount: BN;
+  proof: Uint8Array[];
+  static schema: Schema = new Map([
+    [
+      claimDistributionInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["index", "u64"],
+          ["amount", "u64"],
+          ["proof", [[32]]],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { index: BN; amount: BN; proof: Uint8Array[] }) {
+    this.tag = 57;
+    this.index = obj.index;
+    this.amount = obj.amount;
+    this.proof = obj.proof;
+  }
+  serialize(): Uint8Array {
+    return serialize(claimDistributionInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    distributor: PublicKey,
+    recipient: PublicKey,
+    recipientTokenAccount: PublicKey,
+    centralState: PublicKey,
+    mint: PublicKey,
+    splTokenProgram: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: distributor,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: recipient,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: recipientTokenAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class claimMultipleRewardsInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      claimMultipleRewardsInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 58;
+  }
+  serialize(): Uint8Array {
+    return serialize(claimMultipleRewardsInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    owner: PublicKey,
+    rewardsDestination: PublicKey,
+    centralState: PublicKey,
+    mint: PublicKey,
+    splTokenProgram: PublicKey,
+    claimAccounts: PublicKey[]
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: rewardsDestination,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    for (const k of claimAccounts) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createUserIndexInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      createUserIndexInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 59;
+  }
+  serialize(): Uint8Array {
+    return serialize(createUserIndexInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    userIndex: PublicKey,
+    owner: PublicKey,
+    systemProgram: PublicKey,
+    positions: PublicKey[]
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: userIndex,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    for (const k of positions) {
+      keys.push({
+        pubkey: k,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class closeUserIndexInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      closeUserIndexInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 60;
+  }
+  serialize(): Uint8Array {
+    return serialize(closeUserIndexInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    userIndex: PublicKey,
+    owner: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: userIndex,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class deprecateStakePoolInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      deprecateStakePoolInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 61;
+  }
+  serialize(): Uint8Array {
+    return serialize(deprecateStakePoolInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey,
+    stakePool: PublicKey,
+    freezeRecord: PublicKey,
+    feePayer?: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: freezeRecord,
+      isSigner: false,
+      isWritable: true,
+    });
+    if (feePayer) {
+      keys.push({
+        pubkey: feePayer,
+        isSigner: false,
+        isWritable: true,
+      });
+    }
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class changeActivationDepositInstruction {
+  tag: number;
+  activationDeposit: BN;
+  static schema: Schema = new Map([
+    [
+      changeActivationDepositInstruction,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["activationDeposit", "u64"],
+        ],
+      },
+    ],
+  ]);
+  constructor(obj: { activationDeposit: BN }) {
+    this.tag = 62;
+    this.activationDeposit = obj.activationDeposit;
+  }
+  serialize(): Uint8Array {
+    return serialize(changeActivationDepositInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class selfActivateStakePoolInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      selfActivateStakePoolInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 63;
+  }
+  serialize(): Uint8Array {
+    return serialize(selfActivateStakePoolInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    stakePool: PublicKey,
+    owner: PublicKey,
+    sourceToken: PublicKey,
+    poolVault: PublicKey,
+    centralState: PublicKey,
+    splTokenProgram: PublicKey,
+    mint: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: stakePool,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: owner,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: sourceToken,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: poolVault,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: splTokenProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: mint,
+      isSigner: false,
+      isWritable: false,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class createAdminLogInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      createAdminLogInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 64;
+  }
+  serialize(): Uint8Array {
+    return serialize(createAdminLogInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    authority: PublicKey,
+    adminLog: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: authority,
+      isSigner: true,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: adminLog,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class expireFreezeInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      expireFreezeInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 65;
+  }
+  serialize(): Uint8Array {
+    return serialize(expireFreezeInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    frozenAccount: PublicKey,
+    freezeRecord: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: frozenAccount,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: freezeRecord,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: false,
+      isWritable: true,
+    });
+    return new TransactionInstruction({
+      keys,
+      programId,
+      data,
+    });
+  }
+}
+export class migrateAccountInstruction {
+  tag: number;
+  static schema: Schema = new Map([
+    [
+      migrateAccountInstruction,
+      {
+        kind: "struct",
+        fields: [["tag", "u8"]],
+      },
+    ],
+  ]);
+  constructor() {
+    this.tag = 66;
+  }
+  serialize(): Uint8Array {
+    return serialize(migrateAccountInstruction.schema, this);
+  }
+  getInstruction(
+    programId: PublicKey,
+    centralState: PublicKey,
+    account: PublicKey,
+    systemProgram: PublicKey,
+    feePayer: PublicKey
+  ): TransactionInstruction {
+    const data = Buffer.from(this.serialize());
+    const keys: AccountKey[] = [];
+    keys.push({
+      pubkey: centralState,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: account,
+      isSigner: false,
+      isWritable: true,
+    });
+    keys.push({
+      pubkey: systemProgram,
+      isSigner: false,
+      isWritable: false,
+    });
+    keys.push({
+      pubkey: feePayer,
+      isSigner: true,
+      isWritable: true,
+    });
     return new TransactionInstruction({
       keys,
       programId,
@@ -26,6 +26,28 @@ export enum Tag {
   FrozenStakePool = 8,
   FrozenStakeAccount = 9,
   FrozenBondAccount = 10,
+  // Secondary token rewards funded by a stake pool owner
+  RewardCampaign = 11,
+  CampaignPosition = 12,
+  // Wallets sharing the access of a stake account
+  AccessDelegation = 13,
+  // Wallets allowed to stake in an allowlisted pool
+  PoolMembership = 14,
+  // Bonds escrowed in a listing of the secondary market
+  ListedBondAccount = 15,
+  BondListing = 16,
+  BondAuction = 17,
+  BondRound = 18,
+  // Merkle distributor of ACCESS tokens
+  Distributor = 19,
+  // Stake accounts and bonds of a wallet
+  UserIndex = 20,
+  // Pools winding down: no new stakes nor rewards, withdrawals still allowed
+  DeprecatedStakePool = 21,
+  // Ring buffer of the central state authority actions
+  AdminLog = 22,
+  // Reason and expiry of a frozen account
+  FreezeRecord = 23,
 }
 
 /**
@@ -48,6 +70,7 @@ export class StakePool {
   tag: Tag;
   nonce: number;
   currentDayIdx: number;
+  allowlisted: number;
   _padding: Uint8Array;
   minimumStakeAmount: BN;
   totalStaked: BN;
@@ -55,6 +78,14 @@ export class StakePool {
   stakersPart: BN;
   owner: PublicKey;
   vault: PublicKey;
+  poolWeight: BN;
+  stakersCount: BN;
+  bondHoldersCount: BN;
+  lifetimeStakerRewards: BN;
+  lifetimeOwnerRewards: BN;
+  lifetimeFees: BN;
+  activationDeposit: BN;
+  freezeExpiry: BN;
 
   balances: RewardsTuple[];
 
@@ -67,13 +98,22 @@ export class StakePool {
           ["tag", "u8"],
           ["nonce", "u8"],
           ["currentDayIdx", "u16"],
-          ["_padding", [4]],
+          ["allowlisted", "u8"],
+          ["_padding", [3]],
           ["minimumStakeAmount", "u64"],
           ["totalStaked", "u64"],
           ["lastClaimedOffset", "u64"],
           ["stakersPart", "u64"],
           ["owner", [32]],
           ["vault", [32]],
+          ["poolWeight", "u64"],
+          ["stakersCount", "u64"],
+          ["bondHoldersCount", "u64"],
+          ["lifetimeStakerRewards", "u64"],
+          ["lifetimeOwnerRewards", "u64"],
+          ["lifetimeFees", "u64"],
+          ["activationDeposit", "u64"],
+          ["freezeExpiry", "u64"],
           ["balances", [RewardsTuple, STAKE_BUFFER_LEN]],
         ],
       },
@@ -94,6 +134,7 @@ export class StakePool {
     tag: number;
     nonce: number;
     currentDayIdx: number;
+    allowlisted: number;
     _padding: Uint8Array;
     minimumStakeAmount: BN;
     totalStaked: BN;
@@ -101,12 +142,21 @@ export class StakePool {
     stakersPart: BN;
     owner: Uint8Array;
     vault: Uint8Array;
+    poolWeight: BN;
+    stakersCount: BN;
+    bondHoldersCount: BN;
+    lifetimeStakerRewards: BN;
+    lifetimeOwnerRewards: BN;
+    lifetimeFees: BN;
+    activationDeposit: BN;
+    freezeExpiry: BN;
 
     balances: RewardsTuple[];
   }) {
     this.tag = obj.tag as Tag;
     this.nonce = obj.nonce;
     this.currentDayIdx = obj.currentDayIdx;
+    this.allowlisted = obj.allowlisted;
     this._padding = obj._padding;
     this.minimumStakeAmount = obj.minimumStakeAmount;
     this.totalStaked = obj.totalStaked;
@@ -114,6 +164,14 @@ export class StakePool {
     this.stakersPart = obj.stakersPart;
     this.owner = new PublicKey(obj.owner);
     this.vault = new PublicKey(obj.vault);
+    this.poolWeight = obj.poolWeight;
+    this.stakersCount = obj.stakersCount;
+    this.bondHoldersCount = obj.bondHoldersCount;
+    this.lifetimeStakerRewards = obj.lifetimeStakerRewards;
+    this.lifetimeOwnerRewards = obj.lifetimeOwnerRewards;
+    this.lifetimeFees = obj.lifetimeFees;
+    this.activationDeposit = obj.activationDeposit;
+    this.freezeExpiry = obj.freezeExpiry.fromTwos(64);
     this.balances = obj.balances;
   }
 
@@ -159,6 +217,9 @@ export class StakeAccount {
   stakePool: PublicKey;
   lastClaimedOffset: BN;
   poolMinimumAtCreation: BN;
+  referrer: PublicKey;
+  campaignPositions: number;
+  hasReceipt: number;
 
   static schema: Schema = new Map<any, any>([
     [
@@ -172,6 +233,9 @@ export class StakeAccount {
           ["stakePool", [32]],
           ["lastClaimedOffset", "u64"],
           ["poolMinimumAtCreation", "u64"],
+          ["referrer", [32]],
+          ["campaignPositions", "u8"],
+          ["hasReceipt", "u8"],
         ],
       },
     ],
@@ -184,6 +248,9 @@ export class StakeAccount {
     stakePool: Uint8Array;
     lastClaimedOffset: BN;
     poolMinimumAtCreation: BN;
+    referrer: Uint8Array;
+    campaignPositions: number;
+    hasReceipt: number;
   }) {
     this.tag = obj.tag;
     this.owner = new PublicKey(obj.owner);
@@ -191,6 +258,9 @@ export class StakeAccount {
     this.stakePool = new PublicKey(obj.stakePool);
     this.lastClaimedOffset = obj.lastClaimedOffset.fromTwos(64);
     this.poolMinimumAtCreation = obj.poolMinimumAtCreation;
+    this.referrer = new PublicKey(obj.referrer);
+    this.campaignPositions = obj.campaignPositions;
+    this.hasReceipt = obj.hasReceipt;
   }
 
   static deserialize(data: Buffer) {
@@ -228,6 +298,19 @@ export class StakeAccount {
       programId
     );
   }
+
+  /**
+   * This method can be used to derive the key of the receipt mint of a stake account
+   * @param programId The ACCESS program ID
+   * @param stakeAccount The key of the stake account
+   * @returns
+   */
+  static getReceiptMintKey(programId: PublicKey, stakeAccount: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt"), stakeAccount.toBuffer()],
+      programId
+    );
+  }
 }
 
 /**
@@ -243,6 +326,14 @@ export class CentralState {
   totalStaked: BN;
   totalStakedSnapshot: BN;
   lastSnapshotOffset: BN;
+  totalWeightedStaked: BN;
+  totalWeightedStakedSnapshot: BN;
+  crankBounty: BN;
+  referralFeeRate: BN;
+  referralRewardShare: BN;
+  earlyUnlockPenalty: BN;
+  activationDeposit: BN;
+  adminLog: PublicKey;
 
   static schema: Schema = new Map([
     [
@@ -259,6 +350,14 @@ export class CentralState {
           ["totalStaked", "u64"],
           ["totalStakedSnapshot", "u64"],
           ["lastSnapshotOffset", "u64"],
+          ["totalWeightedStaked", "u128"],
+          ["totalWeightedStakedSnapshot", "u128"],
+          ["crankBounty", "u64"],
+          ["referralFeeRate", "u64"],
+          ["referralRewardShare", "u64"],
+          ["earlyUnlockPenalty", "u64"],
+          ["activationDeposit", "u64"],
+          ["adminLog", [32]],
         ],
       },
     ],
@@ -274,6 +373,14 @@ export class CentralState {
     totalStaked: BN;
     totalStakedSnapshot: BN;
     lastSnapshotOffset: BN;
+    totalWeightedStaked: BN;
+    totalWeightedStakedSnapshot: BN;
+    crankBounty: BN;
+    referralFeeRate: BN;
+    referralRewardShare: BN;
+    earlyUnlockPenalty: BN;
+    activationDeposit: BN;
+    adminLog: Uint8Array;
   }) {
     this.tag = obj.tag as Tag;
     this.signerNonce = obj.signerNonce;
@@ -284,6 +391,21 @@ export class CentralState {
     this.totalStaked = obj.totalStaked;
     this.totalStakedSnapshot = obj.totalStakedSnapshot.fromTwos(64);
     this.lastSnapshotOffset = obj.lastSnapshotOffset.fromTwos(64);
+    this.totalWeightedStaked = obj.totalWeightedStaked;
+    this.totalWeightedStakedSnapshot = obj.totalWeightedStakedSnapshot;
+    this.crankBounty = obj.crankBounty;
+    this.referralFeeRate = obj.referralFeeRate;
+    this.referralRewardShare = obj.referralRewardShare;
+    this.earlyUnlockPenalty = obj.earlyUnlockPenalty;
+    this.activationDeposit = obj.activationDeposit;
+    this.adminLog = new PublicKey(obj.adminLog);
+  }
+
+  /**
+   * The admin log that the admin instructions must pass, undefined until it is created
+   */
+  getAdminLog() {
+    return this.adminLog.equals(PublicKey.default) ? undefined : this.adminLog;
   }
 
   static deserialize(data: Buffer) {
@@ -314,6 +436,22 @@ export class CentralState {
   }
 }
 
+/**
+ * Vesting schedule of a bond: a cliff release followed by linear vesting.
+ * A zero linear end date means the bond uses the periodic unlocks
+ */
+export class VestingSchedule {
+  cliffDate: BN;
+  cliffAmount: BN;
+  linearEndDate: BN;
+
+  constructor(obj: { cliffDate: BN; cliffAmount: BN; linearEndDate: BN }) {
+    this.cliffDate = obj.cliffDate.fromTwos(64);
+    this.cliffAmount = obj.cliffAmount;
+    this.linearEndDate = obj.linearEndDate.fromTwos(64);
+  }
+}
+
 /**
  * The bond account state
  */
@@ -334,8 +472,11 @@ export class BondAccount {
   stakePool: PublicKey;
   lastClaimedOffset: BN;
   sellers: PublicKey[];
+  vesting: VestingSchedule;
+  expiryDate: BN;
+  feePayer: PublicKey;
 
-  static schema: Schema = new Map([
+  static schema: Schema = new Map<any, any>([
     [
       BondAccount,
       {
@@ -357,6 +498,20 @@ export class BondAccount {
           ["stakePool", [32]],
           ["lastClaimedOffset", "u64"],
           ["sellers", [[32]]],
+          ["vesting", VestingSchedule],
+          ["expiryDate", "u64"],
+          ["feePayer", [32]],
+        ],
+      },
+    ],
+    [
+      VestingSchedule,
+      {
+        kind: "struct",
+        fields: [
+          ["cliffDate", "u64"],
+          ["cliffAmount", "u64"],
+          ["linearEndDate", "u64"],
         ],
       },
     ],
@@ -379,6 +534,9 @@ export class BondAccount {
     stakePool: Uint8Array;
     lastClaimedOffset: BN;
     sellers: Uint8Array[];
+    vesting: VestingSchedule;
+    expiryDate: BN;
+    feePayer: Uint8Array;
   }) {
     this.tag = obj.tag as Tag;
     this.owner = new PublicKey(obj.owner);
@@ -396,6 +554,9 @@ export class BondAccount {
     this.stakePool = new PublicKey(obj.stakePool);
     this.lastClaimedOffset = obj.lastClaimedOffset;
     this.sellers = obj.sellers.map((e) => new PublicKey(e));
+    this.vesting = obj.vesting;
+    this.expiryDate = obj.expiryDate.fromTwos(64);
+    this.feePayer = new PublicKey(obj.feePayer);
   }
 
   static deserialize(data: Buffer) {
@@ -438,3 +599,176 @@ export class BondAccount {
     );
   }
 }
+
+/**
+ * An entry of the admin log
+ */
+export class AdminLogEntry {
+  slot: BN;
+  action: number;
+  target: PublicKey;
+  paramsHash: Uint8Array;
+
+  constructor(obj: {
+    slot: BN;
+    action: number;
+    target: Uint8Array;
+    paramsHash: Uint8Array;
+  }) {
+    this.slot = obj.slot;
+    this.action = obj.action;
+    this.target = new PublicKey(obj.target);
+    this.paramsHash = obj.paramsHash;
+  }
+}
+
+/**
+ * The ring buffer of the central state authority actions
+ */
+export class AdminLog {
+  tag: Tag;
+  totalEntries: BN;
+  entries: AdminLogEntry[];
+
+  static schema: Schema = new Map<any, any>([
+    [
+      AdminLog,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["totalEntries", "u64"],
+          ["entries", [AdminLogEntry]],
+        ],
+      },
+    ],
+    [
+      AdminLogEntry,
+      {
+        kind: "struct",
+        fields: [
+          ["slot", "u64"],
+          ["action", "u8"],
+          ["target", [32]],
+          ["paramsHash", [32]],
+        ],
+      },
+    ],
+  ]);
+
+  constructor(obj: {
+    tag: number;
+    totalEntries: BN;
+    entries: AdminLogEntry[];
+  }) {
+    this.tag = obj.tag as Tag;
+    this.totalEntries = obj.totalEntries;
+    this.entries = obj.entries;
+  }
+
+  static deserialize(data: Buffer) {
+    return deserialize(this.schema, AdminLog, data);
+  }
+
+  /**
+   * This method can be used to retrieve the state of the admin log
+   * @param connection The Solana RPC connection
+   * @param key The key of the admin log
+   * @returns
+   */
+  static async retrieve(connection: Connection, key: PublicKey) {
+    const accountInfo = await connection.getAccountInfo(key);
+    if (!accountInfo || !accountInfo.data) {
+      throw new Error("Admin log not found");
+    }
+    return this.deserialize(accountInfo.data);
+  }
+
+  /**
+   * This method can be used to derive the admin log key
+   * @param programId The ACCESS program ID
+   * @returns
+   */
+  static getKey(programId: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_log")],
+      programId
+    );
+  }
+}
+
+/**
+ * The reason and expiry of a frozen account
+ */
+export class FreezeRecord {
+  tag: Tag;
+  account: PublicKey;
+  reason: number;
+  frozenAt: BN;
+  expiry: BN;
+  feePayer: PublicKey;
+
+  static schema: Schema = new Map([
+    [
+      FreezeRecord,
+      {
+        kind: "struct",
+        fields: [
+          ["tag", "u8"],
+          ["account", [32]],
+          ["reason", "u8"],
+          ["frozenAt", "u64"],
+          ["expiry", "u64"],
+          ["feePayer", [32]],
+        ],
+      },
+    ],
+  ]);
+
+  constructor(obj: {
+    tag: number;
+    account: Uint8Array;
+    reason: number;
+    frozenAt: BN;
+    expiry: BN;
+    feePayer: Uint8Array;
+  }) {
+    this.tag = obj.tag as Tag;
+    this.account = new PublicKey(obj.account);
+    this.reason = obj.reason;
+    this.frozenAt = obj.frozenAt.fromTwos(64);
+    this.expiry = obj.expiry.fromTwos(64);
+    this.feePayer = new PublicKey(obj.feePayer);
+  }
+
+  static deserialize(data: Buffer) {
+    return deserialize(this.schema, FreezeRecord, data);
+  }
+
+  /**
+   * This method can be used to retrieve the freeze record of an account
+   * @param connection The Solana RPC connection
+   * @param key The key of the freeze record
+   * @returns
+   */
+  static async retrieve(connection: Connection, key: PublicKey) {
+    const accountInfo = await connection.getAccountInfo(key);
+    if (!accountInfo || !accountInfo.data) {
+      throw new Error("Freeze record not found");
+    }
+    return this.deserialize(accountInfo.data);
+  }
+
+  /**
+   * This method can be used to derive the freeze record key
+   * @param programId The ACCESS program ID
+   * @param account The frozen account
+   * @returns
+   */
+  static getKey(programId: PublicKey, account: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("freeze_record"), account.toBuffer()],
+      programId
+    );
+  }
+}
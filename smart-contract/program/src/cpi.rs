@@ -1,6 +1,14 @@
+/// Access checks for third party programs
+pub mod access;
+
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
-    rent::Rent, system_instruction::create_account, sysvar::Sysvar,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction::{create_account, transfer},
+    sysvar::Sysvar,
 };
 
 #[allow(missing_docs)]
@@ -34,4 +42,23 @@ impl Cpi {
             &[signer_seeds],
         )
     }
+
+    /// Grows a program account to `new_len` bytes, the fee payer tops up the rent
+    pub fn realloc_account<'a>(
+        system_program: &AccountInfo<'a>,
+        fee_payer: &AccountInfo<'a>,
+        account: &AccountInfo<'a>,
+        new_len: usize,
+    ) -> ProgramResult {
+        let missing_lamports = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(account.lamports());
+        if missing_lamports != 0 {
+            invoke(
+                &transfer(fee_payer.key, account.key, missing_lamports),
+                &[system_program.clone(), fee_payer.clone(), account.clone()],
+            )?;
+        }
+        account.realloc(new_len, true)
+    }
 }
@@ -0,0 +1,134 @@
+//! Helpers allowing third party programs to check that a wallet has access to a stake pool
+//!
+//! The accounts are only read, these helpers can be called from any program without a CPI
+//! into the ACCESS program.
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{
+    AccessDelegation, BondAccount, StakeAccount, StakePoolHeader, StakePoolRef, Tag,
+};
+use crate::utils::{assert_bond_derivation, check_account_key, check_account_owner};
+
+/// Returns `true` if `owner` stakes at least the minimum amount of the stake pool
+///
+/// * `stake_pool_account` The stake pool of the publisher
+/// * `stake_account` The stake account of `owner` in the stake pool
+/// * `bond_account` Optional bond bought by `owner` in the stake pool, bond tokens count toward the minimum
+pub fn has_access(
+    owner: &Pubkey,
+    stake_pool_account: &AccountInfo,
+    stake_account: &AccountInfo,
+    bond_account: Option<&AccountInfo>,
+) -> Result<bool, ProgramError> {
+    // Check ownership
+    check_account_owner(
+        stake_pool_account,
+        &crate::ID,
+        AccessError::WrongStakePoolAccountOwner,
+    )?;
+    check_account_owner(
+        stake_account,
+        &crate::ID,
+        AccessError::WrongStakeAccountOwner,
+    )?;
+
+    // Check tags
+    let stake_pool_header = StakePoolHeader::get_checked(stake_pool_account, vec![Tag::StakePool])?;
+    if stake_account.data.borrow()[0] != Tag::StakeAccount as u8 {
+        return Err(AccessError::DataTypeMismatch.into());
+    }
+    let stake_account_data = StakeAccount::from_account_info(stake_account)?;
+
+    // Check derivation
+    let stake_pool_key = StakePoolRef::create_key(
+        &stake_pool_header.nonce,
+        &Pubkey::new(&stake_pool_header.owner),
+        &crate::ID,
+    )?;
+    check_account_key(
+        stake_pool_account,
+        &stake_pool_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    let (stake_account_key, _) = StakeAccount::find_key(owner, &stake_pool_key, &crate::ID);
+    check_account_key(
+        stake_account,
+        &stake_account_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    if stake_account_data.stake_pool != stake_pool_key {
+        return Err(AccessError::StakePoolMismatch.into());
+    }
+
+    let mut amount_in_bonds: u64 = 0;
+    if let Some(bond_account) = bond_account {
+        check_account_owner(bond_account, &crate::ID, AccessError::WrongBondAccountOwner)?;
+        let bond = BondAccount::from_account_info(bond_account, false)?;
+        assert_bond_derivation(bond_account, owner, bond.total_amount_sold, &crate::ID)?;
+        if &bond.owner != owner {
+            return Err(AccessError::StakeAccountOwnerMismatch.into());
+        }
+        if bond.stake_pool != stake_pool_key {
+            return Err(AccessError::StakePoolMismatch.into());
+        }
+        amount_in_bonds = bond.total_staked;
+    }
+
+    let required_amount = std::cmp::min(
+        stake_account_data.pool_minimum_at_creation,
+        stake_pool_header.minimum_stake_amount,
+    );
+    let staked_amount = stake_account_data
+        .stake_amount
+        .checked_add(amount_in_bonds)
+        .ok_or(AccessError::Overflow)?;
+
+    Ok(staked_amount >= required_amount)
+}
+
+/// Returns `true` if `member` is part of an access delegation of a stake account that has access
+///
+/// * `stake_pool_account` The stake pool of the publisher
+/// * `stake_account` The stake account sharing its access
+/// * `access_delegation` The access delegation of `stake_account`
+pub fn has_delegated_access(
+    member: &Pubkey,
+    stake_pool_account: &AccountInfo,
+    stake_account: &AccountInfo,
+    access_delegation: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    check_account_owner(access_delegation, &crate::ID, AccessError::WrongOwner)?;
+    let delegation = AccessDelegation::from_account_info(access_delegation)?;
+    let (delegation_key, _) = AccessDelegation::find_key(stake_account.key, &crate::ID);
+    check_account_key(
+        access_delegation,
+        &delegation_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+
+    if !delegation.is_member(member) {
+        return Ok(false);
+    }
+
+    has_access(&delegation.owner, stake_pool_account, stake_account, None)
+}
+
+/// Fails with [`AccessError::NotEnoughStake`] if `owner` does not have access to the stake pool
+///
+/// See [`has_access`] for the description of the accounts
+pub fn assert_has_access(
+    owner: &Pubkey,
+    stake_pool_account: &AccountInfo,
+    stake_account: &AccountInfo,
+    bond_account: Option<&AccountInfo>,
+) -> ProgramResult {
+    if !has_access(owner, stake_pool_account, stake_account, bond_account)? {
+        msg!("{} does not stake enough to have access", owner);
+        return Err(AccessError::NotEnoughStake.into());
+    }
+    Ok(())
+}
@@ -66,10 +66,10 @@ impl PrintProgramError for AccessError {
                 msg!("Error: The stake account owner must sign")
             }
             AccessError::WrongSplTokenProgramId => {
-                msg!("Error: Wrong SPL token program ID")
+                msg!("Error: Wrong token program ID")
             }
             AccessError::WrongTokenAccountOwner => {
-                msg!("Error: Source token account must be owned by SPL Token")
+                msg!("Error: Source token account must be owned by the token program")
             }
             AccessError::WrongStakeAccountOwner => {
                 msg!("Error: Stake account must be program owned")
@@ -164,6 +164,90 @@ impl PrintProgramError for AccessError {
             AccessError::WrongBondAccountOwner => {
                 msg!("Wrong bond account owner")
             }
+            AccessError::InvalidPoolWeight => {
+                msg!("Invalid pool weight")
+            }
+            AccessError::CampaignVaultMismatch => {
+                msg!("Campaign vault mismatch")
+            }
+            AccessError::CampaignNotOver => {
+                msg!("Campaign is not over")
+            }
+            AccessError::NotEnoughStake => {
+                msg!("Not enough stake to have access")
+            }
+            AccessError::WrongAssociatedTokenProgram => {
+                msg!("Wrong associated token program")
+            }
+            AccessError::TooManyDelegationMembers => {
+                msg!("Too many access delegation members")
+            }
+            AccessError::NotAllowlisted => {
+                msg!("The wallet is not a member of this allowlisted pool")
+            }
+            AccessError::ReferrerMismatch => {
+                msg!("The referrer does not match the one of the stake account")
+            }
+            AccessError::BondExpired => {
+                msg!("The bond has expired")
+            }
+            AccessError::BondNotExpired => {
+                msg!("The bond has not expired")
+            }
+            AccessError::WrongFeePayer => {
+                msg!("Wrong fee payer")
+            }
+            AccessError::InvalidMerkleProof => {
+                msg!("Invalid Merkle proof")
+            }
+            AccessError::AlreadyClaimed => {
+                msg!("The leaf has already been claimed")
+            }
+            AccessError::DistributionBudgetExceeded => {
+                msg!("The distribution budget is exceeded")
+            }
+            AccessError::TooManyIndexedPositions => {
+                msg!("Too many positions in the user index")
+            }
+            AccessError::SelfActivationDisabled => {
+                msg!("Stake pools can only be activated by the central state authority")
+            }
+            AccessError::WrongAdminLog => {
+                msg!("Wrong admin log account")
+            }
+            AccessError::InvalidFreezeExpiry => {
+                msg!("The freeze expiry must be in the future")
+            }
+            AccessError::WrongFreezeRecord => {
+                msg!("Wrong freeze record")
+            }
+            AccessError::FreezeNotExpired => {
+                msg!("The freeze has not expired")
+            }
+            AccessError::WrongQuoteMint => {
+                msg!("Wrong quote mint")
+            }
+            AccessError::AccountNotMigrated => {
+                msg!("The account must be migrated to the current layout")
+            }
+            AccessError::MissingCampaignPositions => {
+                msg!("All the campaign positions of the stake account must be passed")
+            }
+            AccessError::TooManyCampaignPositions => {
+                msg!("Too many campaign positions for the stake account")
+            }
+            AccessError::CampaignNotFunded => {
+                msg!("The campaign vault does not hold enough rewards")
+            }
+            AccessError::WrongCampaignPosition => {
+                msg!("Wrong campaign position")
+            }
+            AccessError::ReceiptNotBurnt => {
+                msg!("The subscription receipt must be burnt first")
+            }
+            AccessError::EarlyUnlockDisabled => {
+                msg!("Early unlock is disabled until a penalty is set")
+            }
         }
     }
 }
@@ -27,9 +27,9 @@ pub enum AccessError {
     StakeAccountMustBeEmpty,
     #[error("The stake account owner must sign")]
     StakeAccountOwnerMustSign,
-    #[error("Wrong SPL token program ID")]
+    #[error("Wrong token program ID")]
     WrongSplTokenProgramId,
-    #[error("Source token account must be owned by SPL Token")]
+    #[error("Source token account must be owned by the token program")]
     WrongTokenAccountOwner,
     #[error("Bond account must be owned by the program")]
     WrongBondAccountOwner,
@@ -93,6 +93,62 @@ pub enum AccessError {
     ForbiddenUnlockPeriodZero,
     #[error("Wrong MPL metadata program")]
     WrongMplProgram,
+    #[error("Invalid pool weight")]
+    InvalidPoolWeight,
+    #[error("Campaign vault mismatch")]
+    CampaignVaultMismatch,
+    #[error("Campaign is not over")]
+    CampaignNotOver,
+    #[error("Not enough stake to have access")]
+    NotEnoughStake,
+    #[error("Wrong associated token program")]
+    WrongAssociatedTokenProgram,
+    #[error("Too many access delegation members")]
+    TooManyDelegationMembers,
+    #[error("The wallet is not a member of this allowlisted pool")]
+    NotAllowlisted,
+    #[error("The referrer does not match the one of the stake account")]
+    ReferrerMismatch,
+    #[error("The bond has expired")]
+    BondExpired,
+    #[error("The bond has not expired")]
+    BondNotExpired,
+    #[error("Wrong fee payer")]
+    WrongFeePayer,
+    #[error("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    #[error("The leaf has already been claimed")]
+    AlreadyClaimed,
+    #[error("The distribution budget is exceeded")]
+    DistributionBudgetExceeded,
+    #[error("Too many positions in the user index")]
+    TooManyIndexedPositions,
+    #[error("Stake pools can only be activated by the central state authority")]
+    SelfActivationDisabled,
+    #[error("Wrong admin log account")]
+    WrongAdminLog,
+    #[error("The freeze expiry must be in the future")]
+    InvalidFreezeExpiry,
+    #[error("Wrong freeze record")]
+    WrongFreezeRecord,
+    #[error("The freeze has not expired")]
+    FreezeNotExpired,
+    #[error("Wrong quote mint")]
+    WrongQuoteMint,
+    #[error("The account must be migrated to the current layout")]
+    AccountNotMigrated,
+    #[error("All the campaign positions of the stake account must be passed")]
+    MissingCampaignPositions,
+    #[error("Too many campaign positions for the stake account")]
+    TooManyCampaignPositions,
+    #[error("The campaign vault does not hold enough rewards")]
+    CampaignNotFunded,
+    #[error("Wrong campaign position")]
+    WrongCampaignPosition,
+    #[error("The subscription receipt must be burnt first")]
+    ReceiptNotBurnt,
+    #[error("Early unlock is disabled until a penalty is set")]
+    EarlyUnlockDisabled,
 }
 
 impl From<AccessError> for ProgramError {
@@ -1,9 +1,19 @@
 pub use crate::processor::{
-    activate_stake_pool, admin_freeze, admin_mint, change_central_state_authority,
-    change_inflation, change_pool_minimum, change_pool_multiplier, claim_bond, claim_bond_rewards,
-    claim_pool_rewards, claim_rewards, close_stake_account, close_stake_pool, crank, create_bond,
-    create_central_state, create_stake_account, create_stake_pool, edit_metadata, sign_bond, stake,
-    unlock_bond_tokens, unstake,
+    activate_stake_pool, add_pool_member, admin_freeze, admin_mint, burn_receipt, cancel_bond,
+    cancel_bond_listing, change_activation_deposit, change_central_state_authority,
+    change_crank_bounty, change_early_unlock_penalty, change_inflation, change_pool_minimum,
+    change_pool_multiplier, change_referral_rates, claim_bond, claim_bond_rewards,
+    claim_campaign_rewards, claim_distribution, claim_multiple_rewards, claim_pool_rewards,
+    claim_rewards, claim_round_bond, close_access_delegation, close_stake_account,
+    close_stake_pool, close_user_index, crank, create_access_delegation, create_admin_log,
+    create_bond, create_bond_auction, create_bond_round, create_central_state, create_distributor,
+    create_reward_campaign, create_stake_account, create_stake_pool, create_user_index,
+    deprecate_stake_pool, early_unlock_bond, edit_access_delegation, edit_metadata, expire_freeze,
+    extend_reward_campaign, fill_bond_auction, fill_bond_listing, fund_reward_campaign,
+    get_bond_unlockable, get_claimable_rewards, get_pool_claimable_rewards, has_access, list_bond,
+    migrate_account, mint_receipt, move_bond_stake, reclaim_campaign_rewards, remove_pool_member,
+    self_activate_stake_pool, set_pool_allowlist, set_pool_weight, sign_bond, sign_bond_round,
+    stake, transfer_bond, unlock_bond_tokens, unstake,
 };
 use bonfida_utils::InstructionsAccount;
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -32,47 +42,59 @@ pub enum ProgramInstruction {
     CreateStakePool,
     /// Activate a stake pool
     ///
-    /// | Index | Writable | Signer | Description                      |
-    /// | ------------------------------------------------------------ |
-    /// | 0     | ❌        | ✅      | The central state authority      |
-    /// | 1     | ✅        | ❌      | The stake pool to activate       |
-    /// | 2     | ❌        | ❌      | The account of the central state |
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ❌        | ✅      | The central state authority                      |
+    /// | 1     | ✅        | ❌      | The stake pool to activate                       |
+    /// | 2     | ❌        | ❌      | The account of the central state                 |
+    /// | 3     | ✅        | ❌      | The admin log, required once it has been created |
     ActivateStakePool,
     /// Create stake account
     ///
-    /// | Index | Writable | Signer | Description                |
-    /// | ------------------------------------------------------ |
-    /// | 0     | ✅        | ❌      | The stake account          |
-    /// | 1     | ❌        | ❌      | The system program account |
-    /// | 2     | ❌        | ❌      | The stake pool account     |
-    /// | 3     | ✅        | ✅      | The fee payer account      |
+    /// | Index | Writable | Signer | Description                                                          |
+    /// | ------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The stake account                                                    |
+    /// | 1     | ❌        | ❌      | The system program account                                           |
+    /// | 2     | ❌        | ❌      | The stake pool account                                               |
+    /// | 3     | ✅        | ✅      | The fee payer account                                                |
+    /// | 4     | ❌        | ❌      | Optional pool membership of the owner, required by allowlisted pools |
+    /// | 5     | ✅        | ❌      | Optional user index of the owner                                     |
     CreateStakeAccount,
     /// Stake
     ///
-    /// | Index | Writable | Signer | Description                                                 |
-    /// | --------------------------------------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The central state account                                   |
-    /// | 1     | ✅        | ❌      | The stake account                                           |
-    /// | 2     | ✅        | ❌      | The stake pool account                                      |
-    /// | 3     | ❌        | ✅      | The owner of the stake account                              |
-    /// | 4     | ✅        | ❌      | The source account of the stake tokens                      |
-    /// | 5     | ❌        | ❌      | The SPL token program account                               |
-    /// | 6     | ✅        | ❌      | The stake pool vault account                                |
-    /// | 7     | ✅        | ❌      | The stake fee account                                       |
-    /// | 8     | ❌        | ❌      | Optional bond account to be able to stake under the minimum |
+    /// | Index | Writable | Signer | Description                                                          |
+    /// | ------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The central state account                                            |
+    /// | 1     | ✅        | ❌      | The stake account                                                    |
+    /// | 2     | ✅        | ❌      | The stake pool account                                               |
+    /// | 3     | ❌        | ✅      | The owner of the stake account                                       |
+    /// | 4     | ✅        | ❌      | The source account of the stake tokens                               |
+    /// | 5     | ❌        | ❌      | The token program of the ACCESS mint                                 |
+    /// | 6     | ✅        | ❌      | The stake pool vault account                                         |
+    /// | 7     | ✅        | ❌      | The stake fee account                                                |
+    /// | 8     | ❌        | ❌      | Optional bond account to be able to stake under the minimum          |
+    /// | 9     | ❌        | ❌      | Optional mint of the ACCESS token, required by Token-2022 mints      |
+    /// | 10    | ❌        | ❌      | Optional pool membership of the owner, required by allowlisted pools |
+    /// | 11    | ✅        | ❌      | Optional token account of the referrer receiving part of the fees    |
+    /// | 12..  | ✅        | ❌      | Pairs of (reward campaign, campaign position) of the stake account   |
     Stake,
     /// Unstake
     ///
-    /// | Index | Writable | Signer | Description                                                 |
-    /// | --------------------------------------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The central state account                                   |
-    /// | 1     | ✅        | ❌      | The stake account                                           |
-    /// | 2     | ✅        | ❌      | The stake pool account                                      |
-    /// | 3     | ❌        | ✅      | The owner of the stake account                              |
-    /// | 4     | ✅        | ❌      | The destination of the staked tokens                        |
-    /// | 5     | ❌        | ❌      | The SPL token program account                               |
-    /// | 6     | ✅        | ❌      | The stake pool vault                                        |
-    /// | 7     | ❌        | ❌      | Optional bond account to be able to stake under the minimum |
+    /// | Index | Writable | Signer | Description                                                                             |
+    /// | ------------------------------------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The central state account                                                               |
+    /// | 1     | ✅        | ❌      | The stake account                                                                       |
+    /// | 2     | ✅        | ❌      | The stake pool account                                                                  |
+    /// | 3     | ❌        | ✅      | The owner of the stake account                                                          |
+    /// | 4     | ✅        | ❌      | The destination of the staked tokens                                                    |
+    /// | 5     | ❌        | ❌      | The token program of the ACCESS mint                                                    |
+    /// | 6     | ✅        | ❌      | The stake pool vault                                                                    |
+    /// | 7     | ❌        | ❌      | Optional bond account to be able to stake under the minimum                             |
+    /// | 8     | ❌        | ❌      | Optional mint of the ACCESS token, required by Token-2022 mints                         |
+    /// | 9     | ✅        | ❌      | Optional receipt mint of the stake account, burnt when the stake goes below the minimum |
+    /// | 10    | ✅        | ❌      | Optional receipt token account of the stake account                                     |
+    /// | 11    | ❌        | ❌      | Optional SPL token program of the receipt                                               |
+    /// | 12..  | ✅        | ❌      | Pairs of (reward campaign, campaign position) of the stake account                      |
     Unstake,
     /// Claim rewards of a stake pool
     /// This instruction is used by stake pool owner for claiming their staking rewards
@@ -89,47 +111,57 @@ pub enum ProgramInstruction {
     /// Claim rewards of a stake account
     /// This instruction can be used by stakers to claim their staking rewards
     ///
-    /// | Index | Writable | Signer | Description                          |
-    /// | ---------------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The stake pool account               |
-    /// | 1     | ✅        | ❌      | The stake account                    |
-    /// | 2     | ❌        | ✅      | The owner of the stake account       |
-    /// | 3     | ✅        | ❌      | The rewards destination              |
-    /// | 4     | ❌        | ❌      | The central state account            |
-    /// | 5     | ✅        | ❌      | The mint address of the ACCESS token |
-    /// | 6     | ❌        | ❌      | The SPL token program account        |
+    /// | Index | Writable | Signer | Description                                                                   |
+    /// | --------------------------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The stake pool account                                                        |
+    /// | 1     | ✅        | ❌      | The stake account                                                             |
+    /// | 2     | ❌        | ✅      | The owner of the stake account                                                |
+    /// | 3     | ✅        | ❌      | The rewards destination                                                       |
+    /// | 4     | ❌        | ❌      | The central state account                                                     |
+    /// | 5     | ✅        | ❌      | The mint address of the ACCESS token                                          |
+    /// | 6     | ❌        | ❌      | The SPL token program account                                                 |
+    /// | 7     | ✅        | ❌      | Optional token account of the referrer, required when the staker was referred |
     ClaimRewards,
     /// Permissionless crank to update the stake pool rewards
     /// This instructions updates the circular buffer with the pool balances multiplied by the current inflation
     ///
-    /// | Index | Writable | Signer | Description                      |
-    /// | ------------------------------------------------------------ |
-    /// | 0     | ✅        | ❌      | The stake pool account           |
-    /// | 1     | ✅        | ❌      | The account of the central state |
+    /// | Index | Writable | Signer | Description                                                                |
+    /// | ------------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The stake pool account                                                     |
+    /// | 1     | ✅        | ❌      | The account of the central state                                           |
+    /// | 2     | ✅        | ❌      | Optional token account receiving the crank bounty                          |
+    /// | 3     | ✅        | ❌      | The mint address of the ACCESS token, required with the bounty destination |
+    /// | 4     | ❌        | ❌      | The SPL token program account, required with the bounty destination        |
     Crank,
     /// Close a stake pool
     /// This instruction can be used to close an empty stake pool and collect the lamports
+    /// The activation deposit of a self activated pool is refunded to the owner
     ///
-    /// | Index | Writable | Signer | Description                   |
-    /// | --------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The account of the stake pool |
-    /// | 1     | ❌        | ❌      | Pool vault                    |
-    /// | 2     | ✅        | ✅      | The owner of the stake pool   |
+    /// | Index | Writable | Signer | Description                                                                            |
+    /// | ------------------------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The account of the stake pool                                                          |
+    /// | 1     | ✅        | ❌      | Pool vault                                                                             |
+    /// | 2     | ✅        | ✅      | The owner of the stake pool                                                            |
+    /// | 3     | ✅        | ❌      | The token account receiving the activation deposit (required for self activated pools) |
+    /// | 4     | ❌        | ❌      | The token program of the ACCESS mint (required for self activated pools)               |
+    /// | 5     | ❌        | ❌      | The mint of the ACCESS token (required for self activated pools)                       |
     CloseStakePool,
     /// Close a stake account
     /// This instruction can be used to close an empty stake account and collect the lamports
     ///
-    /// | Index | Writable | Signer | Description                    |
-    /// | ---------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The stake account              |
-    /// | 1     | ✅        | ✅      | The owner of the stake account |
+    /// | Index | Writable | Signer | Description                      |
+    /// | ------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The stake account                |
+    /// | 1     | ✅        | ✅      | The owner of the stake account   |
+    /// | 2     | ✅        | ❌      | Optional user index of the owner |
     CloseStakeAccount,
     /// Change central state inflation
     ///
-    /// | Index | Writable | Signer | Description                                |
-    /// | ---------------------------------------------------------------------- |
-    /// | 0     | ✅        | ❌      | The account of the central state           |
-    /// | 1     | ❌        | ✅      | The account of the central state authority |
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The account of the central state                 |
+    /// | 1     | ❌        | ✅      | The account of the central state authority       |
+    /// | 2     | ✅        | ❌      | The admin log, required once it has been created |
     ChangeInflation,
     /// Create a bond
     /// This instruction can be used by authorized sellers to create a bond
@@ -177,7 +209,10 @@ pub enum ProgramInstruction {
     /// | 5     | ✅        | ❌      | The mint of the ACCESS token                     |
     /// | 6     | ✅        | ❌      | The vault of the stake pool                      |
     /// | 7     | ✅        | ❌      | The central state account                        |
-    /// | 8     | ❌        | ❌      | The SPL token program account                    |
+    /// | 8     | ❌        | ❌      | The token program of the ACCESS mint             |
+    /// | 9     | ❌        | ❌      | The mint of the quote token                      |
+    /// | 10    | ❌        | ❌      | The token program of the quote mint              |
+    /// | 11    | ✅        | ❌      | Optional user index of the buyer                 |
     ClaimBond,
     /// Claim bond rewards
     /// This Instruction allows bond owners to claim their staking rewards
@@ -202,22 +237,29 @@ pub enum ProgramInstruction {
     ChangePoolMinimum,
     /// Allows central state authority to mint ACCESS tokens
     ///
-    /// | Index | Writable | Signer | Description                      |
-    /// | ------------------------------------------------------------ |
-    /// | 0     | ❌        | ✅      | The central state authority      |
-    /// | 1     | ✅        | ❌      | The ACCESS mint token            |
-    /// | 2     | ✅        | ❌      | The ACCESS token destination     |
-    /// | 3     | ❌        | ❌      | The account of the central state |
-    /// | 4     | ❌        | ❌      | The SPL token program account    |
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ❌        | ✅      | The central state authority                      |
+    /// | 1     | ✅        | ❌      | The ACCESS mint token                            |
+    /// | 2     | ✅        | ❌      | The ACCESS token destination                     |
+    /// | 3     | ❌        | ❌      | The account of the central state                 |
+    /// | 4     | ❌        | ❌      | The SPL token program account                    |
+    /// | 5     | ✅        | ❌      | The admin log, required once it has been created |
     AdminMint,
-    /// Freeze and unfreeze a program account
+    /// Freeze and unfreeze program accounts
     /// This admin instruction can be dangereous 💀
+    /// Freezing records the reason and the expiry of the freeze in a freeze record
     ///
-    /// | Index | Writable | Signer | Description                         |
-    /// | --------------------------------------------------------------- |
-    /// | 0     | ❌        | ✅      | The central state authority         |
-    /// | 1     | ✅        | ❌      | The account to freeze (or unfreeze) |
-    /// | 2     | ❌        | ❌      | The account of the central state    |
+    /// | Index | Writable | Signer | Description                                                                                          |
+    /// | -------------------------------------------------------------------------------------------------------------------------------- |
+    /// | 0     | ❌        | ✅      | The central state authority                                                                          |
+    /// | 1     | ✅        | ❌      | The account to freeze (or unfreeze)                                                                  |
+    /// | 2     | ❌        | ❌      | The account of the central state                                                                     |
+    /// | 3     | ✅        | ❌      | The admin log (required once it has been created)                                                    |
+    /// | 4     | ❌        | ❌      | The system program account, required to record the freeze                                            |
+    /// | 5     | ✅        | ✅      | The fee account, required to record the freeze, the account that paid for the record when unfreezing |
+    /// | 6     | ✅        | ❌      | The freeze record of the account to freeze (or unfreeze)                                             |
+    /// | 7..   | ✅        | ❌      | Pairs of (account to freeze or unfreeze, freeze record) of the other accounts                        |
     AdminFreeze,
     ///
     /// | Index | Writable | Signer | Description                  |
@@ -227,20 +269,473 @@ pub enum ProgramInstruction {
     ChangePoolMultiplier,
     /// Change central state authority
     ///
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The account of the central state                 |
+    /// | 1     | ❌        | ✅      | The account of the central state authority       |
+    /// | 2     | ✅        | ❌      | The admin log, required once it has been created |
+    ChangeCentralStateAuthority,
+    /// Edit metadata
+    ///
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The central state account                        |
+    /// | 1     | ❌        | ✅      | The account of the central state authority       |
+    /// | 2     | ✅        | ❌      | The metadata account                             |
+    /// | 3     | ❌        | ❌      | The metadata program account                     |
+    /// | 4     | ✅        | ❌      | The admin log, required once it has been created |
+    EditMetadata,
+    /// Set the emission weight of a stake pool
+    ///
     /// | Index | Writable | Signer | Description                                |
     /// | ---------------------------------------------------------------------- |
     /// | 0     | ✅        | ❌      | The account of the central state           |
     /// | 1     | ❌        | ✅      | The account of the central state authority |
-    ChangeCentralStateAuthority,
-    /// Edit metadata
+    /// | 2     | ✅        | ❌      | The stake pool account                     |
+    SetPoolWeight,
+    /// Create a reward campaign
+    ///
+    /// | Index | Writable | Signer | Description                  |
+    /// | -------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account       |
+    /// | 1     | ❌        | ✅      | The stake pool owner account |
+    /// | 2     | ✅        | ❌      | The reward campaign account  |
+    /// | 3     | ❌        | ❌      | The campaign vault account   |
+    /// | 4     | ❌        | ❌      | The mint of the reward token |
+    /// | 5     | ❌        | ❌      | The system program account   |
+    /// | 6     | ✅        | ✅      | The fee payer account        |
+    CreateRewardCampaign,
+    /// Fund a reward campaign
+    ///
+    /// | Index | Writable | Signer | Description                             |
+    /// | ------------------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The reward campaign account             |
+    /// | 1     | ❌        | ✅      | The stake pool owner account            |
+    /// | 2     | ✅        | ❌      | The source account of the reward tokens |
+    /// | 3     | ✅        | ❌      | The campaign vault account              |
+    /// | 4     | ❌        | ❌      | The SPL token program account           |
+    /// | 5     | ❌        | ❌      | The mint of the reward token            |
+    FundRewardCampaign,
+    /// Extend a reward campaign
+    ///
+    /// | Index | Writable | Signer | Description                  |
+    /// | -------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The reward campaign account  |
+    /// | 1     | ❌        | ❌      | The stake pool account       |
+    /// | 2     | ❌        | ✅      | The stake pool owner account |
+    /// | 3     | ❌        | ❌      | The campaign vault account   |
+    ExtendRewardCampaign,
+    /// Reclaim the leftovers of a reward campaign
+    ///
+    /// | Index | Writable | Signer | Description                                   |
+    /// | ------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The reward campaign account                   |
+    /// | 1     | ❌        | ❌      | The stake pool account                        |
+    /// | 2     | ❌        | ✅      | The stake pool owner account                  |
+    /// | 3     | ✅        | ❌      | The campaign vault account                    |
+    /// | 4     | ✅        | ❌      | The destination of the leftover reward tokens |
+    /// | 5     | ❌        | ❌      | The SPL token program account                 |
+    /// | 6     | ❌        | ❌      | The mint of the reward token                  |
+    ReclaimCampaignRewards,
+    /// Claim the rewards of a reward campaign
+    ///
+    /// | Index | Writable | Signer | Description                                |
+    /// | ---------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The reward campaign account                |
+    /// | 1     | ❌        | ❌      | The stake pool account                     |
+    /// | 2     | ✅        | ❌      | The stake account                          |
+    /// | 3     | ✅        | ✅      | The owner of the stake account             |
+    /// | 4     | ✅        | ❌      | The campaign position of the stake account |
+    /// | 5     | ✅        | ❌      | The campaign vault account                 |
+    /// | 6     | ✅        | ❌      | The rewards destination                    |
+    /// | 7     | ❌        | ❌      | The SPL token program account              |
+    /// | 8     | ❌        | ❌      | The mint of the reward token               |
+    /// | 9     | ❌        | ❌      | The system program account                 |
+    ClaimCampaignRewards,
+    /// Change the bounty paid to crankers
     ///
     /// | Index | Writable | Signer | Description                                |
     /// | ---------------------------------------------------------------------- |
-    /// | 0     | ❌        | ❌      | The central state account                  |
+    /// | 0     | ✅        | ❌      | The account of the central state           |
     /// | 1     | ❌        | ✅      | The account of the central state authority |
-    /// | 2     | ✅        | ❌      | The metadata account                       |
-    /// | 3     | ❌        | ❌      | The metadata program account               |
-    EditMetadata,
+    ChangeCrankBounty,
+    /// Get the claimable rewards of a stake account
+    ///
+    /// | Index | Writable | Signer | Description               |
+    /// | ----------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account    |
+    /// | 1     | ❌        | ❌      | The stake account         |
+    /// | 2     | ❌        | ❌      | The central state account |
+    GetClaimableRewards,
+    /// Get the claimable rewards of a stake pool owner
+    ///
+    /// | Index | Writable | Signer | Description               |
+    /// | ----------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account    |
+    /// | 1     | ❌        | ❌      | The central state account |
+    GetPoolClaimableRewards,
+    /// Get the amount of tokens that can currently be unlocked from a bond
+    ///
+    /// | Index | Writable | Signer | Description      |
+    /// | -------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The bond account |
+    GetBondUnlockable,
+    /// Check whether a stake account grants access to a stake pool
+    ///
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account                           |
+    /// | 1     | ❌        | ❌      | The stake account                                |
+    /// | 2     | ❌        | ❌      | Optional bond account of the stake account owner |
+    HasAccess,
+    /// Mint a subscription receipt
+    ///
+    /// | Index | Writable | Signer | Description                            |
+    /// | ------------------------------------------------------------------ |
+    /// | 0     | ❌        | ❌      | The central state account              |
+    /// | 1     | ❌        | ❌      | The stake pool account                 |
+    /// | 2     | ✅        | ❌      | The stake account                      |
+    /// | 3     | ✅        | ✅      | The owner of the stake account         |
+    /// | 4     | ✅        | ❌      | The receipt mint account               |
+    /// | 5     | ✅        | ❌      | The receipt token account of the owner |
+    /// | 6     | ✅        | ❌      | The receipt metadata account           |
+    /// | 7     | ❌        | ❌      | The metadata program account           |
+    /// | 8     | ❌        | ❌      | The SPL token program account          |
+    /// | 9     | ❌        | ❌      | The associated token program account   |
+    /// | 10    | ❌        | ❌      | The system program account             |
+    /// | 11    | ❌        | ❌      | The rent sysvar account                |
+    MintReceipt,
+    /// Burn a subscription receipt
+    ///
+    /// | Index | Writable | Signer | Description                         |
+    /// | --------------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The central state account           |
+    /// | 1     | ❌        | ❌      | The stake pool account              |
+    /// | 2     | ✅        | ❌      | The stake account                   |
+    /// | 3     | ✅        | ❌      | The receipt mint account            |
+    /// | 4     | ✅        | ❌      | The receipt token account           |
+    /// | 5     | ❌        | ❌      | The SPL token program account       |
+    /// | 6     | ❌        | ✅      | Optional owner of the stake account |
+    BurnReceipt,
+    /// Create an access delegation
+    ///
+    /// | Index | Writable | Signer | Description                    |
+    /// | ---------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake account              |
+    /// | 1     | ✅        | ❌      | The access delegation account  |
+    /// | 2     | ✅        | ✅      | The owner of the stake account |
+    /// | 3     | ❌        | ❌      | The system program account     |
+    CreateAccessDelegation,
+    /// Edit an access delegation
+    ///
+    /// | Index | Writable | Signer | Description                    |
+    /// | ---------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The access delegation account  |
+    /// | 1     | ❌        | ✅      | The owner of the stake account |
+    EditAccessDelegation,
+    /// Close an access delegation
+    ///
+    /// | Index | Writable | Signer | Description                    |
+    /// | ---------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The access delegation account  |
+    /// | 1     | ✅        | ✅      | The owner of the stake account |
+    CloseAccessDelegation,
+    /// Turn the allowlist of a pool on or off
+    ///
+    /// | Index | Writable | Signer | Description                  |
+    /// | -------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The stake pool account       |
+    /// | 1     | ❌        | ✅      | The stake pool owner account |
+    SetPoolAllowlist,
+    /// Add a member to an allowlisted pool
+    ///
+    /// | Index | Writable | Signer | Description                  |
+    /// | -------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account       |
+    /// | 1     | ❌        | ✅      | The stake pool owner account |
+    /// | 2     | ✅        | ❌      | The pool membership account  |
+    /// | 3     | ❌        | ❌      | The system program account   |
+    /// | 4     | ✅        | ✅      | The fee payer account        |
+    AddPoolMember,
+    /// Remove a member from an allowlisted pool
+    ///
+    /// | Index | Writable | Signer | Description                  |
+    /// | -------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The stake pool account       |
+    /// | 1     | ✅        | ✅      | The stake pool owner account |
+    /// | 2     | ✅        | ❌      | The pool membership account  |
+    RemovePoolMember,
+    /// Change the share of the fees and rewards going to referrers
+    ///
+    /// | Index | Writable | Signer | Description                                |
+    /// | ---------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The account of the central state           |
+    /// | 1     | ❌        | ✅      | The account of the central state authority |
+    ChangeReferralRates,
+    /// Transfer a bond to another wallet
+    ///
+    /// | Index | Writable | Signer | Description                                                                 |
+    /// | ------------------------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The bond account                                                            |
+    /// | 1     | ❌        | ✅      | The current owner of the bond                                               |
+    /// | 2     | ✅        | ❌      | The stake pool of the bond                                                  |
+    /// | 3     | ❌        | ❌      | The central state account                                                   |
+    /// | 4     | ✅        | ❌      | The ACCESS mint token                                                       |
+    /// | 5     | ✅        | ❌      | The ACCESS token account of the current owner receiving the pending rewards |
+    /// | 6     | ❌        | ❌      | The SPL token program account                                               |
+    /// | 7..   | ✅        | ❌      | Optional user indexes of the current and new owners                         |
+    TransferBond,
+    /// List a bond on the secondary market
+    ///
+    /// | Index | Writable | Signer | Description                                                |
+    /// | -------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The bond account                                           |
+    /// | 1     | ✅        | ✅      | The owner of the bond                                      |
+    /// | 2     | ✅        | ❌      | The bond listing account                                   |
+    /// | 3     | ❌        | ❌      | The token account of the seller receiving the quote tokens |
+    /// | 4     | ❌        | ❌      | The system program account                                 |
+    ListBond,
+    /// Cancel a bond listing
+    ///
+    /// | Index | Writable | Signer | Description              |
+    /// | ---------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The bond listing account |
+    /// | 1     | ✅        | ❌      | The bond account         |
+    /// | 2     | ✅        | ✅      | The seller of the bond   |
+    CancelBondListing,
+    /// Buy a listed bond
+    ///
+    /// | Index | Writable | Signer | Description                                                          |
+    /// | ------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The bond listing account                                             |
+    /// | 1     | ✅        | ❌      | The bond account                                                     |
+    /// | 2     | ❌        | ✅      | The buyer of the bond                                                |
+    /// | 3     | ✅        | ❌      | The quote token account of the buyer                                 |
+    /// | 4     | ✅        | ❌      | The seller of the bond, receives the rent of the listing             |
+    /// | 5     | ✅        | ❌      | The quote token account of the seller                                |
+    /// | 6     | ✅        | ❌      | The ACCESS token account of the seller receiving the pending rewards |
+    /// | 7     | ✅        | ❌      | The stake pool of the bond                                           |
+    /// | 8     | ❌        | ❌      | The central state account                                            |
+    /// | 9     | ✅        | ❌      | The ACCESS mint token                                                |
+    /// | 10    | ❌        | ❌      | The token program of the ACCESS mint                                 |
+    /// | 11    | ❌        | ❌      | The mint of the quote token                                          |
+    /// | 12    | ❌        | ❌      | The token program of the quote mint                                  |
+    /// | 13..  | ✅        | ❌      | Optional user indexes of the seller and the buyer                    |
+    FillBondListing,
+    /// Cancel an expired bond that was never claimed
+    ///
+    /// | Index | Writable | Signer | Description                                |
+    /// | ---------------------------------------------------------------------- |
+    /// | 0     | ❌        | ✅      | The bond seller account                    |
+    /// | 1     | ✅        | ❌      | The bond account                           |
+    /// | 2     | ✅        | ❌      | The account that paid the rent of the bond |
+    /// | 3     | ✅        | ❌      | Optional user index of the bond owner      |
+    CancelBond,
+    /// Change the penalty taken on the bond tokens unlocked early
+    ///
+    /// | Index | Writable | Signer | Description                                |
+    /// | ---------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The account of the central state           |
+    /// | 1     | ❌        | ✅      | The account of the central state authority |
+    ChangeEarlyUnlockPenalty,
+    /// Unlock bond tokens before they vest with a penalty
+    ///
+    /// | Index | Writable | Signer | Description                            |
+    /// | ------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The bond account                       |
+    /// | 1     | ❌        | ✅      | The account of the bond owner          |
+    /// | 2     | ❌        | ❌      | The ACCESS mint token                  |
+    /// | 3     | ✅        | ❌      | The ACCESS token destination           |
+    /// | 4     | ✅        | ❌      | The DAO treasury receiving the penalty |
+    /// | 5     | ✅        | ❌      | The account of the central state       |
+    /// | 6     | ✅        | ❌      | The account of the staking pool        |
+    /// | 7     | ✅        | ❌      | The vault of the staking pool          |
+    /// | 8     | ❌        | ❌      | The SPL token program account          |
+    EarlyUnlockBond,
+    /// Create a Dutch auction selling bonds
+    ///
+    /// | Index | Writable | Signer | Description                |
+    /// | ------------------------------------------------------ |
+    /// | 0     | ❌        | ✅      | The bond seller account    |
+    /// | 1     | ✅        | ❌      | The bond auction account   |
+    /// | 2     | ❌        | ❌      | The stake pool             |
+    /// | 3     | ❌        | ❌      | The system program account |
+    /// | 4     | ✅        | ✅      | The fee account            |
+    CreateBondAuction,
+    /// Buy a bond from a Dutch auction
+    ///
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The bond auction account                         |
+    /// | 1     | ✅        | ❌      | The bond account                                 |
+    /// | 2     | ✅        | ✅      | The account of the bond buyer                    |
+    /// | 3     | ✅        | ❌      | The token account used to purchase the bond      |
+    /// | 4     | ✅        | ❌      | The token account where the sell proceed is sent |
+    /// | 5     | ✅        | ❌      | The stake pool account                           |
+    /// | 6     | ✅        | ❌      | The mint of the ACCESS token                     |
+    /// | 7     | ✅        | ❌      | The vault of the stake pool                      |
+    /// | 8     | ✅        | ❌      | The central state account                        |
+    /// | 9     | ❌        | ❌      | The token program of the ACCESS mint             |
+    /// | 10    | ❌        | ❌      | The mint of the quote token                      |
+    /// | 11    | ❌        | ❌      | The token program of the quote mint              |
+    /// | 12    | ❌        | ❌      | The system program account                       |
+    /// | 13    | ✅        | ❌      | Optional user index of the buyer                 |
+    FillBondAuction,
+    /// Move the locked tokens of a bond to another stake pool
+    ///
+    /// | Index | Writable | Signer | Description                                                                      |
+    /// | ------------------------------------------------------------------------------------------------------------ |
+    /// | 0     | ✅        | ❌      | The bond account                                                                 |
+    /// | 1     | ❌        | ✅      | The account of the bond owner                                                    |
+    /// | 2     | ✅        | ❌      | The stake pool the bond is staked in                                             |
+    /// | 3     | ✅        | ❌      | The vault of the source stake pool                                               |
+    /// | 4     | ✅        | ❌      | The stake pool the bond moves to                                                 |
+    /// | 5     | ✅        | ❌      | The vault of the destination stake pool                                          |
+    /// | 6     | ✅        | ❌      | The account of the central state                                                 |
+    /// | 7     | ❌        | ❌      | The SPL token program account                                                    |
+    /// | 8     | ❌        | ❌      | The mint of the ACCESS token                                                     |
+    /// | 9     | ❌        | ❌      | Optional pool membership of the owner, required by allowlisted destination pools |
+    MoveBondStake,
+    /// Create a bond round
+    ///
+    /// | Index | Writable | Signer | Description                |
+    /// | ------------------------------------------------------ |
+    /// | 0     | ❌        | ✅      | The bond seller account    |
+    /// | 1     | ✅        | ❌      | The bond round account     |
+    /// | 2     | ❌        | ❌      | The stake pool             |
+    /// | 3     | ❌        | ❌      | The system program account |
+    /// | 4     | ✅        | ✅      | The fee account            |
+    CreateBondRound,
+    /// Sign a bond round
+    ///
+    /// | Index | Writable | Signer | Description             |
+    /// | --------------------------------------------------- |
+    /// | 0     | ❌        | ✅      | The bond seller account |
+    /// | 1     | ✅        | ❌      | The bond round account  |
+    SignBondRound,
+    /// Claim a bond of a bond round
+    ///
+    /// | Index | Writable | Signer | Description                                      |
+    /// | ---------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The bond round account                           |
+    /// | 1     | ✅        | ❌      | The bond account                                 |
+    /// | 2     | ✅        | ✅      | The account of the bond buyer                    |
+    /// | 3     | ✅        | ❌      | The token account used to purchase the bond      |
+    /// | 4     | ✅        | ❌      | The token account where the sell proceed is sent |
+    /// | 5     | ✅        | ❌      | The stake pool account                           |
+    /// | 6     | ✅        | ❌      | The mint of the ACCESS token                     |
+    /// | 7     | ✅        | ❌      | The vault of the stake pool                      |
+    /// | 8     | ✅        | ❌      | The central state account                        |
+    /// | 9     | ❌        | ❌      | The token program of the ACCESS mint             |
+    /// | 10    | ❌        | ❌      | The mint of the quote token                      |
+    /// | 11    | ❌        | ❌      | The token program of the quote mint              |
+    /// | 12    | ❌        | ❌      | The system program account                       |
+    /// | 13    | ✅        | ❌      | Optional user index of the buyer                 |
+    ClaimRoundBond,
+    /// Create a Merkle distributor
+    ///
+    /// | Index | Writable | Signer | Description                                |
+    /// | ---------------------------------------------------------------------- |
+    /// | 0     | ❌        | ❌      | The account of the central state           |
+    /// | 1     | ❌        | ✅      | The account of the central state authority |
+    /// | 2     | ✅        | ❌      | The distributor account                    |
+    /// | 3     | ❌        | ❌      | The system program account                 |
+    /// | 4     | ✅        | ✅      | The fee account                            |
+    CreateDistributor,
+    /// Claim tokens from a Merkle distributor
+    ///
+    /// | Index | Writable | Signer | Description                        |
+    /// | -------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The distributor account            |
+    /// | 1     | ❌        | ❌      | The recipient of the leaf          |
+    /// | 2     | ✅        | ❌      | The token account of the recipient |
+    /// | 3     | ❌        | ❌      | The account of the central state   |
+    /// | 4     | ✅        | ❌      | The mint of the ACCESS token       |
+    /// | 5     | ❌        | ❌      | The SPL token program account      |
+    ClaimDistribution,
+    /// Claim rewards of many stake accounts and bonds
+    ///
+    /// | Index | Writable | Signer | Description                                                                                  |
+    /// | ------------------------------------------------------------------------------------------------------------------------ |
+    /// | 0     | ❌        | ✅      | The owner of the stake accounts and bonds                                                    |
+    /// | 1     | ✅        | ❌      | The rewards destination                                                                      |
+    /// | 2     | ❌        | ❌      | The central state account                                                                    |
+    /// | 3     | ✅        | ❌      | The mint address of the ACCESS token                                                         |
+    /// | 4     | ❌        | ❌      | The SPL token program account                                                                |
+    /// | 5..   | ✅        | ❌      | (stake pool, stake or bond account, referrer rewards destination of referred stakers) groups |
+    ClaimMultipleRewards,
+    /// Create a user index
+    ///
+    /// | Index | Writable | Signer | Description                                    |
+    /// | -------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The user index account                         |
+    /// | 1     | ✅        | ✅      | The owner of the positions                     |
+    /// | 2     | ❌        | ❌      | The system program account                     |
+    /// | 3..   | ❌        | ❌      | Existing stake accounts and bonds of the owner |
+    CreateUserIndex,
+    /// Close a user index
+    ///
+    /// | Index | Writable | Signer | Description                 |
+    /// | ------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The user index account      |
+    /// | 1     | ✅        | ✅      | The owner of the user index |
+    CloseUserIndex,
+    /// Deprecate a stake pool so that it can be wound down
+    ///
+    /// | Index | Writable | Signer | Description                                                                 |
+    /// | ------------------------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The central state account                                                   |
+    /// | 1     | ❌        | ✅      | The central state authority                                                 |
+    /// | 2     | ✅        | ❌      | The stake pool to deprecate                                                 |
+    /// | 3     | ✅        | ❌      | The freeze record of the stake pool                                         |
+    /// | 4     | ✅        | ❌      | The account that paid for the freeze record, required when the pool has one |
+    DeprecateStakePool,
+    /// Change the deposit required to self activate a stake pool
+    ///
+    /// | Index | Writable | Signer | Description                 |
+    /// | ------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The central state account   |
+    /// | 1     | ❌        | ✅      | The central state authority |
+    ChangeActivationDeposit,
+    /// Activate a stake pool without the central state authority by locking the activation deposit
+    ///
+    /// | Index | Writable | Signer | Description                       |
+    /// | ------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The stake pool to activate        |
+    /// | 1     | ❌        | ✅      | The owner of the stake pool       |
+    /// | 2     | ✅        | ❌      | The source account of the deposit |
+    /// | 3     | ✅        | ❌      | The stake pool vault              |
+    /// | 4     | ❌        | ❌      | The account of the central state  |
+    /// | 5     | ❌        | ❌      | The SPL token program account     |
+    /// | 6     | ❌        | ❌      | The mint of the ACCESS token      |
+    SelfActivateStakePool,
+    /// Create the admin log recording the actions of the central state authority
+    ///
+    /// | Index | Writable | Signer | Description                 |
+    /// | ------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The central state account   |
+    /// | 1     | ❌        | ✅      | The central state authority |
+    /// | 2     | ✅        | ❌      | The admin log account       |
+    /// | 3     | ❌        | ❌      | The system program account  |
+    /// | 4     | ✅        | ✅      | The fee payer account       |
+    CreateAdminLog,
+    /// Unfreeze an account whose freeze has expired
+    ///
+    /// | Index | Writable | Signer | Description                                 |
+    /// | ----------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The frozen account                          |
+    /// | 1     | ✅        | ❌      | The freeze record of the account            |
+    /// | 2     | ✅        | ❌      | The account that paid for the freeze record |
+    ExpireFreeze,
+    /// Migrate an account to the current layout
+    ///
+    /// | Index | Writable | Signer | Description                                                     |
+    /// | ------------------------------------------------------------------------------------------- |
+    /// | 0     | ✅        | ❌      | The account of the central state                                |
+    /// | 1     | ✅        | ❌      | The central state, stake pool, stake account or bond to migrate |
+    /// | 2     | ❌        | ❌      | The system program account                                      |
+    /// | 3     | ✅        | ✅      | The fee account                                                 |
+    MigrateAccount,
 }
 #[allow(missing_docs)]
 pub fn create_central_state(
@@ -490,3 +985,471 @@ pub fn edit_metadata(
 ) -> Instruction {
     accounts.get_instruction(program_id, ProgramInstruction::EditMetadata as u8, params)
 }
+#[allow(missing_docs)]
+pub fn set_pool_weight(
+    program_id: Pubkey,
+    accounts: set_pool_weight::Accounts<Pubkey>,
+    params: set_pool_weight::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::SetPoolWeight as u8, params)
+}
+#[allow(missing_docs)]
+pub fn create_reward_campaign(
+    program_id: Pubkey,
+    accounts: create_reward_campaign::Accounts<Pubkey>,
+    params: create_reward_campaign::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateRewardCampaign as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn fund_reward_campaign(
+    program_id: Pubkey,
+    accounts: fund_reward_campaign::Accounts<Pubkey>,
+    params: fund_reward_campaign::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::FundRewardCampaign as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn extend_reward_campaign(
+    program_id: Pubkey,
+    accounts: extend_reward_campaign::Accounts<Pubkey>,
+    params: extend_reward_campaign::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ExtendRewardCampaign as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn reclaim_campaign_rewards(
+    program_id: Pubkey,
+    accounts: reclaim_campaign_rewards::Accounts<Pubkey>,
+    params: reclaim_campaign_rewards::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ReclaimCampaignRewards as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn claim_campaign_rewards(
+    program_id: Pubkey,
+    accounts: claim_campaign_rewards::Accounts<Pubkey>,
+    params: claim_campaign_rewards::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ClaimCampaignRewards as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn change_crank_bounty(
+    program_id: Pubkey,
+    accounts: change_crank_bounty::Accounts<Pubkey>,
+    params: change_crank_bounty::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ChangeCrankBounty as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn get_claimable_rewards(
+    program_id: Pubkey,
+    accounts: get_claimable_rewards::Accounts<Pubkey>,
+    params: get_claimable_rewards::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::GetClaimableRewards as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn get_pool_claimable_rewards(
+    program_id: Pubkey,
+    accounts: get_pool_claimable_rewards::Accounts<Pubkey>,
+    params: get_pool_claimable_rewards::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::GetPoolClaimableRewards as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn get_bond_unlockable(
+    program_id: Pubkey,
+    accounts: get_bond_unlockable::Accounts<Pubkey>,
+    params: get_bond_unlockable::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::GetBondUnlockable as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn has_access(
+    program_id: Pubkey,
+    accounts: has_access::Accounts<Pubkey>,
+    params: has_access::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::HasAccess as u8, params)
+}
+#[allow(missing_docs)]
+pub fn mint_receipt(
+    program_id: Pubkey,
+    accounts: mint_receipt::Accounts<Pubkey>,
+    params: mint_receipt::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::MintReceipt as u8, params)
+}
+#[allow(missing_docs)]
+pub fn burn_receipt(
+    program_id: Pubkey,
+    accounts: burn_receipt::Accounts<Pubkey>,
+    params: burn_receipt::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::BurnReceipt as u8, params)
+}
+#[allow(missing_docs)]
+pub fn create_access_delegation(
+    program_id: Pubkey,
+    accounts: create_access_delegation::Accounts<Pubkey>,
+    params: create_access_delegation::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateAccessDelegation as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn edit_access_delegation(
+    program_id: Pubkey,
+    accounts: edit_access_delegation::Accounts<Pubkey>,
+    params: edit_access_delegation::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::EditAccessDelegation as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn close_access_delegation(
+    program_id: Pubkey,
+    accounts: close_access_delegation::Accounts<Pubkey>,
+    params: close_access_delegation::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CloseAccessDelegation as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn set_pool_allowlist(
+    program_id: Pubkey,
+    accounts: set_pool_allowlist::Accounts<Pubkey>,
+    params: set_pool_allowlist::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::SetPoolAllowlist as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn add_pool_member(
+    program_id: Pubkey,
+    accounts: add_pool_member::Accounts<Pubkey>,
+    params: add_pool_member::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::AddPoolMember as u8, params)
+}
+#[allow(missing_docs)]
+pub fn remove_pool_member(
+    program_id: Pubkey,
+    accounts: remove_pool_member::Accounts<Pubkey>,
+    params: remove_pool_member::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::RemovePoolMember as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn change_referral_rates(
+    program_id: Pubkey,
+    accounts: change_referral_rates::Accounts<Pubkey>,
+    params: change_referral_rates::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ChangeReferralRates as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn transfer_bond(
+    program_id: Pubkey,
+    accounts: transfer_bond::Accounts<Pubkey>,
+    params: transfer_bond::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::TransferBond as u8, params)
+}
+#[allow(missing_docs)]
+pub fn list_bond(
+    program_id: Pubkey,
+    accounts: list_bond::Accounts<Pubkey>,
+    params: list_bond::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::ListBond as u8, params)
+}
+#[allow(missing_docs)]
+pub fn cancel_bond_listing(
+    program_id: Pubkey,
+    accounts: cancel_bond_listing::Accounts<Pubkey>,
+    params: cancel_bond_listing::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CancelBondListing as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn fill_bond_listing(
+    program_id: Pubkey,
+    accounts: fill_bond_listing::Accounts<Pubkey>,
+    params: fill_bond_listing::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::FillBondListing as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn cancel_bond(
+    program_id: Pubkey,
+    accounts: cancel_bond::Accounts<Pubkey>,
+    params: cancel_bond::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::CancelBond as u8, params)
+}
+#[allow(missing_docs)]
+pub fn change_early_unlock_penalty(
+    program_id: Pubkey,
+    accounts: change_early_unlock_penalty::Accounts<Pubkey>,
+    params: change_early_unlock_penalty::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ChangeEarlyUnlockPenalty as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn early_unlock_bond(
+    program_id: Pubkey,
+    accounts: early_unlock_bond::Accounts<Pubkey>,
+    params: early_unlock_bond::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::EarlyUnlockBond as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn create_bond_auction(
+    program_id: Pubkey,
+    accounts: create_bond_auction::Accounts<Pubkey>,
+    params: create_bond_auction::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateBondAuction as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn fill_bond_auction(
+    program_id: Pubkey,
+    accounts: fill_bond_auction::Accounts<Pubkey>,
+    params: fill_bond_auction::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::FillBondAuction as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn move_bond_stake(
+    program_id: Pubkey,
+    accounts: move_bond_stake::Accounts<Pubkey>,
+    params: move_bond_stake::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::MoveBondStake as u8, params)
+}
+#[allow(missing_docs)]
+pub fn create_bond_round(
+    program_id: Pubkey,
+    accounts: create_bond_round::Accounts<Pubkey>,
+    params: create_bond_round::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateBondRound as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn sign_bond_round(
+    program_id: Pubkey,
+    accounts: sign_bond_round::Accounts<Pubkey>,
+    params: sign_bond_round::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::SignBondRound as u8, params)
+}
+#[allow(missing_docs)]
+pub fn claim_round_bond(
+    program_id: Pubkey,
+    accounts: claim_round_bond::Accounts<Pubkey>,
+    params: claim_round_bond::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::ClaimRoundBond as u8, params)
+}
+#[allow(missing_docs)]
+pub fn create_distributor(
+    program_id: Pubkey,
+    accounts: create_distributor::Accounts<Pubkey>,
+    params: create_distributor::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateDistributor as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn claim_distribution(
+    program_id: Pubkey,
+    accounts: claim_distribution::Accounts<Pubkey>,
+    params: claim_distribution::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ClaimDistribution as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn claim_multiple_rewards(
+    program_id: Pubkey,
+    accounts: claim_multiple_rewards::Accounts<Pubkey>,
+    params: claim_multiple_rewards::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ClaimMultipleRewards as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn create_user_index(
+    program_id: Pubkey,
+    accounts: create_user_index::Accounts<Pubkey>,
+    params: create_user_index::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::CreateUserIndex as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn close_user_index(
+    program_id: Pubkey,
+    accounts: close_user_index::Accounts<Pubkey>,
+    params: close_user_index::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::CloseUserIndex as u8, params)
+}
+#[allow(missing_docs)]
+pub fn deprecate_stake_pool(
+    program_id: Pubkey,
+    accounts: deprecate_stake_pool::Accounts<Pubkey>,
+    params: deprecate_stake_pool::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::DeprecateStakePool as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn change_activation_deposit(
+    program_id: Pubkey,
+    accounts: change_activation_deposit::Accounts<Pubkey>,
+    params: change_activation_deposit::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::ChangeActivationDeposit as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn self_activate_stake_pool(
+    program_id: Pubkey,
+    accounts: self_activate_stake_pool::Accounts<Pubkey>,
+    params: self_activate_stake_pool::Params,
+) -> Instruction {
+    accounts.get_instruction(
+        program_id,
+        ProgramInstruction::SelfActivateStakePool as u8,
+        params,
+    )
+}
+#[allow(missing_docs)]
+pub fn create_admin_log(
+    program_id: Pubkey,
+    accounts: create_admin_log::Accounts<Pubkey>,
+    params: create_admin_log::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::CreateAdminLog as u8, params)
+}
+#[allow(missing_docs)]
+pub fn expire_freeze(
+    program_id: Pubkey,
+    accounts: expire_freeze::Accounts<Pubkey>,
+    params: expire_freeze::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::ExpireFreeze as u8, params)
+}
+#[allow(missing_docs)]
+pub fn migrate_account(
+    program_id: Pubkey,
+    accounts: migrate_account::Accounts<Pubkey>,
+    params: migrate_account::Params,
+) -> Instruction {
+    accounts.get_instruction(program_id, ProgramInstruction::MigrateAccount as u8, params)
+}
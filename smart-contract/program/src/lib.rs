@@ -19,15 +19,27 @@ The [`CentralState`][`state::CentralState`] is the mint authority of the ACCESS
 
 [`Stake pools`][`state::StakePool`] are created by content publishers. In order to get access to the publisher's content users need to stake ACCESS tokens in the [`StakePool`][`state::StakePool`] of the publisher.
 
+Stake pools are created inactive and activated by the central state authority. When the central state sets an activation deposit, a publisher can [`self activate`][`fn@instruction::self_activate_stake_pool`] their pool by locking the deposit in the pool vault, it is refunded when the pool is closed.
+
 A [`Stake pools`][`state::StakePool`] is made of a header ([`StakePoolHeader`][`state::StakePoolHeader`]) and circular buffer that contains the pool balances multiplied by the current inflation at each crank time.
 
 The circular buffer is updated using a permissionless [`crank`][`fn@instruction::crank`].
 
+The header also keeps statistics of the pool: the number of stakers and bond holders, the lifetime rewards claimed by the stakers and the owner, and the lifetime staking fees.
+
+The central state authority can [`deprecate`][`fn@instruction::deprecate_stake_pool`] a pool to wind it down: it no longer accepts stakes nor receives inflation, but stakers can still unstake, claim past rewards and unlock their bonds until the pool is empty and can be closed.
+
 
 ## Stake accounts
 
 [`Stake accounts`][`state::StakeAccount`] are used to deposit funds in a stake pool. Stake accounts allow users to access the content of the publisher and earn yield in ACCESS tokens at the same time.
 
+Stake pool owners can reward their stakers with any token through a [`RewardCampaign`][`state::RewardCampaign`] ([`create_reward_campaign`][`fn@instruction::create_reward_campaign`]). Stake accounts register in a campaign with their first [`claim_campaign_rewards`][`fn@instruction::claim_campaign_rewards`], the rewards are shared between the registered stake only. Every stake and unstake of a registered account must pass its [`CampaignPosition`][`state::CampaignPosition`] accounts so that the rewards earned with the previous stake are checkpointed.
+
+The rewards of many stake accounts and bonds of the same owner can be claimed in a single transaction with [`claim_multiple_rewards`][`fn@instruction::claim_multiple_rewards`].
+
+Wallets can load all their positions with a single account fetch through an optional [`UserIndex`][`state::UserIndex`] created with [`create_user_index`][`fn@instruction::create_user_index`]. The index is kept up to date when it is passed to the instructions creating, transferring or closing a position: [`create_stake_account`][`fn@instruction::create_stake_account`], [`close_stake_account`][`fn@instruction::close_stake_account`], [`claim_bond`][`fn@instruction::claim_bond`], [`fill_bond_auction`][`fn@instruction::fill_bond_auction`], [`claim_round_bond`][`fn@instruction::claim_round_bond`], [`fill_bond_listing`][`fn@instruction::fill_bond_listing`], [`transfer_bond`][`fn@instruction::transfer_bond`] and [`cancel_bond`][`fn@instruction::cancel_bond`]. The transfers accept the indexes of both the previous and the new owner. An index that missed an update can be rebuilt by closing and recreating it.
+
 ## Bonds
 
 [`Bonds`][`state::Bonds`] represent locked ACCESS tokens sold by the ACCESS DAO. The lifecycle of a bond is as follow:
@@ -35,9 +47,44 @@ The circular buffer is updated using a permissionless [`crank`][`fn@instruction:
 - [`create_bond`][`fn@instruction::create_bond`]: This instruction creates an inactive bond. The bond account contains the information about the price of the bond, the buyer, the unlock schedule and the sellers.
 - [`sign_bond`][`fn@instruction::sign_bond`]: This instruction allows DAO members to approve the sell.
 - [`claim_bond`][`fn@instruction::claim_bond`]: Once the bond has been signed by enough DAO members, the buyer can claim the bond.
+- [`cancel_bond`][`fn@instruction::cancel_bond`]: A bond that was not claimed before its expiry date can be cancelled by an authorized seller, the rent is refunded to the fee payer.
 
 Bond tokens can be staked like regular ACCESS tokens.
 
+Bonds can also be sold through a Dutch auction: an authorized seller opens a [`BondAuction`][`state::BondAuction`] with [`create_bond_auction`][`fn@instruction::create_bond_auction`], and buyers fill it at the current price with [`fill_bond_auction`][`fn@instruction::fill_bond_auction`], each fill creating an active bond.
+
+For investor rounds, the bonds of all the buyers are committed to with the Merkle root of a [`BondRound`][`state::BondRound`]: the sellers create and sign the round once with [`create_bond_round`][`fn@instruction::create_bond_round`] and [`sign_bond_round`][`fn@instruction::sign_bond_round`], then each buyer claims their active bond with a proof using [`claim_round_bond`][`fn@instruction::claim_round_bond`].
+
+Bond tokens unlock either by a fixed amount every unlock period, or following a [`VestingSchedule`][`state::VestingSchedule`] made of a cliff release followed by linear vesting.
+
+Active bonds can change hands with [`transfer_bond`][`fn@instruction::transfer_bond`], or be sold for any token through an escrowed [`BondListing`][`state::BondListing`] ([`list_bond`][`fn@instruction::list_bond`], [`fill_bond_listing`][`fn@instruction::fill_bond_listing`], [`cancel_bond_listing`][`fn@instruction::cancel_bond_listing`]). The staking rewards accrued by the bond are settled to the previous owner before the transfer.
+
+## Distributions
+
+ACCESS tokens can be distributed to many wallets at once (airdrops, retroactive rewards) through a [`Distributor`][`state::Distributor`] created by the central state authority with [`create_distributor`][`fn@instruction::create_distributor`]. The distributor holds the Merkle root of the `(index, recipient, amount)` leaves and a budget cap, each leaf is minted once to its recipient with [`claim_distribution`][`fn@instruction::claim_distribution`]. The tree and the proofs are built from a CSV file with the tool in `smart-contract/distributor`.
+
+## Freezes
+
+The central state authority can [`freeze`][`fn@instruction::admin_freeze`] stake pools, stake accounts and bonds in bulk. Each frozen account gets a [`FreezeRecord`][`state::FreezeRecord`] with a reason code and an optional expiry, once the expiry has passed anyone can lift the freeze with [`expire_freeze`][`fn@instruction::expire_freeze`]. Frozen stake pools also hold the expiry and are active again as soon as it passes, stake accounts and bonds have no room left for it and stay frozen until the freeze is lifted. Unfreezing an account closes its record and refunds whoever paid for it.
+
+## Admin log
+
+The actions of the central state authority (freezes, mints, inflation and authority changes, pool activations and metadata edits) are recorded in the [`AdminLog`][`state::AdminLog`] ring buffer once it has been created with [`create_admin_log`][`fn@instruction::create_admin_log`]. Each entry holds the slot, the action, the target account and the hash of the parameters, the log can be rendered with the tool in `smart-contract/admin-log`.
+
+## Token programs
+
+The ACCESS mint, the bond quote mints and the campaign reward mints can belong to either the SPL token program or Token-2022, the token program account passed to each instruction is checked against the owner of the mint it moves. The transfers use `transfer_checked`, only [`stake`][`fn@instruction::stake`] and [`unstake`][`fn@instruction::unstake`] still accept the legacy accounts without the mint for SPL token mints. When a Token-2022 mint has a transfer fee, the fee is paid on top by the sender of the tokens coming from a wallet (stakes and their fees, bond purchases and fills, activation deposits) so that the accounting receives the exact amount. Bond stakes moved between pools are credited with the amount received, the withheld fee is counted as unlocked. Tokens leaving a vault are debited from the accounting in full, the recipient bears the fee. Bond receipts remain SPL token NFTs.
+
+## Migrations
+
+New account fields are appended at the end of the existing layouts. Accounts created before a layout change are rejected until they are grown to the current layout with the permissionless [`migrate_account`][`fn@instruction::migrate_account`] instruction, the new fields start at zero. The central state must be migrated before the stake pools: its migration seeds the weighted stake with the stake of every legacy pool at the default weight, so that pools migrated and cranked early do not take a larger share of the inflation.
+
+## Access checks
+
+Third party programs can check that a wallet has access to a stake pool with [`has_access`][`fn@cpi::access::has_access`] or [`assert_has_access`][`fn@cpi::access::assert_has_access`]. A sample consumer program can be found in `smart-contract/access-gate-example`.
+
+The owner of a stake account can share its access with up to [`MAX_DELEGATION_MEMBERS`][`state::MAX_DELEGATION_MEMBERS`] wallets through an [`AccessDelegation`][`state::AccessDelegation`] account, members are checked with [`has_delegated_access`][`fn@cpi::access::has_delegated_access`].
+
 */
 
 use solana_program::declare_id;
@@ -54,6 +101,9 @@ pub mod state;
 pub(crate) mod processor;
 pub(crate) mod utils;
 
+/// Verifies the Merkle proofs of distributors and bond rounds, to check the trees built off-chain
+pub use utils::verify_merkle_proof;
+
 #[allow(missing_docs)]
 pub mod cpi;
 
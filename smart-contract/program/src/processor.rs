@@ -7,26 +7,70 @@ use solana_program::{
 };
 
 pub mod activate_stake_pool;
+pub mod add_pool_member;
 pub mod admin_freeze;
 pub mod admin_mint;
+pub mod burn_receipt;
+pub mod cancel_bond;
+pub mod cancel_bond_listing;
+pub mod change_activation_deposit;
 pub mod change_central_state_authority;
+pub mod change_crank_bounty;
+pub mod change_early_unlock_penalty;
 pub mod change_inflation;
 pub mod change_pool_minimum;
 pub mod change_pool_multiplier;
+pub mod change_referral_rates;
 pub mod claim_bond;
 pub mod claim_bond_rewards;
+pub mod claim_campaign_rewards;
+pub mod claim_distribution;
+pub mod claim_multiple_rewards;
 pub mod claim_pool_rewards;
 pub mod claim_rewards;
+pub mod claim_round_bond;
+pub mod close_access_delegation;
 pub mod close_stake_account;
 pub mod close_stake_pool;
+pub mod close_user_index;
 pub mod crank;
+pub mod create_access_delegation;
+pub mod create_admin_log;
 pub mod create_bond;
+pub mod create_bond_auction;
+pub mod create_bond_round;
 pub mod create_central_state;
+pub mod create_distributor;
+pub mod create_reward_campaign;
 pub mod create_stake_account;
 pub mod create_stake_pool;
+pub mod create_user_index;
+pub mod deprecate_stake_pool;
+pub mod early_unlock_bond;
+pub mod edit_access_delegation;
 pub mod edit_metadata;
+pub mod expire_freeze;
+pub mod extend_reward_campaign;
+pub mod fill_bond_auction;
+pub mod fill_bond_listing;
+pub mod fund_reward_campaign;
+pub mod get_bond_unlockable;
+pub mod get_claimable_rewards;
+pub mod get_pool_claimable_rewards;
+pub mod has_access;
+pub mod list_bond;
+pub mod migrate_account;
+pub mod mint_receipt;
+pub mod move_bond_stake;
+pub mod reclaim_campaign_rewards;
+pub mod remove_pool_member;
+pub mod self_activate_stake_pool;
+pub mod set_pool_allowlist;
+pub mod set_pool_weight;
 pub mod sign_bond;
+pub mod sign_bond_round;
 pub mod stake;
+pub mod transfer_bond;
 pub mod unlock_bond_tokens;
 pub mod unstake;
 
@@ -69,14 +113,12 @@ impl Processor {
             }
             ProgramInstruction::Stake => {
                 msg!("Instruction: Stake");
-                let params = stake::Params::try_from_slice(instruction_data)
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let params = stake::Params::unpack(instruction_data, accounts.len())?;
                 stake::process_stake(program_id, accounts, params)?;
             }
             ProgramInstruction::Unstake => {
                 msg!("Instruction: Unstake");
-                let params = unstake::Params::try_from_slice(instruction_data)
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let params = unstake::Params::unpack(instruction_data, accounts.len())?;
                 unstake::process_unstake(program_id, accounts, params)?;
             }
             ProgramInstruction::ClaimPoolRewards => {
@@ -133,8 +175,7 @@ impl Processor {
             }
             ProgramInstruction::ClaimBond => {
                 msg!("Instruction: claim bond");
-                let params = claim_bond::Params::try_from_slice(instruction_data)
-                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let params = claim_bond::Params::unpack(instruction_data)?;
                 claim_bond::process_claim_bond(program_id, accounts, params)?;
             }
             ProgramInstruction::ClaimBondRewards => {
@@ -157,7 +198,8 @@ impl Processor {
             }
             ProgramInstruction::AdminFreeze => {
                 msg!("Instruction: Admin freeze");
-                admin_freeze::process_admin_freeze(program_id, accounts)?;
+                let params = admin_freeze::Params::unpack(instruction_data)?;
+                admin_freeze::process_admin_freeze(program_id, accounts, params)?;
             }
             ProgramInstruction::ChangePoolMultiplier => {
                 msg!("Instruction: Change pool multiplier");
@@ -182,6 +224,294 @@ impl Processor {
                     .map_err(|_| ProgramError::InvalidInstructionData)?;
                 edit_metadata::process_edit_metadata(program_id, accounts, params)?;
             }
+            ProgramInstruction::SetPoolWeight => {
+                msg!("Instruction: Set pool weight");
+                let params = set_pool_weight::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                set_pool_weight::process_set_pool_weight(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CreateRewardCampaign => {
+                msg!("Instruction: Create reward campaign");
+                let params = create_reward_campaign::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_reward_campaign::process_create_reward_campaign(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::FundRewardCampaign => {
+                msg!("Instruction: Fund reward campaign");
+                let params = fund_reward_campaign::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                fund_reward_campaign::process_fund_reward_campaign(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ExtendRewardCampaign => {
+                msg!("Instruction: Extend reward campaign");
+                let params = extend_reward_campaign::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                extend_reward_campaign::process_extend_reward_campaign(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::ReclaimCampaignRewards => {
+                msg!("Instruction: Reclaim campaign rewards");
+                let params = reclaim_campaign_rewards::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                reclaim_campaign_rewards::process_reclaim_campaign_rewards(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::ClaimCampaignRewards => {
+                msg!("Instruction: Claim campaign rewards");
+                let params = claim_campaign_rewards::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                claim_campaign_rewards::process_claim_campaign_rewards(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::ChangeCrankBounty => {
+                msg!("Instruction: Change crank bounty");
+                let params = change_crank_bounty::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                change_crank_bounty::process_change_crank_bounty(program_id, accounts, params)?;
+            }
+            ProgramInstruction::GetClaimableRewards => {
+                msg!("Instruction: Get claimable rewards");
+                let params = get_claimable_rewards::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                get_claimable_rewards::process_get_claimable_rewards(program_id, accounts, params)?;
+            }
+            ProgramInstruction::GetPoolClaimableRewards => {
+                msg!("Instruction: Get pool claimable rewards");
+                let params = get_pool_claimable_rewards::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                get_pool_claimable_rewards::process_get_pool_claimable_rewards(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::GetBondUnlockable => {
+                msg!("Instruction: Get bond unlockable");
+                let params = get_bond_unlockable::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                get_bond_unlockable::process_get_bond_unlockable(program_id, accounts, params)?;
+            }
+            ProgramInstruction::HasAccess => {
+                msg!("Instruction: Has access");
+                let params = has_access::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                has_access::process_has_access(program_id, accounts, params)?;
+            }
+            ProgramInstruction::MintReceipt => {
+                msg!("Instruction: Mint receipt");
+                let params = mint_receipt::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                mint_receipt::process_mint_receipt(program_id, accounts, params)?;
+            }
+            ProgramInstruction::BurnReceipt => {
+                msg!("Instruction: Burn receipt");
+                let params = burn_receipt::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                burn_receipt::process_burn_receipt(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CreateAccessDelegation => {
+                msg!("Instruction: Create access delegation");
+                let params = create_access_delegation::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_access_delegation::process_create_access_delegation(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::EditAccessDelegation => {
+                msg!("Instruction: Edit access delegation");
+                let params = edit_access_delegation::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                edit_access_delegation::process_edit_access_delegation(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::CloseAccessDelegation => {
+                msg!("Instruction: Close access delegation");
+                let params = close_access_delegation::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                close_access_delegation::process_close_access_delegation(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::SetPoolAllowlist => {
+                msg!("Instruction: Set pool allowlist");
+                let params = set_pool_allowlist::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                set_pool_allowlist::process_set_pool_allowlist(program_id, accounts, params)?;
+            }
+            ProgramInstruction::AddPoolMember => {
+                msg!("Instruction: Add pool member");
+                let params = add_pool_member::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                add_pool_member::process_add_pool_member(program_id, accounts, params)?;
+            }
+            ProgramInstruction::RemovePoolMember => {
+                msg!("Instruction: Remove pool member");
+                let params = remove_pool_member::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                remove_pool_member::process_remove_pool_member(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ChangeReferralRates => {
+                msg!("Instruction: Change referral rates");
+                let params = change_referral_rates::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                change_referral_rates::process_change_referral_rates(program_id, accounts, params)?;
+            }
+            ProgramInstruction::TransferBond => {
+                msg!("Instruction: Transfer bond");
+                let params = transfer_bond::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                transfer_bond::process_transfer_bond(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ListBond => {
+                msg!("Instruction: List bond");
+                let params = list_bond::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                list_bond::process_list_bond(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CancelBondListing => {
+                msg!("Instruction: Cancel bond listing");
+                let params = cancel_bond_listing::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                cancel_bond_listing::process_cancel_bond_listing(program_id, accounts, params)?;
+            }
+            ProgramInstruction::FillBondListing => {
+                msg!("Instruction: Fill bond listing");
+                let params = fill_bond_listing::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                fill_bond_listing::process_fill_bond_listing(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CancelBond => {
+                msg!("Instruction: Cancel bond");
+                let params = cancel_bond::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                cancel_bond::process_cancel_bond(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ChangeEarlyUnlockPenalty => {
+                msg!("Instruction: Change early unlock penalty");
+                let params = change_early_unlock_penalty::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                change_early_unlock_penalty::process_change_early_unlock_penalty(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::EarlyUnlockBond => {
+                msg!("Instruction: Early unlock bond");
+                let params = early_unlock_bond::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                early_unlock_bond::process_early_unlock_bond(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CreateBondAuction => {
+                msg!("Instruction: Create bond auction");
+                let params = create_bond_auction::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_bond_auction::process_create_bond_auction(program_id, accounts, params)?;
+            }
+            ProgramInstruction::FillBondAuction => {
+                msg!("Instruction: Fill bond auction");
+                let params = fill_bond_auction::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                fill_bond_auction::process_fill_bond_auction(program_id, accounts, params)?;
+            }
+            ProgramInstruction::MoveBondStake => {
+                msg!("Instruction: Move bond stake");
+                let params = move_bond_stake::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                move_bond_stake::process_move_bond_stake(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CreateBondRound => {
+                msg!("Instruction: Create bond round");
+                let params = create_bond_round::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_bond_round::process_create_bond_round(program_id, accounts, params)?;
+            }
+            ProgramInstruction::SignBondRound => {
+                msg!("Instruction: Sign bond round");
+                let params = sign_bond_round::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                sign_bond_round::process_sign_bond_round(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ClaimRoundBond => {
+                msg!("Instruction: Claim round bond");
+                let params = claim_round_bond::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                claim_round_bond::process_claim_round_bond(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CreateDistributor => {
+                msg!("Instruction: Create distributor");
+                let params = create_distributor::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_distributor::process_create_distributor(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ClaimDistribution => {
+                msg!("Instruction: Claim distribution");
+                let params = claim_distribution::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                claim_distribution::process_claim_distribution(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ClaimMultipleRewards => {
+                msg!("Instruction: Claim multiple rewards");
+                let params = claim_multiple_rewards::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                claim_multiple_rewards::process_claim_multiple_rewards(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::CreateUserIndex => {
+                msg!("Instruction: Create user index");
+                let params = create_user_index::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_user_index::process_create_user_index(program_id, accounts, params)?;
+            }
+            ProgramInstruction::CloseUserIndex => {
+                msg!("Instruction: Close user index");
+                let params = close_user_index::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                close_user_index::process_close_user_index(program_id, accounts, params)?;
+            }
+            ProgramInstruction::DeprecateStakePool => {
+                msg!("Instruction: Deprecate stake pool");
+                let params = deprecate_stake_pool::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                deprecate_stake_pool::process_deprecate_stake_pool(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ChangeActivationDeposit => {
+                msg!("Instruction: Change activation deposit");
+                let params = change_activation_deposit::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                change_activation_deposit::process_change_activation_deposit(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::SelfActivateStakePool => {
+                msg!("Instruction: Self activate stake pool");
+                let params = self_activate_stake_pool::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                self_activate_stake_pool::process_self_activate_stake_pool(
+                    program_id, accounts, params,
+                )?;
+            }
+            ProgramInstruction::CreateAdminLog => {
+                msg!("Instruction: Create admin log");
+                let params = create_admin_log::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                create_admin_log::process_create_admin_log(program_id, accounts, params)?;
+            }
+            ProgramInstruction::ExpireFreeze => {
+                msg!("Instruction: Expire freeze");
+                let params = expire_freeze::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                expire_freeze::process_expire_freeze(program_id, accounts, params)?;
+            }
+            ProgramInstruction::MigrateAccount => {
+                msg!("Instruction: Migrate account");
+                let params = migrate_account::Params::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                migrate_account::process_migrate_account(program_id, accounts, params)?;
+            }
         }
 
         Ok(())
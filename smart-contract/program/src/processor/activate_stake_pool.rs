@@ -1,6 +1,6 @@
 //! Activate a stake pool
 use crate::error::AccessError;
-use crate::state::{CentralState, StakePool, Tag};
+use crate::state::{AdminAction, CentralState, StakePool, Tag};
 use bonfida_utils::{BorshSize, InstructionsAccount};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
@@ -10,7 +10,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::utils::{check_account_key, check_account_owner, check_signer, log_admin_action};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 pub struct Params {}
@@ -27,6 +27,10 @@ pub struct Accounts<'a, T> {
 
     /// The account of the central state
     pub central_state: &'a T,
+
+    /// The admin log, required once it has been created
+    #[cons(writable)]
+    pub admin_log: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -39,6 +43,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             authority: next_account_info(accounts_iter)?,
             stake_pool: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
+            admin_log: next_account_info(accounts_iter).ok(),
         };
 
         // Check ownership
@@ -70,6 +75,15 @@ pub fn process_activate_stake_pool(program_id: &Pubkey, accounts: &[AccountInfo]
         return Err(AccessError::ActiveStakePoolNotAllowed.into());
     }
 
+    log_admin_action(
+        program_id,
+        accounts.admin_log,
+        &central_state,
+        AdminAction::ActivateStakePool,
+        accounts.stake_pool.key,
+        &[],
+    )?;
+
     stake_pool.header.tag = Tag::StakePool as u8;
     stake_pool.header.last_claimed_offset = central_state.last_snapshot_offset;
 
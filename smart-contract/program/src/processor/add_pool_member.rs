@@ -0,0 +1,131 @@
+//! Add a member to an allowlisted pool
+//! This instruction allows a pool owner to let a wallet create a stake account and stake in its pool
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::state::{PoolMembership, StakePool};
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use crate::{cpi::Cpi, error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `add_pool_member` instruction
+pub struct Params {
+    /// The wallet allowed to stake in the pool
+    pub member: Pubkey,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `add_pool_member` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub stake_pool_owner: &'a T,
+
+    /// The pool membership account
+    #[cons(writable)]
+    pub pool_membership: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee payer account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_pool_owner: next_account_info(accounts_iter)?,
+            pool_membership: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(
+            accounts.stake_pool_owner,
+            AccessError::StakePoolOwnerMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_add_pool_member(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let Params { member } = params;
+
+    let stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::InactiveStakePool],
+    )?;
+
+    check_account_key(
+        accounts.stake_pool_owner,
+        &Pubkey::new(&stake_pool.header.owner),
+        AccessError::WrongStakePoolOwner,
+    )?;
+
+    let (derived_key, nonce) =
+        PoolMembership::find_key(accounts.stake_pool.key, &member, program_id);
+    check_account_key(
+        accounts.pool_membership,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.pool_membership)?;
+
+    let membership = PoolMembership::new(*accounts.stake_pool.key, member);
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.pool_membership,
+        &[
+            PoolMembership::SEED,
+            &accounts.stake_pool.key.to_bytes(),
+            &member.to_bytes(),
+            &[nonce],
+        ],
+        membership.borsh_len(),
+    )?;
+
+    membership.save(&mut accounts.pool_membership.data.borrow_mut())?;
+
+    Ok(())
+}
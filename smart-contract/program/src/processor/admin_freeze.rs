@@ -1,21 +1,52 @@
-//! Freeze and unfreeze a program account
+//! Freeze and unfreeze program accounts
 //! This admin instruction can be dangereous 💀
+//! Frozen accounts get a freeze record holding the reason and the expiry of the freeze
+//! Unfreezing an account requires its freeze record, which is closed and refunded to whoever paid for it
+use crate::cpi::Cpi;
 use crate::error::AccessError;
-use crate::state::{CentralState, Tag};
+use crate::state::{AdminAction, CentralState, FreezeRecord, StakePoolHeader, Tag};
 use bonfida_utils::{BorshSize, InstructionsAccount};
 use borsh::{BorshDeserialize, BorshSerialize};
 use num_traits::FromPrimitive;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
 };
 
-use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, log_admin_action,
+    next_optional_account_info,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
-pub struct Params {}
+pub struct Params {
+    /// Reason code of the freeze
+    pub reason: u8,
+    /// Timestamp after which the accounts can be unfrozen by anyone, 0 if the freeze never expires
+    pub expiry: i64,
+    /// Whether the admin log is passed
+    pub has_admin_log: bool,
+}
+
+impl Params {
+    /// Decodes the parameters, legacy clients send no parameters and only pass the required accounts
+    pub fn unpack(instruction_data: &[u8]) -> Result<Self, ProgramError> {
+        if instruction_data.is_empty() {
+            return Ok(Params {
+                reason: 0,
+                expiry: 0,
+                has_admin_log: false,
+            });
+        }
+        Params::try_from_slice(instruction_data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
 
 #[derive(InstructionsAccount)]
 pub struct Accounts<'a, T> {
@@ -29,20 +60,69 @@ pub struct Accounts<'a, T> {
 
     /// The account of the central state
     pub central_state: &'a T,
+
+    /// The admin log (required once it has been created)
+    #[cons(writable)]
+    pub admin_log: Option<&'a T>,
+
+    /// The system program account, required to record the freeze
+    pub system_program: Option<&'a T>,
+
+    /// The fee account, required to record the freeze, the account that paid for the record when unfreezing
+    #[cons(writable, signer)]
+    pub fee_payer: Option<&'a T>,
+
+    /// The freeze record of the account to freeze (or unfreeze)
+    #[cons(writable)]
+    pub freeze_record: Option<&'a T>,
+
+    /// Pairs of (account to freeze or unfreeze, freeze record) of the other accounts
+    #[cons(writable)]
+    pub accounts: &'a [T],
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
     pub fn parse(
         accounts: &'a [AccountInfo<'b>],
         program_id: &Pubkey,
+        params: &Params,
     ) -> Result<Self, ProgramError> {
         let accounts_iter = &mut accounts.iter();
         let accounts = Accounts {
             authority: next_account_info(accounts_iter)?,
             account_to_freeze: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
+            admin_log: next_optional_account_info(accounts_iter, params.has_admin_log)?,
+            system_program: next_account_info(accounts_iter).ok(),
+            fee_payer: next_account_info(accounts_iter).ok(),
+            freeze_record: next_account_info(accounts_iter).ok(),
+            accounts: accounts_iter.as_slice(),
         };
 
+        // The freeze records are written with the system program and the fee payer,
+        // legacy clients freeze a single account without a record but must pass it to unfreeze
+        if accounts.freeze_record.is_none() && accounts.system_program.is_some() {
+            msg!("The freeze record must be passed with the system program and the fee payer");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        if accounts.freeze_record.is_none() && !accounts.accounts.is_empty() {
+            msg!("Several accounts can only be frozen with their freeze records");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        if accounts.accounts.len() % 2 != 0 {
+            msg!("Accounts must be (account to freeze, freeze record) pairs");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        // Check keys
+        if let Some(system_program) = accounts.system_program {
+            check_account_key(
+                system_program,
+                &system_program::ID,
+                AccessError::WrongSystemProgram,
+            )?;
+        }
+
         // Check ownership
         check_account_owner(
             accounts.account_to_freeze,
@@ -50,6 +130,9 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             AccessError::WrongOwner,
         )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        for pair in accounts.accounts.chunks(2) {
+            check_account_owner(&pair[0], program_id, AccessError::WrongOwner)?;
+        }
 
         // Check signer
         check_signer(
@@ -61,8 +144,12 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
     }
 }
 
-pub fn process_admin_freeze(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let accounts = Accounts::parse(accounts, program_id)?;
+pub fn process_admin_freeze(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id, &params)?;
 
     let central_state = CentralState::from_account_info(accounts.central_state)?;
 
@@ -72,12 +159,110 @@ pub fn process_admin_freeze(program_id: &Pubkey, accounts: &[AccountInfo]) -> Pr
         AccessError::WrongCentralStateAuthority,
     )?;
 
-    let mut data = accounts.account_to_freeze.data.borrow_mut();
+    let now = Clock::get()?.unix_timestamp;
+    if params.expiry != 0 && params.expiry <= now {
+        msg!("The freeze expiry must be in the future");
+        return Err(AccessError::InvalidFreezeExpiry.into());
+    }
+
+    let pairs = accounts
+        .accounts
+        .chunks(2)
+        .map(|pair| (&pair[0], Some(&pair[1])));
+    for (account, freeze_record) in
+        std::iter::once((accounts.account_to_freeze, accounts.freeze_record)).chain(pairs)
+    {
+        let current_tag =
+            Tag::from_u8(account.data.borrow()[0]).ok_or(ProgramError::InvalidAccountData)?;
+        let new_tag = Tag::opposite(&current_tag)?;
 
-    let current_tag = Tag::from_u8(data[0]).ok_or(ProgramError::InvalidAccountData)?;
-    let new_tag = Tag::opposite(&current_tag)?;
+        if new_tag.is_frozen() {
+            match (freeze_record, accounts.system_program, accounts.fee_payer) {
+                (Some(freeze_record), Some(system_program), Some(fee_payer)) => {
+                    check_signer(fee_payer, AccessError::CentralStateAuthorityMustSign)?;
+                    let (derived_key, nonce) = FreezeRecord::find_key(account.key, program_id);
+                    check_account_key(
+                        freeze_record,
+                        &derived_key,
+                        AccessError::AccountNotDeterministic,
+                    )?;
+                    let record = FreezeRecord::new(
+                        *account.key,
+                        params.reason,
+                        now,
+                        params.expiry,
+                        *fee_payer.key,
+                    );
+                    Cpi::create_account(
+                        program_id,
+                        system_program,
+                        fee_payer,
+                        freeze_record,
+                        &[FreezeRecord::SEED, &account.key.to_bytes(), &[nonce]],
+                        record.borsh_len(),
+                    )?;
+                    record.save(&mut freeze_record.data.borrow_mut())?;
+                }
+                _ if params.expiry != 0 => {
+                    msg!("A freeze with an expiry must be recorded");
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                }
+                _ => (),
+            }
+            log_admin_action(
+                program_id,
+                accounts.admin_log,
+                &central_state,
+                AdminAction::Freeze,
+                account.key,
+                &(params.reason, params.expiry).try_to_vec()?,
+            )?;
+        } else {
+            // The record is always closed with the freeze so that it can't lift a later one,
+            // accounts frozen without a record pass the empty record account
+            let freeze_record = freeze_record.ok_or_else(|| {
+                msg!("The freeze record must be passed to unfreeze an account");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+            let (derived_key, _nonce) = FreezeRecord::find_key(account.key, program_id);
+            check_account_key(
+                freeze_record,
+                &derived_key,
+                AccessError::AccountNotDeterministic,
+            )?;
+            if !freeze_record.data_is_empty() {
+                check_account_owner(freeze_record, program_id, AccessError::WrongOwner)?;
+                let mut record = FreezeRecord::from_account_info(freeze_record)?;
+                let fee_payer = accounts
+                    .fee_payer
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                check_account_key(fee_payer, &record.fee_payer, AccessError::WrongFreezeRecord)?;
+                record.close();
+                record.save(&mut freeze_record.data.borrow_mut())?;
+                let mut record_lamports = freeze_record.lamports.borrow_mut();
+                let mut fee_payer_lamports = fee_payer.lamports.borrow_mut();
+                **fee_payer_lamports += **record_lamports;
+                **record_lamports = 0;
+            }
+            log_admin_action(
+                program_id,
+                accounts.admin_log,
+                &central_state,
+                AdminAction::Unfreeze,
+                account.key,
+                &[],
+            )?;
+        }
 
-    data[0] = new_tag as u8;
+        account.data.borrow_mut()[0] = new_tag as u8;
+
+        // Stake pools keep the expiry of their freeze to be active again once it expires
+        if new_tag == Tag::FrozenStakePool {
+            StakePoolHeader::set_freeze_expiry(account, params.expiry);
+        } else if current_tag == Tag::FrozenStakePool {
+            StakePoolHeader::set_freeze_expiry(account, 0);
+        }
+    }
 
     Ok(())
 }
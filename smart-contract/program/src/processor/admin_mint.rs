@@ -9,10 +9,12 @@ use solana_program::{
 };
 
 use crate::error::AccessError;
-use crate::state::CentralState;
+use crate::state::{AdminAction, CentralState};
 use bonfida_utils::{BorshSize, InstructionsAccount};
 
-use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, check_token_program, log_admin_action,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 pub struct Params {
@@ -39,6 +41,10 @@ pub struct Accounts<'a, T> {
 
     /// The SPL token program account
     pub spl_token_program: &'a T,
+
+    /// The admin log, required once it has been created
+    #[cons(writable)]
+    pub admin_log: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -53,16 +59,18 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             access_token_destination: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
             spl_token_program: next_account_info(accounts_iter)?,
+            admin_log: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
 
         // Check signer
@@ -94,9 +102,18 @@ pub fn process_admin_mint(
         AccessError::WrongCentralStateAuthority,
     )?;
 
+    log_admin_action(
+        program_id,
+        accounts.admin_log,
+        &central_state,
+        AdminAction::Mint,
+        accounts.access_token_destination.key,
+        &params.try_to_vec()?,
+    )?;
+
     // Transfer tokens
-    let transfer_ix = spl_token::instruction::mint_to(
-        &spl_token::ID,
+    let transfer_ix = spl_token_2022::instruction::mint_to(
+        accounts.spl_token_program.key,
         accounts.mint.key,
         accounts.access_token_destination.key,
         accounts.central_state.key,
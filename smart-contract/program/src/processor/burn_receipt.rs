@@ -0,0 +1,131 @@
+//! Burn a subscription receipt
+//! The owner of the stake account can burn its receipt at any time, anyone can burn the receipt of a stake account that has been closed
+//! or no longer has access to its pool, including the pools that are frozen or deprecated.
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::cpi::access::has_access;
+use crate::error::AccessError;
+use crate::state::{CentralState, StakeAccount, Tag};
+use crate::utils::{burn_receipt_tokens, check_account_key, check_account_owner};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `burn_receipt` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `burn_receipt` instruction
+pub struct Accounts<'a, T> {
+    /// The central state account
+    pub central_state: &'a T,
+
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake account
+    #[cons(writable)]
+    pub stake_account: &'a T,
+
+    /// The receipt mint account
+    #[cons(writable)]
+    pub receipt_mint: &'a T,
+
+    /// The receipt token account
+    #[cons(writable)]
+    pub receipt_token: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// Optional owner of the stake account
+    #[cons(signer)]
+    pub owner: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_account: next_account_info(accounts_iter)?,
+            receipt_mint: next_account_info(accounts_iter)?,
+            receipt_token: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_burn_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    // Closed stake accounts are drained of their lamports
+    let is_closed = accounts.stake_account.lamports() == 0
+        || accounts.stake_account.data_is_empty()
+        || accounts.stake_account.data.borrow()[0] == Tag::Deleted as u8;
+    if !is_closed {
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+        let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+        check_account_key(
+            accounts.stake_pool,
+            &stake_account.stake_pool,
+            AccessError::StakePoolMismatch,
+        )?;
+        let signed_by_owner = accounts
+            .owner
+            .map(|o| o.is_signer && o.key == &stake_account.owner)
+            .unwrap_or(false);
+        // Frozen and deprecated pools do not grant access
+        let is_active_pool = accounts.stake_pool.data.borrow()[0] == Tag::StakePool as u8;
+        if !signed_by_owner
+            && is_active_pool
+            && has_access(
+                &stake_account.owner,
+                accounts.stake_pool,
+                accounts.stake_account,
+                None,
+            )?
+        {
+            msg!("The stake account still has access");
+            return Err(AccessError::NoOp.into());
+        }
+        stake_account.has_receipt = false;
+        stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
+    }
+
+    burn_receipt_tokens(
+        program_id,
+        accounts.central_state,
+        &central_state,
+        accounts.stake_account.key,
+        accounts.receipt_mint,
+        accounts.receipt_token,
+        accounts.spl_token_program,
+    )
+}
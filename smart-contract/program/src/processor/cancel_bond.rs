@@ -0,0 +1,115 @@
+//! Cancel a bond
+//! This instruction allows authorized sellers to remove an expired bond that was never claimed and refund its rent
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::error::AccessError;
+use crate::state::BondAccount;
+#[cfg(not(feature = "no-bond-signer"))]
+use crate::utils::assert_authorized_seller;
+use crate::utils::{check_account_key, check_account_owner, check_signer, update_user_index};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `cancel_bond` instruction
+pub struct Params {
+    /// Index of the seller in the [`array`][`crate::state::AUTHORIZED_BOND_SELLERS`] of authorized sellers
+    pub seller_index: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `cancel_bond` instruction
+pub struct Accounts<'a, T> {
+    /// The bond seller account
+    #[cons(signer)]
+    pub seller: &'a T,
+
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The account that paid the rent of the bond
+    #[cons(writable)]
+    pub fee_payer: &'a T,
+
+    /// Optional user index of the bond owner
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            seller: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+            user_index: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BondSellerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_cancel_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut bond = BondAccount::from_account_info(accounts.bond_account, true)?;
+
+    #[cfg(not(feature = "no-bond-signer"))]
+    assert_authorized_seller(accounts.seller, params.seller_index as usize)?;
+    #[cfg(feature = "no-bond-signer")]
+    let _ = params;
+
+    check_account_key(
+        accounts.fee_payer,
+        &bond.fee_payer,
+        AccessError::WrongFeePayer,
+    )?;
+
+    if !bond.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Only expired bonds can be cancelled");
+        return Err(AccessError::BondNotExpired.into());
+    }
+
+    bond.close();
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    update_user_index(accounts.user_index, &bond.owner, program_id, |index| {
+        index.remove_bond_account(accounts.bond_account.key);
+        Ok(())
+    })?;
+
+    let mut bond_lamports = accounts.bond_account.lamports.borrow_mut();
+    let mut fee_payer_lamports = accounts.fee_payer.lamports.borrow_mut();
+
+    **fee_payer_lamports += **bond_lamports;
+    **bond_lamports = 0;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//! Cancel a bond listing
+//! This instruction gives the bond back to the seller and returns the rent of the listing
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondAccount, BondListing, Tag};
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `cancel_bond_listing` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `cancel_bond_listing` instruction
+pub struct Accounts<'a, T> {
+    /// The bond listing account
+    #[cons(writable)]
+    pub bond_listing: &'a T,
+
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The seller of the bond
+    #[cons(writable, signer)]
+    pub seller: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_listing: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter)?,
+            seller: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(accounts.bond_listing, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_cancel_bond_listing(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut listing = BondListing::from_account_info(accounts.bond_listing)?;
+    let mut bond = BondAccount::from_listed_account_info(accounts.bond_account)?;
+
+    check_account_key(
+        accounts.bond_account,
+        &listing.bond_account,
+        AccessError::AccountNotDeterministic,
+    )?;
+    check_account_key(
+        accounts.seller,
+        &listing.seller,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    bond.tag = Tag::BondAccount;
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    listing.close();
+    listing.save(&mut accounts.bond_listing.data.borrow_mut())?;
+
+    let mut listing_lamports = accounts.bond_listing.lamports.borrow_mut();
+    let mut seller_lamports = accounts.seller.lamports.borrow_mut();
+
+    **seller_lamports += **listing_lamports;
+    **listing_lamports = 0;
+
+    Ok(())
+}
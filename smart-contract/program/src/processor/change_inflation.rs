@@ -7,10 +7,13 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{error::AccessError, state::CentralState};
+use crate::{
+    error::AccessError,
+    state::{AdminAction, CentralState},
+};
 use bonfida_utils::{BorshSize, InstructionsAccount};
 
-use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::utils::{check_account_key, check_account_owner, check_signer, log_admin_action};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `change_inflation` instruction
@@ -29,6 +32,10 @@ pub struct Accounts<'a, T> {
     /// The account of the central state authority
     #[cons(signer)]
     pub authority: &'a T,
+
+    /// The admin log, required once it has been created
+    #[cons(writable)]
+    pub admin_log: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -40,6 +47,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         let accounts = Accounts {
             central_state: next_account_info(accounts_iter)?,
             authority: next_account_info(accounts_iter)?,
+            admin_log: next_account_info(accounts_iter).ok(),
         };
 
         // Check ownership
@@ -70,6 +78,15 @@ pub fn process_change_inflation(
         AccessError::WrongCentralStateAuthority,
     )?;
 
+    log_admin_action(
+        program_id,
+        accounts.admin_log,
+        &central_state,
+        AdminAction::ChangeInflation,
+        accounts.central_state.key,
+        &params.try_to_vec()?,
+    )?;
+
     central_state.daily_inflation = params.daily_inflation;
     central_state.save(&mut accounts.central_state.data.borrow_mut())?;
 
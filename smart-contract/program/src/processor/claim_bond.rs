@@ -3,23 +3,42 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 use crate::state::{BondAccount, CentralState, StakePool, BOND_SIGNER_THRESHOLD};
 use crate::{error::AccessError, state::Tag};
 use bonfida_utils::{BorshSize, InstructionsAccount};
-use spl_token;
 
-use crate::utils::{assert_bond_derivation, check_account_key, check_account_owner, check_signer};
+use crate::utils::{
+    activate_bond, amount_with_transfer_fee, assert_bond_derivation, check_account_key,
+    check_account_owner, check_signer, check_token_program, next_optional_account_info,
+    transfer_checked, transfer_tokens, update_user_index,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `claim_bond` instruction
-pub struct Params {}
+pub struct Params {
+    /// Whether the quote mint and its token program are passed, they are required by Token-2022 quote mints
+    pub has_quote_mint: bool,
+}
+
+impl Params {
+    /// Decodes the parameters, legacy clients send no parameters and don't pass the quote mint
+    pub fn unpack(instruction_data: &[u8]) -> Result<Self, ProgramError> {
+        if instruction_data.is_empty() {
+            return Ok(Params {
+                has_quote_mint: false,
+            });
+        }
+        Params::try_from_slice(instruction_data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
 
 #[derive(InstructionsAccount)]
 /// The required accounts for the `claim_bond_rewards` instruction
@@ -56,14 +75,25 @@ pub struct Accounts<'a, T> {
     #[cons(writable)]
     pub central_state: &'a T,
 
-    /// The SPL token program account
+    /// The token program of the ACCESS mint
     pub spl_token_program: &'a T,
+
+    /// The mint of the quote token
+    pub quote_mint: Option<&'a T>,
+
+    /// The token program of the quote mint
+    pub quote_token_program: Option<&'a T>,
+
+    /// Optional user index of the buyer
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
     pub fn parse(
         accounts: &'a [AccountInfo<'b>],
         program_id: &Pubkey,
+        params: &Params,
     ) -> Result<Self, ProgramError> {
         let accounts_iter = &mut accounts.iter();
         let accounts = Accounts {
@@ -76,19 +106,31 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             pool_vault: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
             spl_token_program: next_account_info(accounts_iter)?,
+            quote_mint: next_optional_account_info(accounts_iter, params.has_quote_mint)?,
+            quote_token_program: next_optional_account_info(accounts_iter, params.has_quote_mint)?,
+            user_index: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::id(),
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
+        if let Some(quote_token_program) = accounts.quote_token_program {
+            check_token_program(quote_token_program)?;
+        }
 
         // Check ownership
+        check_account_owner(
+            accounts.access_mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
         check_account_owner(accounts.bond_account, program_id, AccessError::WrongOwner)?;
         check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        if let (Some(quote_mint), Some(quote_token_program)) =
+            (accounts.quote_mint, accounts.quote_token_program)
+        {
+            check_account_owner(quote_mint, quote_token_program.key, AccessError::WrongOwner)?;
+        }
 
         Ok(accounts)
     }
@@ -97,9 +139,9 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
 pub fn process_claim_bond(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    _params: Params,
+    params: Params,
 ) -> ProgramResult {
-    let accounts = Accounts::parse(accounts, program_id)?;
+    let accounts = Accounts::parse(accounts, program_id, &params)?;
     let mut bond = BondAccount::from_account_info(accounts.bond_account, true)?;
     let mut central_state = CentralState::from_account_info(accounts.central_state)?;
     let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
@@ -125,6 +167,11 @@ pub fn process_claim_bond(
         AccessError::StakePoolVaultMismatch,
     )?;
 
+    if bond.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("The bond has expired");
+        return Err(AccessError::BondExpired.into());
+    }
+
     if bond.sellers.len() < BOND_SIGNER_THRESHOLD as usize {
         msg!("Not enough sellers have signed");
         return Err(AccessError::NotEnoughSellers.into());
@@ -145,60 +192,49 @@ pub fn process_claim_bond(
         msg!("Checking buyer signature");
         // Check signer
         check_signer(accounts.buyer, AccessError::BuyerMustSign)?;
-        // Transfer tokens
-        let transfer_ix = spl_token::instruction::transfer(
-            &spl_token::ID,
-            accounts.quote_token_source.key,
-            accounts.quote_token_destination.key,
-            accounts.buyer.key,
-            &[],
-            bond.total_quote_amount,
-        )?;
-        invoke(
-            &transfer_ix,
-            &[
-                accounts.spl_token_program.clone(),
-                accounts.quote_token_destination.clone(),
-                accounts.quote_token_source.clone(),
-                accounts.buyer.clone(),
-            ],
-        )?;
+        match (accounts.quote_mint, accounts.quote_token_program) {
+            (Some(quote_mint), Some(quote_token_program)) => {
+                check_account_key(quote_mint, &bond.quote_mint, AccessError::WrongQuoteMint)?;
+                // Transfer tokens, the buyer pays the transfer fee of the quote mint on top of the price
+                transfer_checked(
+                    quote_token_program,
+                    accounts.quote_token_source,
+                    quote_mint,
+                    accounts.quote_token_destination,
+                    accounts.buyer,
+                    amount_with_transfer_fee(quote_mint, bond.total_quote_amount)?,
+                    &[],
+                )?;
+            }
+            // Legacy clients pay with SPL token quote mints
+            _ => transfer_tokens(
+                accounts.spl_token_program,
+                accounts.quote_token_source,
+                None,
+                accounts.quote_token_destination,
+                accounts.buyer,
+                bond.total_quote_amount,
+                &[],
+            )?,
+        }
     }
 
     // Activate the bond account
-    bond.activate(central_state.last_snapshot_offset)?;
-
-    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
-
-    // Mint ACCESS tokens into the pool vault
-    let mint_ix = spl_token::instruction::mint_to(
-        &spl_token::ID,
-        accounts.access_mint.key,
-        accounts.pool_vault.key,
-        accounts.central_state.key,
-        &[],
-        bond.total_amount_sold,
-    )?;
-
-    invoke_signed(
-        &mint_ix,
-        &[
-            accounts.spl_token_program.clone(),
-            accounts.access_mint.clone(),
-            accounts.pool_vault.clone(),
-            accounts.central_state.clone(),
-        ],
-        &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+    activate_bond(
+        program_id,
+        &mut bond,
+        accounts.bond_account,
+        &mut stake_pool,
+        &mut central_state,
+        accounts.central_state,
+        accounts.access_mint,
+        accounts.pool_vault,
+        accounts.spl_token_program,
     )?;
 
-    stake_pool.header.deposit(bond.total_amount_sold)?;
-
-    // Update central state
-    central_state.total_staked = central_state
-        .total_staked
-        .checked_add(bond.total_amount_sold)
-        .ok_or(AccessError::Overflow)?;
-    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+    update_user_index(accounts.user_index, &bond.owner, program_id, |index| {
+        index.add_bond_account(*accounts.bond_account.key)
+    })?;
 
     Ok(())
 }
@@ -7,18 +7,17 @@ use solana_program::{
     msg,
     program::invoke_signed,
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
 };
 
 use crate::state::{BondAccount, CentralState, StakePool};
 use crate::{error::AccessError, state::Tag};
 use bonfida_utils::{fp_math::safe_downcast, BorshSize, InstructionsAccount};
-use spl_token::{instruction::mint_to, state::Account};
+use spl_token_2022::instruction::mint_to;
 
 use crate::utils::{
     assert_no_close_or_delegate, calc_reward_fp32, check_account_key, check_account_owner,
-    check_signer,
+    check_signer, check_token_program, unpack_token_account,
 };
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
@@ -72,11 +71,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
         check_account_owner(
@@ -91,11 +86,15 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         )?;
         check_account_owner(
             accounts.rewards_destination,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongOwner,
         )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
-        check_account_owner(accounts.mint, &spl_token::ID, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
 
         Ok(accounts)
     }
@@ -114,10 +113,13 @@ pub fn process_claim_bond_rewards(
     let accounts = Accounts::parse(accounts, program_id)?;
 
     let central_state = CentralState::from_account_info(accounts.central_state)?;
-    let stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
     let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
 
-    let destination_token_acc = Account::unpack(&accounts.rewards_destination.data.borrow())?;
+    let destination_token_acc = unpack_token_account(accounts.rewards_destination)?;
     if destination_token_acc.mint != central_state.token_mint {
         return Err(AccessError::WrongMint.into());
     }
@@ -164,7 +166,7 @@ pub fn process_claim_bond_rewards(
 
     // Transfer rewards
     let transfer_ix = mint_to(
-        &spl_token::ID,
+        accounts.spl_token_program.key,
         accounts.mint.key,
         accounts.rewards_destination.key,
         accounts.central_state.key,
@@ -183,6 +185,7 @@ pub fn process_claim_bond_rewards(
     )?;
 
     // Update states
+    stake_pool.header.record_staker_rewards(reward);
     bond.last_claimed_offset = central_state.last_snapshot_offset;
     bond.save(&mut accounts.bond_account.data.borrow_mut())?;
 
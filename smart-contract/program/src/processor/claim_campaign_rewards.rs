@@ -0,0 +1,282 @@
+//! Claim the rewards of a reward campaign
+//! This instruction can be used by stakers to claim their share of a secondary token campaign.
+//! The first call registers the stake account in the campaign and does not pay out anything.
+//! Claiming once the campaign is over or the stake account is empty closes the position.
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use crate::state::{
+    CampaignPosition, RewardCampaign, StakeAccount, StakePool, MAX_CAMPAIGN_POSITIONS,
+};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, check_token_program, transfer_checked,
+    unpack_token_account,
+};
+use crate::{cpi::Cpi, error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `claim_campaign_rewards` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `claim_campaign_rewards` instruction
+pub struct Accounts<'a, T> {
+    /// The reward campaign account
+    #[cons(writable)]
+    pub reward_campaign: &'a T,
+
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake account
+    #[cons(writable)]
+    pub stake_account: &'a T,
+
+    /// The owner of the stake account
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+
+    /// The campaign position of the stake account
+    #[cons(writable)]
+    pub campaign_position: &'a T,
+
+    /// The campaign vault account
+    #[cons(writable)]
+    pub vault: &'a T,
+
+    /// The rewards destination
+    #[cons(writable)]
+    pub rewards_destination: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The mint of the reward token
+    pub mint: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            reward_campaign: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_account: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            campaign_position: next_account_info(accounts_iter)?,
+            vault: next_account_info(accounts_iter)?,
+            rewards_destination: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.reward_campaign,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.rewards_destination,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_claim_campaign_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut campaign = RewardCampaign::from_account_info(accounts.reward_campaign)?;
+    // Positions in deprecated pools can still be claimed and closed
+    let stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
+    let is_deprecated = stake_pool.header.tag == Tag::DeprecatedStakePool as u8;
+    drop(stake_pool);
+    let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    check_account_key(
+        accounts.stake_pool,
+        &campaign.stake_pool,
+        AccessError::StakePoolMismatch,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &stake_account.stake_pool,
+        AccessError::StakePoolMismatch,
+    )?;
+    check_account_key(
+        accounts.owner,
+        &stake_account.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+    check_account_key(accounts.mint, &campaign.mint, AccessError::WrongMint)?;
+    check_account_key(
+        accounts.vault,
+        &campaign.vault,
+        AccessError::CampaignVaultMismatch,
+    )?;
+
+    let (position_key, position_nonce) = CampaignPosition::find_key(
+        accounts.reward_campaign.key,
+        accounts.stake_account.key,
+        program_id,
+    );
+    check_account_key(
+        accounts.campaign_position,
+        &position_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+
+    campaign.update_accumulator(current_time)?;
+
+    if accounts.campaign_position.data_is_empty() {
+        msg!("Registering stake account in the campaign");
+        if current_time >= campaign.end_time || stake_account.stake_amount == 0 || is_deprecated {
+            msg!("Only stake accounts with a stake can join a running campaign of an active pool");
+            return Err(ProgramError::InvalidArgument);
+        }
+        if stake_account.campaign_positions >= MAX_CAMPAIGN_POSITIONS {
+            return Err(AccessError::TooManyCampaignPositions.into());
+        }
+        let position = CampaignPosition::new(
+            *accounts.reward_campaign.key,
+            *accounts.stake_account.key,
+            campaign.reward_per_token_fp32,
+            stake_account.stake_amount,
+        );
+        Cpi::create_account(
+            program_id,
+            accounts.system_program,
+            accounts.owner,
+            accounts.campaign_position,
+            &[
+                CampaignPosition::SEED,
+                &accounts.reward_campaign.key.to_bytes(),
+                &accounts.stake_account.key.to_bytes(),
+                &[position_nonce],
+            ],
+            position.borsh_len(),
+        )?;
+        position.save(&mut accounts.campaign_position.data.borrow_mut())?;
+        campaign.update_registered_stake(0, stake_account.stake_amount)?;
+        campaign.save(&mut accounts.reward_campaign.data.borrow_mut())?;
+        stake_account.campaign_positions += 1;
+        stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
+        return Ok(());
+    }
+
+    check_account_owner(
+        accounts.campaign_position,
+        program_id,
+        AccessError::WrongOwner,
+    )?;
+    let mut position = CampaignPosition::from_account_info(accounts.campaign_position)?;
+
+    let destination_token_acc = unpack_token_account(accounts.rewards_destination)?;
+    if destination_token_acc.mint != campaign.mint {
+        return Err(AccessError::WrongMint.into());
+    }
+
+    position.checkpoint(campaign.reward_per_token_fp32)?;
+    let reward = position.pending_rewards;
+
+    msg!("Claiming campaign rewards {}", reward);
+
+    if reward > 0 {
+        transfer_checked(
+            accounts.spl_token_program,
+            accounts.vault,
+            accounts.mint,
+            accounts.rewards_destination,
+            accounts.reward_campaign,
+            reward,
+            &[&[
+                RewardCampaign::SEED,
+                &campaign.stake_pool.to_bytes(),
+                &campaign.mint.to_bytes(),
+                &[campaign.nonce],
+            ]],
+        )?;
+    }
+
+    // Update states
+    position.pending_rewards = 0;
+    campaign.total_claimed = campaign
+        .total_claimed
+        .checked_add(reward)
+        .ok_or(AccessError::Overflow)?;
+
+    if current_time >= campaign.end_time || position.stake_amount == 0 {
+        msg!("Closing the campaign position");
+        campaign.update_registered_stake(position.stake_amount, 0)?;
+        stake_account.campaign_positions -= 1;
+        stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
+
+        position.tag = Tag::Deleted;
+        position.save(&mut accounts.campaign_position.data.borrow_mut())?;
+
+        let mut position_lamports = accounts.campaign_position.lamports.borrow_mut();
+        let mut owner_lamports = accounts.owner.lamports.borrow_mut();
+        **owner_lamports += **position_lamports;
+        **position_lamports = 0;
+    } else {
+        position.save(&mut accounts.campaign_position.data.borrow_mut())?;
+    }
+
+    campaign.save(&mut accounts.reward_campaign.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//! Claim tokens from a Merkle distributor
+//! This permissionless instruction mints the tokens of a leaf of the distribution to a token account of its recipient
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{CentralState, Distributor};
+use crate::utils::{
+    check_account_key, check_account_owner, check_token_program, unpack_token_account,
+    verify_merkle_proof,
+};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `claim_distribution` instruction
+pub struct Params {
+    /// Position of the leaf in the Merkle tree
+    pub index: u64,
+    /// Amount of tokens distributed to the recipient
+    pub amount: u64,
+    /// Merkle proof of the leaf
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `claim_distribution` instruction
+pub struct Accounts<'a, T> {
+    /// The distributor account
+    #[cons(writable)]
+    pub distributor: &'a T,
+
+    /// The recipient of the leaf
+    pub recipient: &'a T,
+
+    /// The token account of the recipient
+    #[cons(writable)]
+    pub recipient_token_account: &'a T,
+
+    /// The account of the central state
+    pub central_state: &'a T,
+
+    /// The mint of the ACCESS token
+    #[cons(writable)]
+    pub mint: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            distributor: next_account_info(accounts_iter)?,
+            recipient: next_account_info(accounts_iter)?,
+            recipient_token_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.distributor, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.recipient_token_account,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_claim_distribution(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut distributor = Distributor::from_account_info(accounts.distributor)?;
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    check_account_key(
+        accounts.mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+
+    let destination_token_acc = unpack_token_account(accounts.recipient_token_account)?;
+    if destination_token_acc.mint != central_state.token_mint {
+        return Err(AccessError::WrongMint.into());
+    }
+    if &destination_token_acc.owner != accounts.recipient.key {
+        msg!("The tokens must be sent to the recipient");
+        return Err(AccessError::WrongOwner.into());
+    }
+
+    if params.index >= distributor.num_nodes {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if distributor.is_claimed(params.index) {
+        msg!("The leaf {} has already been claimed", params.index);
+        return Err(AccessError::AlreadyClaimed.into());
+    }
+
+    let leaf = Distributor::leaf(params.index, accounts.recipient.key, params.amount);
+    if !verify_merkle_proof(&params.proof, &distributor.merkle_root, leaf) {
+        return Err(AccessError::InvalidMerkleProof.into());
+    }
+
+    distributor.total_claimed = distributor
+        .total_claimed
+        .checked_add(params.amount)
+        .ok_or(AccessError::Overflow)?;
+    if distributor.total_claimed > distributor.max_total_claim {
+        msg!("The distribution budget is exhausted");
+        return Err(AccessError::DistributionBudgetExceeded.into());
+    }
+    distributor.set_claimed(params.index);
+    distributor.save(&mut accounts.distributor.data.borrow_mut())?;
+
+    // Mint the tokens
+    let mint_ix = spl_token_2022::instruction::mint_to(
+        accounts.spl_token_program.key,
+        accounts.mint.key,
+        accounts.recipient_token_account.key,
+        accounts.central_state.key,
+        &[],
+        params.amount,
+    )?;
+    invoke_signed(
+        &mint_ix,
+        &[
+            accounts.spl_token_program.clone(),
+            accounts.central_state.clone(),
+            accounts.mint.clone(),
+            accounts.recipient_token_account.clone(),
+        ],
+        &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+//! Claim rewards of many stake accounts and bonds
+//! This instruction can be used by stakers to claim the rewards of all their stake accounts and bonds at once
+use crate::error::AccessError;
+use crate::state::{BondAccount, CentralState, StakeAccount, StakePool, Tag};
+use crate::utils::{
+    calc_reward_fp32, check_account_key, check_account_owner, check_signer, check_token_program,
+    unpack_token_account,
+};
+use bonfida_utils::fp_math::safe_downcast;
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program::invoke_signed;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token_2022::instruction::mint_to;
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `claim_multiple_rewards` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `claim_multiple_rewards` instruction
+pub struct Accounts<'a, T> {
+    /// The owner of the stake accounts and bonds
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The rewards destination
+    #[cons(writable)]
+    pub rewards_destination: &'a T,
+
+    /// The central state account
+    pub central_state: &'a T,
+
+    /// The mint address of the ACCESS token
+    #[cons(writable)]
+    pub mint: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// (stake pool, stake or bond account, referrer rewards destination of referred stakers) groups
+    #[cons(writable)]
+    pub claim_accounts: &'a [T],
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            owner: next_account_info(accounts_iter)?,
+            rewards_destination: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            claim_accounts: accounts_iter.as_slice(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.rewards_destination,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        if accounts.claim_accounts.is_empty() {
+            msg!("No claimed accounts");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_claim_multiple_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    let destination_token_acc = unpack_token_account(accounts.rewards_destination)?;
+    if destination_token_acc.mint != central_state.token_mint {
+        return Err(AccessError::WrongMint.into());
+    }
+    check_account_key(
+        accounts.mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+
+    let mut total_reward = 0u64;
+    let claim_accounts = &mut accounts.claim_accounts.iter();
+    while let Some(stake_pool_account) = claim_accounts.next() {
+        let claimed_account = next_account_info(claim_accounts)?;
+        check_account_owner(stake_pool_account, program_id, AccessError::WrongOwner)?;
+        check_account_owner(claimed_account, program_id, AccessError::WrongOwner)?;
+        let mut stake_pool = StakePool::get_checked(
+            stake_pool_account,
+            vec![Tag::StakePool, Tag::DeprecatedStakePool],
+        )?;
+        let tag = claimed_account.data.borrow()[0];
+
+        let mut referral_reward = 0;
+        let reward = if tag == Tag::StakeAccount as u8 {
+            let mut stake_account = StakeAccount::from_account_info(claimed_account)?;
+            check_account_key(
+                stake_pool_account,
+                &stake_account.stake_pool,
+                AccessError::WrongStakePool,
+            )?;
+            check_account_key(
+                accounts.owner,
+                &stake_account.owner,
+                AccessError::StakeAccountOwnerMismatch,
+            )?;
+            let reward = calc_reward_fp32(
+                central_state.last_snapshot_offset,
+                stake_account.last_claimed_offset,
+                &stake_pool,
+                true,
+                true,
+            )?
+            .checked_mul(stake_account.stake_amount as u128)
+            .map(|r| ((r >> 31) + 1) >> 1)
+            .and_then(safe_downcast)
+            .ok_or(AccessError::Overflow)?;
+
+            // Part of the rewards goes to the referrer of the staker
+            if stake_account.has_referrer() && central_state.referral_reward_share > 0 {
+                let referrer_rewards_destination = next_account_info(claim_accounts)?;
+                check_account_owner(
+                    referrer_rewards_destination,
+                    accounts.spl_token_program.key,
+                    AccessError::WrongOwner,
+                )?;
+                let referrer_token_acc = unpack_token_account(referrer_rewards_destination)?;
+                if referrer_token_acc.owner != stake_account.referrer {
+                    return Err(AccessError::ReferrerMismatch.into());
+                }
+                if referrer_token_acc.mint != central_state.token_mint {
+                    return Err(AccessError::WrongMint.into());
+                }
+                referral_reward = reward
+                    .checked_mul(central_state.referral_reward_share)
+                    .ok_or(AccessError::Overflow)?
+                    / 100;
+
+                msg!("Referral rewards {}", referral_reward);
+
+                let transfer_ix = mint_to(
+                    accounts.spl_token_program.key,
+                    accounts.mint.key,
+                    referrer_rewards_destination.key,
+                    accounts.central_state.key,
+                    &[],
+                    referral_reward,
+                )?;
+                invoke_signed(
+                    &transfer_ix,
+                    &[
+                        accounts.spl_token_program.clone(),
+                        accounts.mint.clone(),
+                        accounts.central_state.clone(),
+                        referrer_rewards_destination.clone(),
+                    ],
+                    &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+                )?;
+            }
+
+            stake_account.last_claimed_offset = central_state.last_snapshot_offset;
+            stake_account.save(&mut claimed_account.data.borrow_mut())?;
+            reward
+        } else if tag == Tag::BondAccount as u8 {
+            if cfg!(feature = "claim-bond-rewards-off") {
+                return Err(AccessError::NoOp.into());
+            }
+            let mut bond = BondAccount::from_account_info(claimed_account, false)?;
+            check_account_key(
+                stake_pool_account,
+                &bond.stake_pool,
+                AccessError::WrongStakePool,
+            )?;
+            check_account_key(
+                accounts.owner,
+                &bond.owner,
+                AccessError::StakeAccountOwnerMismatch,
+            )?;
+
+            let reward = calc_reward_fp32(
+                central_state.last_snapshot_offset,
+                bond.last_claimed_offset,
+                &stake_pool,
+                true,
+                true,
+            )?
+            .checked_mul(bond.total_staked as u128)
+            .map(|r| ((r >> 31) + 1) >> 1)
+            .and_then(safe_downcast)
+            .ok_or(AccessError::Overflow)?;
+
+            bond.last_claimed_offset = central_state.last_snapshot_offset;
+            bond.save(&mut claimed_account.data.borrow_mut())?;
+            reward
+        } else {
+            return Err(AccessError::DataTypeMismatch.into());
+        };
+
+        stake_pool.header.record_staker_rewards(reward);
+        total_reward = total_reward
+            .checked_add(reward - referral_reward)
+            .ok_or(AccessError::Overflow)?;
+    }
+
+    msg!("Claiming rewards {}", total_reward);
+
+    if total_reward == 0 {
+        return Err(AccessError::NoOp.into());
+    }
+
+    // Transfer rewards
+    let transfer_ix = mint_to(
+        accounts.spl_token_program.key,
+        accounts.mint.key,
+        accounts.rewards_destination.key,
+        accounts.central_state.key,
+        &[],
+        total_reward,
+    )?;
+    invoke_signed(
+        &transfer_ix,
+        &[
+            accounts.spl_token_program.clone(),
+            accounts.mint.clone(),
+            accounts.central_state.clone(),
+            accounts.rewards_destination.clone(),
+        ],
+        &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+    )?;
+
+    Ok(())
+}
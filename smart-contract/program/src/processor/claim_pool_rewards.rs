@@ -4,7 +4,7 @@ use crate::error::AccessError;
 use crate::state::{CentralState, StakePool, Tag};
 use crate::utils::{
     assert_no_close_or_delegate, calc_reward_fp32, check_account_key, check_account_owner,
-    check_signer,
+    check_signer, check_token_program, unpack_token_account,
 };
 use bonfida_utils::fp_math::safe_downcast;
 use bonfida_utils::{BorshSize, InstructionsAccount};
@@ -15,10 +15,9 @@ use solana_program::{
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
 };
-use spl_token::{instruction::mint_to, state::Account};
+use spl_token_2022::instruction::mint_to;
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `claim_pool_rewards` instruction
@@ -66,11 +65,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
         check_account_owner(
@@ -80,11 +75,15 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         )?;
         check_account_owner(
             accounts.rewards_destination,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongOwner,
         )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
-        check_account_owner(accounts.mint, &spl_token::ID, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
 
         Ok(accounts)
     }
@@ -98,9 +97,12 @@ pub fn process_claim_pool_rewards(
     let accounts = Accounts::parse(accounts, program_id)?;
 
     let central_state = CentralState::from_account_info(accounts.central_state)?;
-    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
 
-    let destination_token_acc = Account::unpack(&accounts.rewards_destination.data.borrow())?;
+    let destination_token_acc = unpack_token_account(accounts.rewards_destination)?;
 
     if destination_token_acc.mint != central_state.token_mint {
         return Err(AccessError::WrongMint.into());
@@ -141,7 +143,7 @@ pub fn process_claim_pool_rewards(
 
     // Transfer rewards
     let transfer_ix = mint_to(
-        &spl_token::ID,
+        accounts.spl_token_program.key,
         accounts.mint.key,
         accounts.rewards_destination.key,
         accounts.central_state.key,
@@ -161,6 +163,7 @@ pub fn process_claim_pool_rewards(
 
     // Update stake pool state
     stake_pool.header.last_claimed_offset = central_state.last_snapshot_offset;
+    stake_pool.header.record_owner_rewards(reward);
 
     Ok(())
 }
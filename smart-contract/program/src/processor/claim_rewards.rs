@@ -4,7 +4,7 @@ use crate::error::AccessError;
 use crate::state::{CentralState, StakeAccount, StakePool, Tag};
 use crate::utils::{
     assert_no_close_or_delegate, calc_reward_fp32, check_account_key, check_account_owner,
-    check_signer,
+    check_signer, check_token_program, unpack_token_account,
 };
 use bonfida_utils::fp_math::safe_downcast;
 use bonfida_utils::{BorshSize, InstructionsAccount};
@@ -15,10 +15,9 @@ use solana_program::{
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
 };
-use spl_token::{instruction::mint_to, state::Account};
+use spl_token_2022::instruction::mint_to;
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `claim_rewards` instruction
@@ -55,6 +54,10 @@ pub struct Accounts<'a, T> {
 
     /// The SPL token program account
     pub spl_token_program: &'a T,
+
+    /// Optional token account of the referrer, required when the staker was referred
+    #[cons(writable)]
+    pub referrer_rewards_destination: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -71,14 +74,11 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             central_state: next_account_info(accounts_iter)?,
             mint: next_account_info(accounts_iter)?,
             spl_token_program: next_account_info(accounts_iter)?,
+            referrer_rewards_destination: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
         check_account_owner(
@@ -93,11 +93,15 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         )?;
         check_account_owner(
             accounts.rewards_destination,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongOwner,
         )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
-        check_account_owner(accounts.mint, &spl_token::ID, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
 
         Ok(accounts)
     }
@@ -111,10 +115,13 @@ pub fn process_claim_rewards(
     let accounts = Accounts::parse(accounts, program_id)?;
 
     let central_state = CentralState::from_account_info(accounts.central_state)?;
-    let stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
     let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
 
-    let destination_token_acc = Account::unpack(&accounts.rewards_destination.data.borrow())?;
+    let destination_token_acc = unpack_token_account(accounts.rewards_destination)?;
     msg!("Account owner: {}", destination_token_acc.owner);
 
     if destination_token_acc.owner != stake_account.owner {
@@ -157,16 +164,61 @@ pub fn process_claim_rewards(
     .and_then(safe_downcast)
     .ok_or(AccessError::Overflow)?;
 
-    msg!("Claiming rewards {}", reward);
+    // Part of the rewards goes to the referrer of the staker
+    let mut referral_reward = 0;
+    if stake_account.has_referrer() && central_state.referral_reward_share > 0 {
+        let referrer_rewards_destination = accounts
+            .referrer_rewards_destination
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        check_account_owner(
+            referrer_rewards_destination,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        let referrer_token_acc = unpack_token_account(referrer_rewards_destination)?;
+        if referrer_token_acc.owner != stake_account.referrer {
+            return Err(AccessError::ReferrerMismatch.into());
+        }
+        if referrer_token_acc.mint != central_state.token_mint {
+            return Err(AccessError::WrongMint.into());
+        }
+        referral_reward = reward
+            .checked_mul(central_state.referral_reward_share)
+            .ok_or(AccessError::Overflow)?
+            / 100;
+
+        msg!("Referral rewards {}", referral_reward);
+
+        let transfer_ix = mint_to(
+            accounts.spl_token_program.key,
+            accounts.mint.key,
+            referrer_rewards_destination.key,
+            accounts.central_state.key,
+            &[],
+            referral_reward,
+        )?;
+        invoke_signed(
+            &transfer_ix,
+            &[
+                accounts.spl_token_program.clone(),
+                accounts.mint.clone(),
+                accounts.central_state.clone(),
+                referrer_rewards_destination.clone(),
+            ],
+            &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+        )?;
+    }
+
+    msg!("Claiming rewards {}", reward - referral_reward);
 
     // Transfer rewards
     let transfer_ix = mint_to(
-        &spl_token::ID,
+        accounts.spl_token_program.key,
         accounts.mint.key,
         accounts.rewards_destination.key,
         accounts.central_state.key,
         &[],
-        reward,
+        reward - referral_reward,
     )?;
     invoke_signed(
         &transfer_ix,
@@ -180,6 +232,7 @@ pub fn process_claim_rewards(
     )?;
 
     // Update states
+    stake_pool.header.record_staker_rewards(reward);
     stake_account.last_claimed_offset = central_state.last_snapshot_offset;
     stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
 
@@ -0,0 +1,300 @@
+//! Claim a bond of a bond round
+//! This instruction allows a buyer to claim their bond from a signed bond round with a Merkle proof, the bond is created and activated at once
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::error::AccessError;
+use crate::state::{
+    BondAccount, BondRound, CentralState, StakePool, VestingSchedule, BOND_SIGNER_THRESHOLD,
+};
+use crate::utils::{
+    activate_bond, amount_with_transfer_fee, assert_uninitialized, check_account_key,
+    check_account_owner, check_signer, check_token_program, transfer_checked, update_user_index,
+    verify_merkle_proof,
+};
+use crate::{cpi::Cpi, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `claim_round_bond` instruction
+pub struct Params {
+    /// Position of the leaf in the Merkle tree
+    pub index: u64,
+    /// Total amount of ACCESS tokens being sold
+    pub total_amount_sold: u64,
+    /// Total price of the bond
+    pub total_quote_amount: u64,
+    /// Merkle proof of the bond in the round
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `claim_round_bond` instruction
+pub struct Accounts<'a, T> {
+    /// The bond round account
+    #[cons(writable)]
+    pub bond_round: &'a T,
+
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The account of the bond buyer
+    #[cons(writable, signer)]
+    pub buyer: &'a T,
+
+    /// The token account used to purchase the bond
+    #[cons(writable)]
+    pub quote_token_source: &'a T,
+
+    /// The token account where the sell proceed is sent
+    #[cons(writable)]
+    pub quote_token_destination: &'a T,
+
+    /// The stake pool account
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The mint of the ACCESS token
+    #[cons(writable)]
+    pub access_mint: &'a T,
+
+    /// The vault of the stake pool
+    #[cons(writable)]
+    pub pool_vault: &'a T,
+
+    /// The central state account
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The token program of the ACCESS mint
+    pub spl_token_program: &'a T,
+
+    /// The mint of the quote token
+    pub quote_mint: &'a T,
+
+    /// The token program of the quote mint
+    pub quote_token_program: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// Optional user index of the buyer
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_round: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter)?,
+            buyer: next_account_info(accounts_iter)?,
+            quote_token_source: next_account_info(accounts_iter)?,
+            quote_token_destination: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            access_mint: next_account_info(accounts_iter)?,
+            pool_vault: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            quote_mint: next_account_info(accounts_iter)?,
+            quote_token_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            user_index: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.access_mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.bond_round, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.buyer, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_claim_round_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut round = BondRound::from_account_info(accounts.bond_round)?;
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+
+    check_account_key(
+        accounts.stake_pool,
+        &round.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+    check_account_key(
+        accounts.access_mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+    check_account_key(
+        accounts.quote_token_destination,
+        &round.seller_token_account,
+        AccessError::WrongQuoteDestination,
+    )?;
+    check_account_key(
+        accounts.pool_vault,
+        &Pubkey::new(&stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+
+    if round.sellers.len() < BOND_SIGNER_THRESHOLD as usize {
+        msg!("Not enough sellers have signed");
+        return Err(AccessError::NotEnoughSellers.into());
+    }
+
+    if params.index >= round.num_nodes {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if round.is_claimed(params.index) {
+        msg!("The leaf {} has already been claimed", params.index);
+        return Err(AccessError::AlreadyClaimed.into());
+    }
+
+    let leaf = BondRound::leaf(
+        params.index,
+        accounts.buyer.key,
+        params.total_amount_sold,
+        params.total_quote_amount,
+    );
+    if !verify_merkle_proof(&params.proof, &round.merkle_root, leaf) {
+        return Err(AccessError::InvalidMerkleProof.into());
+    }
+
+    if params.total_amount_sold == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()? {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    // Create the bond account
+    let (derived_key, nonce) =
+        BondAccount::create_round_claim_key(accounts.bond_round.key, params.index, program_id);
+    check_account_key(
+        accounts.bond_account,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.bond_account)?;
+
+    let unlock_amount = params
+        .total_amount_sold
+        .checked_add(round.unlock_periods.saturating_sub(1))
+        .and_then(|a| a.checked_div(round.unlock_periods))
+        .unwrap_or(params.total_amount_sold);
+
+    let mut bond = BondAccount::new(
+        *accounts.buyer.key,
+        params.total_amount_sold,
+        params.total_quote_amount,
+        round.quote_mint,
+        round.seller_token_account,
+        round.unlock_start_date,
+        round.unlock_period,
+        unlock_amount,
+        round.unlock_start_date,
+        stake_pool.header.minimum_stake_amount,
+        *accounts.stake_pool.key,
+        round.sellers[0],
+        VestingSchedule::default(),
+        0,
+        *accounts.buyer.key,
+    );
+    bond.sellers = round.sellers.clone();
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.buyer,
+        accounts.bond_account,
+        &[
+            BondAccount::SEED,
+            &accounts.bond_round.key.to_bytes(),
+            &params.index.to_le_bytes(),
+            &[nonce],
+        ],
+        // The bond is activated at once, it does not need room for more signers
+        bond.borsh_len(),
+    )?;
+
+    round.set_claimed(params.index);
+    round.save(&mut accounts.bond_round.data.borrow_mut())?;
+
+    // Pay the seller
+    if params.total_quote_amount > 0 {
+        // The quote accounts are only used by paid bonds
+        check_token_program(accounts.quote_token_program)?;
+        check_account_key(
+            accounts.quote_mint,
+            &round.quote_mint,
+            AccessError::WrongQuoteMint,
+        )?;
+        check_account_owner(
+            accounts.quote_mint,
+            accounts.quote_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        transfer_checked(
+            accounts.quote_token_program,
+            accounts.quote_token_source,
+            accounts.quote_mint,
+            accounts.quote_token_destination,
+            accounts.buyer,
+            amount_with_transfer_fee(accounts.quote_mint, params.total_quote_amount)?,
+            &[],
+        )?;
+    }
+
+    // The round was signed by the authorized sellers, the bond is activated right away
+    activate_bond(
+        program_id,
+        &mut bond,
+        accounts.bond_account,
+        &mut stake_pool,
+        &mut central_state,
+        accounts.central_state,
+        accounts.access_mint,
+        accounts.pool_vault,
+        accounts.spl_token_program,
+    )?;
+
+    update_user_index(accounts.user_index, &bond.owner, program_id, |index| {
+        index.add_bond_account(*accounts.bond_account.key)
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+//! Close an access delegation
+//! This instruction revokes the access of all the members and returns the rent to the owner of the stake account
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::AccessDelegation;
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `close_access_delegation` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `close_access_delegation` instruction
+pub struct Accounts<'a, T> {
+    /// The access delegation account
+    #[cons(writable)]
+    pub access_delegation: &'a T,
+
+    /// The owner of the stake account
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            access_delegation: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.access_delegation,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_close_access_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut delegation = AccessDelegation::from_account_info(accounts.access_delegation)?;
+
+    check_account_key(
+        accounts.owner,
+        &delegation.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    delegation.close();
+    delegation.save(&mut accounts.access_delegation.data.borrow_mut())?;
+
+    let mut delegation_lamports = accounts.access_delegation.lamports.borrow_mut();
+    let mut owner_lamports = accounts.owner.lamports.borrow_mut();
+
+    **owner_lamports += **delegation_lamports;
+    **delegation_lamports = 0;
+
+    Ok(())
+}
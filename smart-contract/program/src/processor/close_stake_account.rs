@@ -10,6 +10,7 @@ use solana_program::{
 
 use crate::utils::{
     assert_empty_stake_account, check_account_key, check_account_owner, check_signer,
+    update_user_index,
 };
 use bonfida_utils::BorshSize;
 use bonfida_utils::InstructionsAccount;
@@ -31,6 +32,10 @@ pub struct Accounts<'a, T> {
     /// The owner of the stake account
     #[cons(writable, signer)]
     pub owner: &'a T,
+
+    /// Optional user index of the owner
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -42,6 +47,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         let accounts = Accounts {
             stake_account: next_account_info(accounts_iter)?,
             owner: next_account_info(accounts_iter)?,
+            user_index: next_account_info(accounts_iter).ok(),
         };
 
         // Check ownership
@@ -70,6 +76,16 @@ pub fn process_close_stake_account(program_id: &Pubkey, accounts: &[AccountInfo]
     stake_account.close();
     stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
 
+    update_user_index(
+        accounts.user_index,
+        accounts.owner.key,
+        program_id,
+        |index| {
+            index.remove_stake_account(accounts.stake_account.key);
+            Ok(())
+        },
+    )?;
+
     let mut stake_lamports = accounts.stake_account.lamports.borrow_mut();
     let mut owner_lamports = accounts.owner.lamports.borrow_mut();
 
@@ -1,8 +1,12 @@
 //! Close a stake pool
 //! This instruction can be used to close an empty stake pool and collect the lamports
+//! The activation deposit of a self activated pool is refunded to the owner
 use crate::{
     state::Tag,
-    utils::{assert_empty_stake_pool, check_account_key, check_account_owner, check_signer},
+    utils::{
+        assert_empty_stake_pool, check_account_key, check_account_owner, check_signer,
+        check_token_program, check_token_program_owner, transfer_checked, unpack_token_account,
+    },
 };
 use bonfida_utils::{BorshSize, InstructionsAccount};
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -11,13 +15,11 @@ use solana_program::{
     entrypoint::ProgramResult,
     msg,
     program_error::ProgramError,
-    program_pack::Pack,
     pubkey::Pubkey,
 };
-use spl_token::state::Account;
 
 use crate::error::AccessError;
-use crate::state::StakePool;
+use crate::state::{StakePool, StakePoolHeader};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `close_stake_pool` instruction
@@ -31,11 +33,22 @@ pub struct Accounts<'a, T> {
     pub stake_pool_account: &'a T,
 
     /// Pool vault
+    #[cons(writable)]
     pub pool_vault: &'a T,
 
     /// The owner of the stake pool
     #[cons(writable, signer)]
     pub owner: &'a T,
+
+    /// The token account receiving the activation deposit (required for self activated pools)
+    #[cons(writable)]
+    pub deposit_destination: Option<&'a T>,
+
+    /// The token program of the ACCESS mint (required for self activated pools)
+    pub spl_token_program: Option<&'a T>,
+
+    /// The mint of the ACCESS token (required for self activated pools)
+    pub mint: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -48,9 +61,15 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             stake_pool_account: next_account_info(accounts_iter)?,
             pool_vault: next_account_info(accounts_iter)?,
             owner: next_account_info(accounts_iter)?,
+            deposit_destination: next_account_info(accounts_iter).ok(),
+            spl_token_program: next_account_info(accounts_iter).ok(),
+            mint: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
+        if let Some(spl_token_program) = accounts.spl_token_program {
+            check_token_program(spl_token_program)?;
+        }
 
         // Check ownership
         check_account_owner(
@@ -58,7 +77,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             program_id,
             AccessError::WrongOwner,
         )?;
-        check_account_owner(accounts.pool_vault, &spl_token::ID, AccessError::WrongOwner)?;
+        check_token_program_owner(accounts.pool_vault, AccessError::WrongOwner)?;
 
         // Check signer
         check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
@@ -76,7 +95,11 @@ pub fn process_close_stake_pool(
 
     let mut stake_pool = StakePool::get_checked(
         accounts.stake_pool_account,
-        vec![Tag::InactiveStakePool, Tag::StakePool],
+        vec![
+            Tag::InactiveStakePool,
+            Tag::StakePool,
+            Tag::DeprecatedStakePool,
+        ],
     )?;
 
     check_account_key(
@@ -90,9 +113,10 @@ pub fn process_close_stake_pool(
         AccessError::StakePoolVaultMismatch,
     )?;
 
-    let vault = Account::unpack_from_slice(&accounts.pool_vault.data.borrow_mut())?;
+    let vault = unpack_token_account(accounts.pool_vault)?;
 
-    if vault.amount != 0 {
+    // Only the activation deposit may remain in the vault
+    if vault.amount != stake_pool.header.activation_deposit {
         msg!("Vault isn't empty, there are remaining unstake requests");
         return Err(AccessError::PendingUnstakeRequests.into());
     }
@@ -101,6 +125,39 @@ pub fn process_close_stake_pool(
 
     stake_pool.header.close();
 
+    let activation_deposit = stake_pool.header.activation_deposit;
+    if activation_deposit != 0 {
+        let (deposit_destination, spl_token_program, mint) = match (
+            accounts.deposit_destination,
+            accounts.spl_token_program,
+            accounts.mint,
+        ) {
+            (Some(d), Some(p), Some(m)) => (d, p, m),
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+        check_account_key(mint, &vault.mint, AccessError::WrongMint)?;
+        check_account_owner(mint, spl_token_program.key, AccessError::WrongOwner)?;
+        stake_pool.header.activation_deposit = 0;
+
+        let signer_seeds: &[&[u8]] = &[
+            StakePoolHeader::SEED,
+            &stake_pool.header.owner.clone(),
+            &[stake_pool.header.nonce],
+        ];
+
+        drop(stake_pool);
+
+        transfer_checked(
+            spl_token_program,
+            accounts.pool_vault,
+            mint,
+            deposit_destination,
+            accounts.stake_pool_account,
+            activation_deposit,
+            &[signer_seeds],
+        )?;
+    }
+
     let mut stake_pool_lamports = accounts.stake_pool_account.lamports.borrow_mut();
     let mut owner_lamports = accounts.owner.lamports.borrow_mut();
 
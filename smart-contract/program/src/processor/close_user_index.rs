@@ -0,0 +1,78 @@
+//! Close a user index
+//! This instruction can be used by the owner of a user index to close it and collect the lamports
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::UserIndex;
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `close_user_index` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `close_user_index` instruction
+pub struct Accounts<'a, T> {
+    /// The user index account
+    #[cons(writable)]
+    pub user_index: &'a T,
+
+    /// The owner of the user index
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            user_index: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(accounts.user_index, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_close_user_index(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut index = UserIndex::from_account_info(accounts.user_index)?;
+
+    check_account_key(
+        accounts.owner,
+        &index.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    index.close();
+    index.save(&mut accounts.user_index.data.borrow_mut())?;
+
+    let mut index_lamports = accounts.user_index.lamports.borrow_mut();
+    let mut owner_lamports = accounts.owner.lamports.borrow_mut();
+
+    **owner_lamports += **index_lamports;
+    **index_lamports = 0;
+
+    Ok(())
+}
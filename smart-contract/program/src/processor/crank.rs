@@ -7,14 +7,18 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
+    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
-use spl_math::{precise_number::PreciseNumber};
+use spl_math::precise_number::PreciseNumber;
+use spl_token_2022::instruction::mint_to;
 
 use crate::error::AccessError;
 use crate::state::{CentralState, RewardsTuple, StakePool, Tag};
-use crate::utils::check_account_owner;
+use crate::utils::{
+    check_account_key, check_account_owner, check_token_program, check_token_program_owner,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `crank` instruction
@@ -30,6 +34,17 @@ pub struct Accounts<'a, T> {
     /// The account of the central state
     #[cons(writable)]
     pub central_state: &'a T,
+
+    /// Optional token account receiving the crank bounty
+    #[cons(writable)]
+    pub bounty_destination: Option<&'a T>,
+
+    /// The mint address of the ACCESS token, required with the bounty destination
+    #[cons(writable)]
+    pub mint: Option<&'a T>,
+
+    /// The SPL token program account, required with the bounty destination
+    pub spl_token_program: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -41,8 +56,16 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         let accounts = Accounts {
             stake_pool: next_account_info(accounts_iter)?,
             central_state: next_account_info(accounts_iter)?,
+            bounty_destination: next_account_info(accounts_iter).ok(),
+            mint: next_account_info(accounts_iter).ok(),
+            spl_token_program: next_account_info(accounts_iter).ok(),
         };
 
+        // Check keys
+        if let Some(spl_token_program) = accounts.spl_token_program {
+            check_token_program(spl_token_program)?;
+        }
+
         // Check ownership
         check_account_owner(
             accounts.stake_pool,
@@ -50,6 +73,9 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             AccessError::WrongStakeAccountOwner,
         )?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        if let Some(bounty_destination) = accounts.bounty_destination {
+            check_token_program_owner(bounty_destination, AccessError::WrongTokenAccountOwner)?;
+        }
 
         Ok(accounts)
     }
@@ -62,63 +88,109 @@ pub fn process_crank(
 ) -> ProgramResult {
     let accounts = Accounts::parse(accounts, program_id)?;
 
-    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
     let mut central_state = CentralState::from_account_info(accounts.central_state)?;
 
     let current_offset = central_state.get_current_offset()?;
     // check if we need to do a system wide snapshot
     if central_state.last_snapshot_offset < current_offset {
         central_state.total_staked_snapshot = central_state.total_staked;
+        central_state.total_weighted_staked_snapshot = central_state.total_weighted_staked;
         central_state.last_snapshot_offset = current_offset;
         central_state.save(&mut accounts.central_state.data.borrow_mut())?;
     }
 
-    if stake_pool.header.current_day_idx as u64 == central_state.last_snapshot_offset {
+    let advances_day =
+        stake_pool.header.current_day_idx as u64 != central_state.last_snapshot_offset;
+    if !advances_day {
         #[cfg(not(any(feature = "days-to-sec-10s", feature = "days-to-sec-15m")))]
         return Err(AccessError::NoOp.into());
     }
+
+    // The bounty is taken out of the pool share of the inflation and can never exceed it
+    let mut crank_bounty = 0;
+    let mut daily_inflation = central_state.daily_inflation;
+    if advances_day && accounts.bounty_destination.is_some() && central_state.crank_bounty != 0 {
+        let pool_weighted_staked = stake_pool.header.weighted_staked()?;
+        let pool_inflation = (central_state.daily_inflation as u128)
+            .checked_mul(pool_weighted_staked)
+            .ok_or(AccessError::Overflow)?
+            .checked_div(central_state.total_weighted_staked_snapshot)
+            .unwrap_or(0);
+        crank_bounty = std::cmp::min(central_state.crank_bounty as u128, pool_inflation);
+        if crank_bounty != 0 {
+            // Lower the inflation seen by this pool so that its rewards shrink by the bounty
+            let inflation_reduction = crank_bounty
+                .checked_mul(central_state.total_weighted_staked_snapshot)
+                .ok_or(AccessError::Overflow)?
+                .checked_add(pool_weighted_staked - 1)
+                .ok_or(AccessError::Overflow)?
+                / pool_weighted_staked;
+            daily_inflation = (daily_inflation as u128).saturating_sub(inflation_reduction) as u64;
+        }
+    }
     msg!("Total staked in pool {}", stake_pool.header.total_staked);
-    msg!("Daily inflation {}", central_state.daily_inflation);
+    msg!("Daily inflation {}", daily_inflation);
     msg!("Total staked {}", central_state.total_staked);
     msg!(
         "Total staked snapshot {}",
         central_state.total_staked_snapshot
     );
+    msg!("Pool weight {}", stake_pool.header.pool_weight);
+    msg!(
+        "Total weighted staked snapshot {}",
+        central_state.total_weighted_staked_snapshot
+    );
 
     // get the pool staked amount at the time of last system snapshot
     let total_staked_snapshot = stake_pool.header.total_staked as u128;
 
     let mut stakers_reward = 0;
     if total_staked_snapshot != 0 {
-        // stakers_reward = [(pool_total_staked << 32) * inflation * stakers_part] / (100 * total_staked * pool_total_staked)
-        stakers_reward = ((central_state.daily_inflation as u128) << 32)
+        // stakers_reward = [(pool_total_staked << 32) * inflation * stakers_part * pool_weight] / (100 * total_weighted_staked * pool_total_staked)
+        stakers_reward = ((daily_inflation as u128) << 32)
             .checked_mul(stake_pool.header.stakers_part as u128)
             .ok_or(AccessError::Overflow)?
+            .checked_mul(stake_pool.header.pool_weight as u128)
+            .ok_or(AccessError::Overflow)?
             .checked_div(100u128)
             .ok_or(AccessError::Overflow)?
-            .checked_div(central_state.total_staked_snapshot as u128)
+            .checked_div(central_state.total_weighted_staked_snapshot)
             .unwrap_or(0);
     };
 
     msg!("Stakers reward {}", stakers_reward);
 
-    let precise_total_staked_snapshot = PreciseNumber::new(total_staked_snapshot.checked_shl(32)
-        .ok_or(AccessError::Overflow)?)
-        .ok_or(AccessError::Overflow)?;
-    let precise_daily_inflation = PreciseNumber::new(central_state.daily_inflation as u128)
-        .ok_or(AccessError::Overflow)?;
-    let precise_system_staked_snapshot = PreciseNumber::new(central_state.total_staked_snapshot as u128)
-        .ok_or(AccessError::Overflow)?;
+    let precise_total_staked_snapshot = PreciseNumber::new(
+        total_staked_snapshot
+            .checked_shl(32)
+            .ok_or(AccessError::Overflow)?,
+    )
+    .ok_or(AccessError::Overflow)?;
+    let precise_daily_inflation =
+        PreciseNumber::new(daily_inflation as u128).ok_or(AccessError::Overflow)?;
+    let precise_pool_weight =
+        PreciseNumber::new(stake_pool.header.pool_weight as u128).ok_or(AccessError::Overflow)?;
+    let precise_system_staked_snapshot =
+        PreciseNumber::new(central_state.total_weighted_staked_snapshot)
+            .ok_or(AccessError::Overflow)?;
 
-    // pool_rewards = [(pool_total_staked << 32) * inflation * (100 - stakers_part)] / (100 * total_staked)
+    // pool_rewards = [(pool_total_staked << 32) * inflation * pool_weight * (100 - stakers_part)] / (100 * total_weighted_staked)
     let precise_pool_reward = (precise_total_staked_snapshot)
         .checked_mul(&precise_daily_inflation)
         .ok_or(AccessError::Overflow)?
+        .checked_mul(&precise_pool_weight)
+        .ok_or(AccessError::Overflow)?
         .checked_mul(
-            &PreciseNumber::new(100u64
-                .checked_sub(stake_pool.header.stakers_part)
-                .ok_or(AccessError::Overflow)? as u128,
-            ).ok_or(AccessError::Overflow)?,
+            &PreciseNumber::new(
+                100u64
+                    .checked_sub(stake_pool.header.stakers_part)
+                    .ok_or(AccessError::Overflow)? as u128,
+            )
+            .ok_or(AccessError::Overflow)?,
         )
         .ok_or(AccessError::Overflow)?
         .checked_div(&PreciseNumber::new(100u128).ok_or(AccessError::Overflow)?)
@@ -126,20 +198,31 @@ pub fn process_crank(
         .checked_div(&precise_system_staked_snapshot)
         .unwrap_or(PreciseNumber::new(0).ok_or(AccessError::Overflow)?);
 
-    let pool_reward= precise_pool_reward.to_imprecise().ok_or(AccessError::Overflow)?;
+    let pool_reward = precise_pool_reward
+        .to_imprecise()
+        .ok_or(AccessError::Overflow)?;
 
     msg!("Pool reward {}", pool_reward);
 
     let total_claimable_rewards = (((pool_reward >> 31) + 1) >> 1)
         .checked_add(
-            ((stakers_reward.checked_mul(total_staked_snapshot)
-                .ok_or(AccessError::Overflow)? >> 31) + 1) >> 1
-        ).ok_or(AccessError::Overflow)?;
+            ((stakers_reward
+                .checked_mul(total_staked_snapshot)
+                .ok_or(AccessError::Overflow)?
+                >> 31)
+                + 1)
+                >> 1,
+        )
+        .ok_or(AccessError::Overflow)?;
 
     msg!("Total claimable rewards {}", total_claimable_rewards);
 
-    assert!(total_claimable_rewards <= (central_state.daily_inflation as u128)
-        .checked_add(1_000_000).ok_or(AccessError::Overflow)?);
+    assert!(
+        total_claimable_rewards
+            <= (central_state.daily_inflation as u128)
+                .checked_add(1_000_000)
+                .ok_or(AccessError::Overflow)?
+    );
 
     stake_pool.push_balances_buff(
         current_offset,
@@ -149,5 +232,41 @@ pub fn process_crank(
             stakers_reward,
         },
     )?;
+
+    if crank_bounty != 0 {
+        // Both accounts are required to mint the bounty
+        let (bounty_destination, mint, spl_token_program) = match (
+            accounts.bounty_destination,
+            accounts.mint,
+            accounts.spl_token_program,
+        ) {
+            (Some(d), Some(m), Some(p)) => (d, m, p),
+            _ => return Err(ProgramError::NotEnoughAccountKeys),
+        };
+        check_account_key(mint, &central_state.token_mint, AccessError::WrongMint)?;
+        check_account_owner(mint, spl_token_program.key, AccessError::WrongOwner)?;
+
+        msg!("Crank bounty {}", crank_bounty);
+
+        let mint_ix = mint_to(
+            spl_token_program.key,
+            mint.key,
+            bounty_destination.key,
+            accounts.central_state.key,
+            &[],
+            crank_bounty as u64,
+        )?;
+        invoke_signed(
+            &mint_ix,
+            &[
+                spl_token_program.clone(),
+                mint.clone(),
+                accounts.central_state.clone(),
+                bounty_destination.clone(),
+            ],
+            &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+        )?;
+    }
+
     Ok(())
 }
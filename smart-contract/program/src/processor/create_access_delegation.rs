@@ -0,0 +1,125 @@
+//! Create an access delegation
+//! This instruction allows the owner of a stake account to share its access with a list of member wallets
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::state::{AccessDelegation, StakeAccount};
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use crate::{cpi::Cpi, error::AccessError};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_access_delegation` instruction
+pub struct Params {
+    /// The wallets sharing the access of the stake account
+    pub members: Vec<Pubkey>,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_access_delegation` instruction
+pub struct Accounts<'a, T> {
+    /// The stake account
+    pub stake_account: &'a T,
+
+    /// The access delegation account
+    #[cons(writable)]
+    pub access_delegation: &'a T,
+
+    /// The owner of the stake account
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_account: next_account_info(accounts_iter)?,
+            access_delegation: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_access_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+
+    check_account_key(
+        accounts.owner,
+        &stake_account.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    let (derived_key, nonce) = AccessDelegation::find_key(accounts.stake_account.key, program_id);
+    check_account_key(
+        accounts.access_delegation,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.access_delegation)?;
+
+    let delegation = AccessDelegation::new(
+        stake_account.stake_pool,
+        *accounts.stake_account.key,
+        stake_account.owner,
+        params.members,
+    )?;
+
+    msg!("Delegating access to {} wallets", delegation.members.len());
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.owner,
+        accounts.access_delegation,
+        &[
+            AccessDelegation::SEED,
+            &accounts.stake_account.key.to_bytes(),
+            &[nonce],
+        ],
+        AccessDelegation::LEN,
+    )?;
+
+    delegation.save(&mut accounts.access_delegation.data.borrow_mut())?;
+
+    Ok(())
+}
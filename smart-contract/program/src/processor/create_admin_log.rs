@@ -0,0 +1,116 @@
+//! Create the admin log
+//! Once created, every admin action of the central state authority must append an entry to the log
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::cpi::Cpi;
+use crate::error::AccessError;
+use crate::state::{AdminLog, CentralState};
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_admin_log` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_admin_log` instruction
+pub struct Accounts<'a, T> {
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The account of the central state authority
+    #[cons(signer)]
+    pub authority: &'a T,
+
+    /// The admin log account
+    #[cons(writable)]
+    pub admin_log: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            authority: next_account_info(accounts_iter)?,
+            admin_log: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(
+            accounts.authority,
+            AccessError::CentralStateAuthorityMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_admin_log(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    check_account_key(
+        accounts.authority,
+        &central_state.authority,
+        AccessError::WrongCentralStateAuthority,
+    )?;
+
+    let (derived_key, nonce) = AdminLog::find_key(program_id);
+    check_account_key(
+        accounts.admin_log,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.admin_log)?;
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.admin_log,
+        &[AdminLog::SEED, &[nonce]],
+        AdminLog::LEN,
+    )?;
+
+    AdminLog::new().save(&mut accounts.admin_log.data.borrow_mut())?;
+
+    central_state.admin_log = derived_key;
+    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ use solana_program::{
 };
 
 use crate::error::AccessError;
-use crate::state::{BondAccount, StakePool, BOND_SIGNER_THRESHOLD};
+use crate::state::{BondAccount, StakePool, VestingSchedule, BOND_SIGNER_THRESHOLD};
 use crate::utils::{
     assert_uninitialized, check_account_key, check_account_owner,
     check_signer,
@@ -41,6 +41,10 @@ pub struct Params {
     pub unlock_amount: u64,
     /// Index of the seller in the [`array`][`crate::state::AUTHORIZED_BOND_SELLERS`] of authorized sellers
     pub seller_index: u64,
+    /// Optional cliff and linear vesting schedule, replaces the periodic unlocks
+    pub vesting: Option<VestingSchedule>,
+    /// Date after which the bond can no longer be claimed and can be cancelled, zero if the bond never expires
+    pub expiry_date: i64,
 }
 
 #[derive(InstructionsAccount)]
@@ -118,7 +122,10 @@ pub fn process_create_bond(
     #[cfg(not(feature = "no-bond-signer"))]
     assert_authorized_seller(accounts.seller, params.seller_index as usize)?;
 
-    if params.unlock_period == 0 {
+    let vesting = params.vesting.unwrap_or_default();
+    if vesting.is_linear() {
+        vesting.check(params.total_amount_sold)?;
+    } else if params.unlock_period == 0 {
         return Err(AccessError::ForbiddenUnlockPeriodZero.into());
     }
 
@@ -135,6 +142,9 @@ pub fn process_create_bond(
         stake_pool.header.minimum_stake_amount,
         *accounts.stake_pool.key,
         *accounts.seller.key,
+        vesting,
+        params.expiry_date,
+        *accounts.fee_payer.key,
     );
 
     // Create bond account
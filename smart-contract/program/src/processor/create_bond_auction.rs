@@ -0,0 +1,180 @@
+//! Create a bond auction
+//! This instruction can be used by authorized sellers to sell bonds through a Dutch auction
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondAuction, StakePool, VestingSchedule};
+#[cfg(not(feature = "no-bond-signer"))]
+use crate::utils::assert_authorized_seller;
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use crate::{cpi::Cpi, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_bond_auction` instruction
+pub struct Params {
+    /// Total amount of ACCESS tokens being sold
+    pub total_amount: u64,
+    /// Mint of the token used to buy the bonds
+    pub quote_mint: Pubkey,
+    /// The token account i.e where the sell proceeds go
+    pub seller_token_account: Pubkey,
+    /// Price of `total_amount` tokens when the auction opens
+    pub start_price: u64,
+    /// Price of `total_amount` tokens once the price stops decaying
+    pub floor_price: u64,
+    /// The date at which the auction opens
+    pub start_time: i64,
+    /// The date at which the price reaches `floor_price`
+    pub end_time: i64,
+    /// The start date of the unlock of the bonds
+    pub unlock_start_date: i64,
+    /// The time interval at which the bond tokens unlock
+    pub unlock_period: i64,
+    /// The number of unlock periods over which the bond tokens unlock
+    pub unlock_periods: u64,
+    /// Optional cliff and linear vesting template, the cliff amount is given for `total_amount`
+    pub vesting: Option<VestingSchedule>,
+    /// Index of the seller in the [`array`][`crate::state::AUTHORIZED_BOND_SELLERS`] of authorized sellers
+    pub seller_index: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_bond_auction` instruction
+pub struct Accounts<'a, T> {
+    /// The bond seller account
+    #[cons(signer)]
+    pub seller: &'a T,
+
+    /// The bond auction account
+    #[cons(writable)]
+    pub bond_auction: &'a T,
+
+    /// The stake pool
+    pub stake_pool: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            seller: next_account_info(accounts_iter)?,
+            bond_auction: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BondSellerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_bond_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+
+    let (derived_key, nonce) = BondAuction::find_key(
+        accounts.seller.key,
+        accounts.stake_pool.key,
+        params.start_time,
+        program_id,
+    );
+    check_account_key(
+        accounts.bond_auction,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.bond_auction)?;
+
+    #[cfg(not(feature = "no-bond-signer"))]
+    assert_authorized_seller(accounts.seller, params.seller_index as usize)?;
+
+    if params.total_amount == 0
+        || params.floor_price > params.start_price
+        || params.end_time <= params.start_time
+    {
+        msg!("Invalid auction parameters");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vesting = params.vesting.unwrap_or_default();
+    if vesting.is_linear() {
+        vesting.check(params.total_amount)?;
+    } else if params.unlock_period == 0 || params.unlock_periods == 0 {
+        return Err(AccessError::ForbiddenUnlockPeriodZero.into());
+    }
+
+    let auction = BondAuction {
+        tag: Tag::BondAuction,
+        seller: *accounts.seller.key,
+        stake_pool: *accounts.stake_pool.key,
+        quote_mint: params.quote_mint,
+        seller_token_account: params.seller_token_account,
+        total_amount: params.total_amount,
+        remaining_amount: params.total_amount,
+        start_price: params.start_price,
+        floor_price: params.floor_price,
+        start_time: params.start_time,
+        end_time: params.end_time,
+        unlock_start_date: params.unlock_start_date,
+        unlock_period: params.unlock_period,
+        unlock_periods: params.unlock_periods,
+        vesting,
+        fill_count: 0,
+    };
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.bond_auction,
+        &[
+            BondAuction::SEED,
+            &accounts.seller.key.to_bytes(),
+            &accounts.stake_pool.key.to_bytes(),
+            &params.start_time.to_le_bytes(),
+            &[nonce],
+        ],
+        auction.borsh_len(),
+    )?;
+
+    auction.save(&mut accounts.bond_auction.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+//! Create a bond round
+//! This instruction can be used by authorized sellers to sell many bonds at once, the bonds are committed to with a Merkle root
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondRound, StakePool, BOND_SIGNER_THRESHOLD};
+#[cfg(not(feature = "no-bond-signer"))]
+use crate::utils::assert_authorized_seller;
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use crate::{cpi::Cpi, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_bond_round` instruction
+pub struct Params {
+    /// Root of the Merkle tree of the bonds of the round
+    pub merkle_root: [u8; 32],
+    /// Mint of the token used to buy the bonds
+    pub quote_mint: Pubkey,
+    /// The token account i.e where the sell proceeds go
+    pub seller_token_account: Pubkey,
+    /// The start date of the unlock of the bonds
+    pub unlock_start_date: i64,
+    /// The time interval at which the bond tokens unlock
+    pub unlock_period: i64,
+    /// The number of unlock periods over which the bond tokens unlock
+    pub unlock_periods: u64,
+    /// Index of the seller in the [`array`][`crate::state::AUTHORIZED_BOND_SELLERS`] of authorized sellers
+    pub seller_index: u64,
+    /// Number of leaves of the Merkle tree
+    pub num_nodes: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_bond_round` instruction
+pub struct Accounts<'a, T> {
+    /// The bond seller account
+    #[cons(signer)]
+    pub seller: &'a T,
+
+    /// The bond round account
+    #[cons(writable)]
+    pub bond_round: &'a T,
+
+    /// The stake pool
+    pub stake_pool: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            seller: next_account_info(accounts_iter)?,
+            bond_round: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BondSellerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_bond_round(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+
+    let (derived_key, nonce) = BondRound::find_key(&params.merkle_root, program_id);
+    check_account_key(
+        accounts.bond_round,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.bond_round)?;
+
+    #[cfg(not(feature = "no-bond-signer"))]
+    assert_authorized_seller(accounts.seller, params.seller_index as usize)?;
+
+    if params.unlock_period == 0 || params.unlock_periods == 0 {
+        return Err(AccessError::ForbiddenUnlockPeriodZero.into());
+    }
+
+    if params.num_nodes == 0 {
+        msg!("The round must have at least one bond");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let round = BondRound {
+        tag: Tag::BondRound,
+        merkle_root: params.merkle_root,
+        stake_pool: *accounts.stake_pool.key,
+        quote_mint: params.quote_mint,
+        seller_token_account: params.seller_token_account,
+        unlock_start_date: params.unlock_start_date,
+        unlock_period: params.unlock_period,
+        unlock_periods: params.unlock_periods,
+        sellers: vec![*accounts.seller.key],
+        num_nodes: params.num_nodes,
+        claimed_bitmap: vec![0; ((params.num_nodes + 7) / 8) as usize],
+    };
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.bond_round,
+        &[BondRound::SEED, &params.merkle_root, &[nonce]],
+        round.borsh_len() + ((BOND_SIGNER_THRESHOLD - 1) * 32) as usize,
+    )?;
+
+    round.save(&mut accounts.bond_round.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Create a Merkle distributor
+//! This instruction can be used by the central state authority to distribute ACCESS tokens to many recipients, the recipients are committed to with a Merkle root
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::cpi::Cpi;
+use crate::error::AccessError;
+use crate::state::{CentralState, Distributor};
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_distributor` instruction
+pub struct Params {
+    /// Root of the Merkle tree of the distribution
+    pub merkle_root: [u8; 32],
+    /// Maximum amount of tokens that can be claimed from the distributor
+    pub max_total_claim: u64,
+    /// Number of leaves of the Merkle tree
+    pub num_nodes: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_distributor` instruction
+pub struct Accounts<'a, T> {
+    /// The account of the central state
+    pub central_state: &'a T,
+
+    /// The account of the central state authority
+    #[cons(signer)]
+    pub authority: &'a T,
+
+    /// The distributor account
+    #[cons(writable)]
+    pub distributor: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            authority: next_account_info(accounts_iter)?,
+            distributor: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(
+            accounts.authority,
+            AccessError::CentralStateAuthorityMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_distributor(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    check_account_key(
+        accounts.authority,
+        &central_state.authority,
+        AccessError::WrongCentralStateAuthority,
+    )?;
+
+    let (derived_key, nonce) = Distributor::find_key(&params.merkle_root, program_id);
+    check_account_key(
+        accounts.distributor,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.distributor)?;
+
+    if params.num_nodes == 0 {
+        msg!("The distribution must have at least one recipient");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let distributor =
+        Distributor::new(params.merkle_root, params.max_total_claim, params.num_nodes);
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.distributor,
+        &[Distributor::SEED, &params.merkle_root, &[nonce]],
+        distributor.borsh_len(),
+    )?;
+
+    distributor.save(&mut accounts.distributor.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+//! Create a reward campaign
+//! This instruction allows a stake pool owner to reward its stakers with a secondary token
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use crate::state::{RewardCampaign, StakePool};
+use crate::utils::{
+    assert_uninitialized, assert_valid_campaign_vault, check_account_key, check_account_owner,
+    check_signer, check_token_program_owner,
+};
+use crate::{cpi::Cpi, error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_reward_campaign` instruction
+pub struct Params {
+    /// Amount of reward tokens distributed to the pool stakers every day
+    pub daily_rate: u64,
+    /// Timestamp after which no more rewards are distributed
+    pub end_time: i64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_reward_campaign` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The reward campaign account
+    #[cons(writable)]
+    pub reward_campaign: &'a T,
+
+    /// The campaign vault account
+    pub vault: &'a T,
+
+    /// The mint of the reward token
+    pub mint: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee payer account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            reward_campaign: next_account_info(accounts_iter)?,
+            vault: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_token_program_owner(accounts.mint, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.vault,
+            accounts.mint.owner,
+            AccessError::WrongTokenAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_reward_campaign(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let Params {
+        daily_rate,
+        end_time,
+    } = params;
+
+    let stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+
+    check_account_key(
+        accounts.owner,
+        &Pubkey::new(&stake_pool.header.owner),
+        AccessError::WrongStakePoolOwner,
+    )?;
+
+    let (derived_key, nonce) =
+        RewardCampaign::find_key(accounts.stake_pool.key, accounts.mint.key, program_id);
+    check_account_key(
+        accounts.reward_campaign,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.reward_campaign)?;
+    assert_valid_campaign_vault(accounts.vault, &derived_key, accounts.mint.key)?;
+
+    if end_time <= Clock::get()?.unix_timestamp {
+        msg!("The campaign must end in the future");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let campaign = RewardCampaign::new(
+        nonce,
+        *accounts.stake_pool.key,
+        *accounts.owner.key,
+        *accounts.mint.key,
+        *accounts.vault.key,
+        daily_rate,
+        end_time,
+    )?;
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.reward_campaign,
+        &[
+            RewardCampaign::SEED,
+            &accounts.stake_pool.key.to_bytes(),
+            &accounts.mint.key.to_bytes(),
+            &[nonce],
+        ],
+        campaign.borsh_len(),
+    )?;
+
+    campaign.save(&mut accounts.reward_campaign.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -13,7 +13,7 @@ use crate::{cpi::Cpi, error::AccessError};
 
 use bonfida_utils::{BorshSize, InstructionsAccount};
 
-use crate::utils::{check_account_key, check_account_owner};
+use crate::utils::{assert_pool_member, check_account_key, check_account_owner, update_user_index};
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `create_stake_account` instruction
 pub struct Params {
@@ -39,6 +39,13 @@ pub struct Accounts<'a, T> {
     /// The fee payer account
     #[cons(writable, signer)]
     pub fee_payer: &'a T,
+
+    /// Optional pool membership of the owner, required by allowlisted pools
+    pub pool_membership: Option<&'a T>,
+
+    /// Optional user index of the owner, any account can be passed as pool membership before it when the pool has no allowlist
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -52,6 +59,8 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             system_program: next_account_info(accounts_iter)?,
             stake_pool: next_account_info(accounts_iter)?,
             fee_payer: next_account_info(accounts_iter)?,
+            pool_membership: next_account_info(accounts_iter).ok(),
+            user_index: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
@@ -82,6 +91,14 @@ pub fn process_create_stake_account(
 
     let stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
 
+    assert_pool_member(
+        &stake_pool,
+        accounts.stake_pool.key,
+        &params.owner,
+        accounts.pool_membership,
+        program_id,
+    )?;
+
     let derived_stake_key = StakeAccount::create_key(
         &params.nonce,
         &params.owner,
@@ -117,5 +134,9 @@ pub fn process_create_stake_account(
 
     stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
 
+    update_user_index(accounts.user_index, &params.owner, program_id, |index| {
+        index.add_stake_account(*accounts.stake_account.key)
+    })?;
+
     Ok(())
 }
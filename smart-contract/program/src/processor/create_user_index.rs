@@ -0,0 +1,122 @@
+//! Create a user index
+//! This instruction creates the account listing the stake accounts and bonds of a wallet, the existing positions of the wallet can be indexed at once
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::state::{BondAccount, StakeAccount, Tag, UserIndex};
+use crate::utils::{assert_uninitialized, check_account_key, check_account_owner, check_signer};
+use crate::{cpi::Cpi, error::AccessError};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `create_user_index` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `create_user_index` instruction
+pub struct Accounts<'a, T> {
+    /// The user index account
+    #[cons(writable)]
+    pub user_index: &'a T,
+
+    /// The owner of the positions
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// Existing stake accounts and bonds of the owner
+    pub positions: &'a [T],
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            user_index: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            positions: accounts_iter.as_slice(),
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        for a in accounts.positions {
+            check_account_owner(a, program_id, AccessError::WrongOwner)?;
+        }
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_create_user_index(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let (derived_key, nonce) = UserIndex::find_key(accounts.owner.key, program_id);
+    check_account_key(
+        accounts.user_index,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.user_index)?;
+
+    let mut index = UserIndex::new(*accounts.owner.key);
+    for position in accounts.positions {
+        let tag = position.data.borrow()[0];
+        if tag == Tag::StakeAccount as u8 {
+            let stake_account = StakeAccount::from_account_info(position)?;
+            check_account_key(
+                accounts.owner,
+                &stake_account.owner,
+                AccessError::StakeAccountOwnerMismatch,
+            )?;
+            index.add_stake_account(*position.key)?;
+        } else if tag == Tag::BondAccount as u8 {
+            let bond = BondAccount::from_account_info(position, false)?;
+            check_account_key(
+                accounts.owner,
+                &bond.owner,
+                AccessError::StakeAccountOwnerMismatch,
+            )?;
+            index.add_bond_account(*position.key)?;
+        } else {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+    }
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.owner,
+        accounts.user_index,
+        &[UserIndex::SEED, &accounts.owner.key.to_bytes(), &[nonce]],
+        UserIndex::LEN,
+    )?;
+
+    index.save(&mut accounts.user_index.data.borrow_mut())?;
+
+    Ok(())
+}
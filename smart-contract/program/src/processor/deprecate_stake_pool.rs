@@ -0,0 +1,150 @@
+//! Deprecate a stake pool
+//! This instruction allows the central state authority to wind down a pool: it stops receiving new stakes and inflation,
+//! while stakers can still unstake, claim the rewards of past days and unlock their bonds until it can be closed
+//! Frozen pools can be deprecated as well, the deprecation lifts the freeze and closes its record
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::state::{CentralState, FreezeRecord, StakePool};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `deprecate_stake_pool` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `deprecate_stake_pool` instruction
+pub struct Accounts<'a, T> {
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The account of the central state authority
+    #[cons(signer)]
+    pub authority: &'a T,
+
+    /// The stake pool account
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The freeze record of the stake pool
+    #[cons(writable)]
+    pub freeze_record: &'a T,
+
+    /// The account that paid for the freeze record, required when the pool has one
+    #[cons(writable)]
+    pub fee_payer: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            authority: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            freeze_record: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(
+            accounts.authority,
+            AccessError::CentralStateAuthorityMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_deprecate_stake_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::InactiveStakePool, Tag::FrozenStakePool],
+    )?;
+    // A pool whose freeze expired can be cranked again
+    let is_frozen = stake_pool.header.tag == Tag::FrozenStakePool as u8
+        && !stake_pool
+            .header
+            .is_freeze_expired(Clock::get()?.unix_timestamp);
+
+    check_account_key(
+        accounts.authority,
+        &central_state.authority,
+        AccessError::WrongCentralStateAuthority,
+    )?;
+
+    // The rewards of the current day are computed with the weight the pool had until now,
+    // a frozen pool cannot be cranked and the days it missed are computed with a zero weight
+    if !is_frozen
+        && stake_pool.header.total_staked != 0
+        && (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()?
+    {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    // The freeze is lifted with its record, the record of an expired freeze is closed as well
+    let (derived_key, _nonce) = FreezeRecord::find_key(accounts.stake_pool.key, program_id);
+    check_account_key(
+        accounts.freeze_record,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    if !accounts.freeze_record.data_is_empty() {
+        check_account_owner(accounts.freeze_record, program_id, AccessError::WrongOwner)?;
+        let mut record = FreezeRecord::from_account_info(accounts.freeze_record)?;
+        let fee_payer = accounts.fee_payer.ok_or_else(|| {
+            msg!("The fee payer of the freeze record must be passed");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        check_account_key(fee_payer, &record.fee_payer, AccessError::WrongFreezeRecord)?;
+        record.close();
+        record.save(&mut accounts.freeze_record.data.borrow_mut())?;
+        let mut record_lamports = accounts.freeze_record.lamports.borrow_mut();
+        let mut fee_payer_lamports = fee_payer.lamports.borrow_mut();
+        **fee_payer_lamports += **record_lamports;
+        **record_lamports = 0;
+    }
+
+    // A zero weight pool gets no share of the inflation on the following cranks
+    central_state.total_weighted_staked = central_state
+        .total_weighted_staked
+        .checked_sub(stake_pool.header.weighted_staked()?)
+        .ok_or(AccessError::Overflow)?;
+    stake_pool.header.pool_weight = 0;
+    stake_pool.header.tag = Tag::DeprecatedStakePool as u8;
+    stake_pool.header.freeze_expiry = 0;
+
+    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+
+    Ok(())
+}
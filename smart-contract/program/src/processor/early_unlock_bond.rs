@@ -0,0 +1,237 @@
+//! Unlock ACCESS tokens of a bond before they vest
+//! A penalty set in the central state is taken on the unvested part of the unlocked tokens and sent to the DAO treasury
+//! Early unlocks are disabled as long as the authority has not set a penalty
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::state::{BondAccount, CentralState, StakePool, StakePoolHeader};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::utils::{
+    assert_valid_fee, check_account_key, check_account_owner, check_signer, check_token_program,
+    transfer_checked, unpack_token_account,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `early_unlock_bond` instruction
+pub struct Params {
+    /// The amount of locked tokens to unlock, including the penalty taken on the unvested tokens
+    pub amount: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `early_unlock_bond` instruction
+pub struct Accounts<'a, T> {
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The account of the bond owner
+    #[cons(signer)]
+    pub bond_owner: &'a T,
+
+    /// The ACCESS mint token
+    pub mint: &'a T,
+
+    /// The ACCESS token destination
+    #[cons(writable)]
+    pub access_token_destination: &'a T,
+
+    /// The DAO treasury receiving the penalty
+    #[cons(writable)]
+    pub fee_account: &'a T,
+
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The account of the staking pool
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The vault of the staking pool
+    #[cons(writable)]
+    pub pool_vault: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_account: next_account_info(accounts_iter)?,
+            bond_owner: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            access_token_destination: next_account_info(accounts_iter)?,
+            fee_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            pool_vault: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.bond_account, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.bond_owner, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_early_unlock_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    if central_state.early_unlock_penalty == 0 {
+        return Err(AccessError::EarlyUnlockDisabled.into());
+    }
+    let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
+
+    let destination_token_acc = unpack_token_account(accounts.access_token_destination)?;
+    if destination_token_acc.mint != central_state.token_mint {
+        return Err(AccessError::WrongMint.into());
+    }
+
+    check_account_key(
+        accounts.bond_owner,
+        &bond.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &bond.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+    check_account_key(
+        accounts.mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+    check_account_key(
+        accounts.pool_vault,
+        &Pubkey::new(&stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+    assert_valid_fee(accounts.fee_account, &central_state.authority)?;
+
+    let locked_amount = bond
+        .total_amount_sold
+        .checked_sub(bond.total_unlocked_amount)
+        .ok_or(AccessError::Overflow)?;
+    if params.amount == 0 || params.amount > locked_amount {
+        msg!("Can only unlock up to {} tokens", locked_amount);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()? {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    if bond.last_claimed_offset < central_state.get_current_offset()? {
+        return Err(AccessError::UnclaimedRewards.into());
+    }
+
+    // Tokens that are already vested can be unlocked without a penalty
+    let current_time = Clock::get()?.unix_timestamp;
+    let vested_amount = bond.calc_unlockable_amount(current_time)?;
+    let unvested_amount = params.amount.saturating_sub(vested_amount);
+
+    // penalty = unvested_amount * early_unlock_penalty / 100
+    let penalty = (unvested_amount as u128)
+        .checked_mul(central_state.early_unlock_penalty as u128)
+        .and_then(|p| p.checked_div(100))
+        .ok_or(AccessError::Overflow)? as u64;
+    let unlock_amount = params.amount - penalty;
+
+    msg!("Unlocking {} tokens, penalty {}", unlock_amount, penalty);
+
+    // Update the stake pool
+    if params.amount >= bond.total_staked {
+        stake_pool.header.remove_bond_holder();
+    }
+    stake_pool.header.withdraw(params.amount)?;
+    let pool_weight = stake_pool.header.pool_weight;
+
+    let signer_seeds: &[&[u8]] = &[
+        StakePoolHeader::SEED,
+        &stake_pool.header.owner.clone(),
+        &[stake_pool.header.nonce],
+    ];
+
+    drop(stake_pool);
+
+    // Transfer tokens
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.pool_vault,
+        accounts.mint,
+        accounts.access_token_destination,
+        accounts.stake_pool,
+        unlock_amount,
+        &[signer_seeds],
+    )?;
+
+    if penalty > 0 {
+        transfer_checked(
+            accounts.spl_token_program,
+            accounts.pool_vault,
+            accounts.mint,
+            accounts.fee_account,
+            accounts.stake_pool,
+            penalty,
+            &[signer_seeds],
+        )?;
+    }
+
+    // Update bond state
+    bond.total_unlocked_amount = bond
+        .total_unlocked_amount
+        .checked_add(params.amount)
+        .ok_or(AccessError::Overflow)?;
+
+    bond.total_staked = bond
+        .total_staked
+        .checked_sub(params.amount)
+        .ok_or(AccessError::Overflow)?;
+
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    // Update central state
+    central_state.withdraw(params.amount, pool_weight)?;
+    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Edit an access delegation
+//! This instruction allows the owner of a stake account to replace the members of its access delegation
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::AccessDelegation;
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `edit_access_delegation` instruction
+pub struct Params {
+    /// The new wallets sharing the access of the stake account
+    pub members: Vec<Pubkey>,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `edit_access_delegation` instruction
+pub struct Accounts<'a, T> {
+    /// The access delegation account
+    #[cons(writable)]
+    pub access_delegation: &'a T,
+
+    /// The owner of the stake account
+    #[cons(signer)]
+    pub owner: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            access_delegation: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.access_delegation,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_edit_access_delegation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut delegation = AccessDelegation::from_account_info(accounts.access_delegation)?;
+
+    check_account_key(
+        accounts.owner,
+        &delegation.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    delegation.set_members(params.members)?;
+
+    msg!("Delegating access to {} wallets", delegation.members.len());
+
+    delegation.save(&mut accounts.access_delegation.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 //! Edit metadata
-use crate::{error::AccessError, state::CentralState};
+use crate::{
+    error::AccessError,
+    state::{AdminAction, CentralState},
+};
 use bonfida_utils::{BorshSize, InstructionsAccount};
 use borsh::{BorshDeserialize, BorshSerialize};
 use mpl_token_metadata::{
@@ -13,7 +16,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::utils::{check_account_key, check_account_owner, check_signer, log_admin_action};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `edit_metadata` instruction
@@ -42,6 +45,10 @@ pub struct Accounts<'a, T> {
 
     /// The metadata program account
     pub metadata_program: &'a T,
+
+    /// The admin log, required once it has been created
+    #[cons(writable)]
+    pub admin_log: Option<&'a T>,
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
@@ -55,6 +62,7 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             authority: next_account_info(accounts_iter)?,
             metadata: next_account_info(accounts_iter)?,
             metadata_program: next_account_info(accounts_iter)?,
+            admin_log: next_account_info(accounts_iter).ok(),
         };
 
         // Check keys
@@ -103,6 +111,15 @@ pub fn process_edit_metadata(
         AccessError::AccountNotDeterministic,
     )?;
 
+    log_admin_action(
+        program_id,
+        accounts.admin_log,
+        &central_state,
+        AdminAction::EditMetadata,
+        accounts.metadata.key,
+        &params.try_to_vec()?,
+    )?;
+
     let data = DataV2 {
         name: params.name,
         uri: params.uri,
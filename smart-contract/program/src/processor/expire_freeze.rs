@@ -0,0 +1,118 @@
+//! Unfreeze an account whose freeze has expired
+//! This instruction is permissionless, the lamports of the freeze record go back to whoever paid for it
+use crate::error::AccessError;
+use crate::state::{FreezeRecord, StakePoolHeader, Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::FromPrimitive;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::utils::{check_account_key, check_account_owner};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `expire_freeze` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `expire_freeze` instruction
+pub struct Accounts<'a, T> {
+    /// The frozen account
+    #[cons(writable)]
+    pub frozen_account: &'a T,
+
+    /// The freeze record of the account
+    #[cons(writable)]
+    pub freeze_record: &'a T,
+
+    /// The account that paid for the freeze record
+    #[cons(writable)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            frozen_account: next_account_info(accounts_iter)?,
+            freeze_record: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(accounts.frozen_account, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.freeze_record, program_id, AccessError::WrongOwner)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_expire_freeze(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut record = FreezeRecord::from_account_info(accounts.freeze_record)?;
+
+    check_account_key(
+        accounts.frozen_account,
+        &record.account,
+        AccessError::WrongFreezeRecord,
+    )?;
+    check_account_key(
+        accounts.fee_payer,
+        &record.fee_payer,
+        AccessError::WrongFreezeRecord,
+    )?;
+
+    if !record.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("The freeze has not expired");
+        return Err(AccessError::FreezeNotExpired.into());
+    }
+
+    let current_tag = Tag::from_u8(accounts.frozen_account.data.borrow()[0])
+        .ok_or(ProgramError::InvalidAccountData)?;
+    if !current_tag.is_frozen() {
+        return Err(AccessError::InvalidTagChange.into());
+    }
+
+    // Records are closed on every unfreeze, a pool also keeps the expiry of its current freeze
+    if current_tag == Tag::FrozenStakePool {
+        if let Some(freeze_expiry) = StakePoolHeader::get_freeze_expiry(accounts.frozen_account) {
+            if freeze_expiry != record.expiry {
+                msg!("The freeze record does not belong to the current freeze");
+                return Err(AccessError::WrongFreezeRecord.into());
+            }
+        }
+    }
+
+    let mut data = accounts.frozen_account.data.borrow_mut();
+    data[0] = Tag::opposite(&current_tag)? as u8;
+    drop(data);
+    if current_tag == Tag::FrozenStakePool {
+        StakePoolHeader::set_freeze_expiry(accounts.frozen_account, 0);
+    }
+
+    record.close();
+    record.save(&mut accounts.freeze_record.data.borrow_mut())?;
+
+    let mut record_lamports = accounts.freeze_record.lamports.borrow_mut();
+    let mut fee_payer_lamports = accounts.fee_payer.lamports.borrow_mut();
+
+    **fee_payer_lamports += **record_lamports;
+    **record_lamports = 0;
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Extend a reward campaign
+//! This instruction allows the stake pool owner to push back the end date of a campaign and change its daily rate
+//! The vault must already hold the outstanding rewards and the rewards of the remaining duration at the new rate
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::state::{RewardCampaign, StakePool, SECONDS_IN_DAY};
+use crate::utils::{check_account_key, check_account_owner, check_signer, unpack_token_account};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `extend_reward_campaign` instruction
+pub struct Params {
+    /// The new end date of the campaign
+    pub new_end_time: i64,
+    /// The new amount of reward tokens distributed every day
+    pub new_daily_rate: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `extend_reward_campaign` instruction
+pub struct Accounts<'a, T> {
+    /// The reward campaign account
+    #[cons(writable)]
+    pub reward_campaign: &'a T,
+
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The campaign vault account
+    pub vault: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            reward_campaign: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            vault: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.reward_campaign,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_extend_reward_campaign(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let Params {
+        new_end_time,
+        new_daily_rate,
+    } = params;
+
+    let mut campaign = RewardCampaign::from_account_info(accounts.reward_campaign)?;
+    StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    check_account_key(
+        accounts.owner,
+        &campaign.owner,
+        AccessError::WrongStakePoolOwner,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &campaign.stake_pool,
+        AccessError::StakePoolMismatch,
+    )?;
+    check_account_key(
+        accounts.vault,
+        &campaign.vault,
+        AccessError::CampaignVaultMismatch,
+    )?;
+
+    if new_end_time < campaign.end_time || new_end_time <= current_time {
+        msg!("The campaign can only be extended into the future");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Settle the rewards emitted at the previous rate
+    campaign.update_accumulator(current_time)?;
+    // The time between a past end date and the extension does not earn rewards
+    campaign.last_update_time = std::cmp::max(campaign.last_update_time, current_time);
+
+    campaign.end_time = new_end_time;
+    campaign.daily_rate = new_daily_rate;
+
+    // remaining_rewards = new_daily_rate * (new_end_time - last_update_time) / SECONDS_IN_DAY
+    let remaining_rewards = ((new_end_time - campaign.last_update_time) as u128)
+        .checked_mul(new_daily_rate as u128)
+        .ok_or(AccessError::Overflow)?
+        / SECONDS_IN_DAY as u128;
+    let required_amount = remaining_rewards
+        .checked_add(campaign.outstanding_rewards()? as u128)
+        .ok_or(AccessError::Overflow)?;
+    let vault = unpack_token_account(accounts.vault)?;
+    if (vault.amount as u128) < required_amount {
+        msg!("The vault must hold {} reward tokens", required_amount);
+        return Err(AccessError::CampaignNotFunded.into());
+    }
+
+    campaign.save(&mut accounts.reward_campaign.data.borrow_mut())?;
+
+    Ok(())
+}
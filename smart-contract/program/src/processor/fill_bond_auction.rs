@@ -0,0 +1,291 @@
+//! Fill a bond auction
+//! This instruction allows any buyer to buy part of a bond auction at the current price, the bond is created and activated at once
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+    sysvar::Sysvar,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondAccount, BondAuction, CentralState, StakePool};
+use crate::utils::{
+    activate_bond, amount_with_transfer_fee, assert_uninitialized, check_account_key,
+    check_account_owner, check_signer, check_token_program, transfer_checked, update_user_index,
+};
+use crate::{cpi::Cpi, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `fill_bond_auction` instruction
+pub struct Params {
+    /// The amount of ACCESS tokens to buy
+    pub amount: u64,
+    /// The maximum amount of quote tokens the buyer is willing to pay
+    pub max_quote_amount: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `fill_bond_auction` instruction
+pub struct Accounts<'a, T> {
+    /// The bond auction account
+    #[cons(writable)]
+    pub bond_auction: &'a T,
+
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The account of the bond buyer
+    #[cons(writable, signer)]
+    pub buyer: &'a T,
+
+    /// The token account used to purchase the bond
+    #[cons(writable)]
+    pub quote_token_source: &'a T,
+
+    /// The token account where the sell proceed is sent
+    #[cons(writable)]
+    pub quote_token_destination: &'a T,
+
+    /// The stake pool account
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The mint of the ACCESS token
+    #[cons(writable)]
+    pub access_mint: &'a T,
+
+    /// The vault of the stake pool
+    #[cons(writable)]
+    pub pool_vault: &'a T,
+
+    /// The central state account
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The token program of the ACCESS mint
+    pub spl_token_program: &'a T,
+
+    /// The mint of the quote token
+    pub quote_mint: &'a T,
+
+    /// The token program of the quote mint
+    pub quote_token_program: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// Optional user index of the buyer
+    #[cons(writable)]
+    pub user_index: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_auction: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter)?,
+            buyer: next_account_info(accounts_iter)?,
+            quote_token_source: next_account_info(accounts_iter)?,
+            quote_token_destination: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            access_mint: next_account_info(accounts_iter)?,
+            pool_vault: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            quote_mint: next_account_info(accounts_iter)?,
+            quote_token_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            user_index: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.access_mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.bond_auction, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.buyer, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_fill_bond_auction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut auction = BondAuction::from_account_info(accounts.bond_auction)?;
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    check_account_key(
+        accounts.stake_pool,
+        &auction.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+    check_account_key(
+        accounts.access_mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+    check_account_key(
+        accounts.quote_token_destination,
+        &auction.seller_token_account,
+        AccessError::WrongQuoteDestination,
+    )?;
+    check_account_key(
+        accounts.pool_vault,
+        &Pubkey::new(&stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+
+    if current_time < auction.start_time {
+        msg!("The auction has not started yet");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if params.amount == 0 || params.amount > auction.remaining_amount {
+        msg!("Only {} tokens are left", auction.remaining_amount);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()? {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    let quote_amount = auction.quote_amount(params.amount, current_time)?;
+    msg!("Buying {} tokens for {}", params.amount, quote_amount);
+    if quote_amount > params.max_quote_amount {
+        msg!("The price is above the maximum quote amount");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create the bond account, every fill gets its own account
+    let (derived_key, nonce) = BondAccount::create_auction_fill_key(
+        accounts.bond_auction.key,
+        accounts.buyer.key,
+        auction.fill_count,
+        program_id,
+    );
+    check_account_key(
+        accounts.bond_account,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.bond_account)?;
+
+    let unlock_amount = params
+        .amount
+        .checked_add(auction.unlock_periods.saturating_sub(1))
+        .and_then(|a| a.checked_div(auction.unlock_periods))
+        .unwrap_or(params.amount);
+
+    let mut bond = BondAccount::new(
+        *accounts.buyer.key,
+        params.amount,
+        quote_amount,
+        auction.quote_mint,
+        auction.seller_token_account,
+        auction.unlock_start_date,
+        auction.unlock_period,
+        unlock_amount,
+        auction.unlock_start_date,
+        stake_pool.header.minimum_stake_amount,
+        *accounts.stake_pool.key,
+        auction.seller,
+        auction.vesting_for(params.amount)?,
+        0,
+        *accounts.buyer.key,
+    );
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.buyer,
+        accounts.bond_account,
+        &[
+            BondAccount::SEED,
+            &accounts.bond_auction.key.to_bytes(),
+            &accounts.buyer.key.to_bytes(),
+            &auction.fill_count.to_le_bytes(),
+            &[nonce],
+        ],
+        // The bond is activated at once, it does not need room for more signers
+        bond.borsh_len(),
+    )?;
+
+    // Pay the seller
+    if quote_amount > 0 {
+        // The quote accounts are only used by paid bonds
+        check_token_program(accounts.quote_token_program)?;
+        check_account_key(
+            accounts.quote_mint,
+            &auction.quote_mint,
+            AccessError::WrongQuoteMint,
+        )?;
+        check_account_owner(
+            accounts.quote_mint,
+            accounts.quote_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        transfer_checked(
+            accounts.quote_token_program,
+            accounts.quote_token_source,
+            accounts.quote_mint,
+            accounts.quote_token_destination,
+            accounts.buyer,
+            amount_with_transfer_fee(accounts.quote_mint, quote_amount)?,
+            &[],
+        )?;
+    }
+
+    auction.remaining_amount -= params.amount;
+    auction.fill_count += 1;
+    auction.save(&mut accounts.bond_auction.data.borrow_mut())?;
+
+    // The auction was approved by an authorized seller, the bond is activated right away
+    activate_bond(
+        program_id,
+        &mut bond,
+        accounts.bond_account,
+        &mut stake_pool,
+        &mut central_state,
+        accounts.central_state,
+        accounts.access_mint,
+        accounts.pool_vault,
+        accounts.spl_token_program,
+    )?;
+
+    update_user_index(accounts.user_index, &bond.owner, program_id, |index| {
+        index.add_bond_account(*accounts.bond_account.key)
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! Fill a bond listing
+//! This instruction allows any wallet to buy a listed bond, the pending rewards of the bond are settled to the seller before the transfer
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondAccount, BondListing, CentralState, StakePool, Tag};
+use crate::utils::{
+    amount_with_transfer_fee, check_account_key, check_account_owner, check_signer,
+    check_token_program, settle_bond_rewards, transfer_checked, transfer_user_index_bond,
+};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `fill_bond_listing` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `fill_bond_listing` instruction
+pub struct Accounts<'a, T> {
+    /// The bond listing account
+    #[cons(writable)]
+    pub bond_listing: &'a T,
+
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The buyer of the bond
+    #[cons(signer)]
+    pub buyer: &'a T,
+
+    /// The quote token account of the buyer
+    #[cons(writable)]
+    pub buyer_token_account: &'a T,
+
+    /// The seller of the bond, receives the rent of the listing
+    #[cons(writable)]
+    pub seller: &'a T,
+
+    /// The quote token account of the seller
+    #[cons(writable)]
+    pub seller_token_account: &'a T,
+
+    /// The ACCESS token account of the seller receiving the pending rewards
+    #[cons(writable)]
+    pub seller_rewards_destination: &'a T,
+
+    /// The stake pool of the bond
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The central state account
+    pub central_state: &'a T,
+
+    /// The ACCESS mint token
+    #[cons(writable)]
+    pub mint: &'a T,
+
+    /// The token program of the ACCESS mint
+    pub spl_token_program: &'a T,
+
+    /// The mint of the quote token
+    pub quote_mint: &'a T,
+
+    /// The token program of the quote mint
+    pub quote_token_program: &'a T,
+
+    /// Optional user indexes of the seller and the buyer
+    #[cons(writable)]
+    pub user_indexes: &'a [T],
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_listing: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter)?,
+            buyer: next_account_info(accounts_iter)?,
+            buyer_token_account: next_account_info(accounts_iter)?,
+            seller: next_account_info(accounts_iter)?,
+            seller_token_account: next_account_info(accounts_iter)?,
+            seller_rewards_destination: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            quote_mint: next_account_info(accounts_iter)?,
+            quote_token_program: next_account_info(accounts_iter)?,
+            user_indexes: accounts_iter.as_slice(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+        check_token_program(accounts.quote_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.quote_mint,
+            accounts.quote_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.bond_listing, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.buyer, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_fill_bond_listing(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut listing = BondListing::from_account_info(accounts.bond_listing)?;
+    let mut bond = BondAccount::from_listed_account_info(accounts.bond_account)?;
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+
+    check_account_key(
+        accounts.bond_account,
+        &listing.bond_account,
+        AccessError::AccountNotDeterministic,
+    )?;
+    check_account_key(
+        accounts.seller,
+        &listing.seller,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+    check_account_key(
+        accounts.quote_mint,
+        &listing.quote_mint,
+        AccessError::WrongQuoteMint,
+    )?;
+    check_account_key(
+        accounts.seller_token_account,
+        &listing.seller_token_account,
+        AccessError::WrongQuoteDestination,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &bond.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+
+    // Pay the seller
+    transfer_checked(
+        accounts.quote_token_program,
+        accounts.buyer_token_account,
+        accounts.quote_mint,
+        accounts.seller_token_account,
+        accounts.buyer,
+        amount_with_transfer_fee(accounts.quote_mint, listing.price)?,
+        &[],
+    )?;
+
+    // The rewards accrued until the sale belong to the seller
+    settle_bond_rewards(
+        program_id,
+        &mut bond,
+        &mut stake_pool,
+        &central_state,
+        accounts.central_state,
+        accounts.mint,
+        accounts.seller_rewards_destination,
+        accounts.spl_token_program,
+    )?;
+
+    msg!("Selling bond to {}", accounts.buyer.key);
+    bond.tag = Tag::BondAccount;
+    bond.owner = *accounts.buyer.key;
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    transfer_user_index_bond(
+        accounts.user_indexes,
+        accounts.bond_account.key,
+        accounts.seller.key,
+        accounts.buyer.key,
+        program_id,
+    )?;
+
+    listing.close();
+    listing.save(&mut accounts.bond_listing.data.borrow_mut())?;
+
+    let mut listing_lamports = accounts.bond_listing.lamports.borrow_mut();
+    let mut seller_lamports = accounts.seller.lamports.borrow_mut();
+
+    **seller_lamports += **listing_lamports;
+    **listing_lamports = 0;
+
+    Ok(())
+}
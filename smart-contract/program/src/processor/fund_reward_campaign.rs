@@ -0,0 +1,129 @@
+//! Fund a reward campaign
+//! This instruction allows the stake pool owner to deposit reward tokens in the campaign vault
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::RewardCampaign;
+use crate::utils::{
+    amount_with_transfer_fee, check_account_key, check_account_owner, check_signer,
+    check_token_program, transfer_checked,
+};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `fund_reward_campaign` instruction
+pub struct Params {
+    // Amount of reward tokens to deposit
+    pub amount: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `fund_reward_campaign` instruction
+pub struct Accounts<'a, T> {
+    /// The reward campaign account
+    pub reward_campaign: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The source account of the reward tokens
+    #[cons(writable)]
+    pub source_token: &'a T,
+
+    /// The campaign vault account
+    #[cons(writable)]
+    pub vault: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The mint of the reward token
+    pub mint: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            reward_campaign: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            source_token: next_account_info(accounts_iter)?,
+            vault: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.reward_campaign,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.source_token,
+            accounts.spl_token_program.key,
+            AccessError::WrongTokenAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_fund_reward_campaign(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let campaign = RewardCampaign::from_account_info(accounts.reward_campaign)?;
+
+    check_account_key(
+        accounts.owner,
+        &campaign.owner,
+        AccessError::WrongStakePoolOwner,
+    )?;
+    check_account_key(accounts.mint, &campaign.mint, AccessError::WrongMint)?;
+    check_account_key(
+        accounts.vault,
+        &campaign.vault,
+        AccessError::CampaignVaultMismatch,
+    )?;
+
+    if params.amount == 0 {
+        return Err(AccessError::NoOp.into());
+    }
+
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.source_token,
+        accounts.mint,
+        accounts.vault,
+        accounts.owner,
+        amount_with_transfer_fee(accounts.mint, params.amount)?,
+        &[],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Get the amount of tokens that can currently be unlocked from a bond
+//! This instruction does not modify any state, the result is returned with `set_return_data`
+use crate::error::AccessError;
+use crate::state::BondAccount;
+use crate::utils::check_account_owner;
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `get_bond_unlockable` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `get_bond_unlockable` instruction
+pub struct Accounts<'a, T> {
+    /// The bond account
+    pub bond_account: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_account: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_get_bond_unlockable(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let bond = BondAccount::from_account_info(accounts.bond_account, false)?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Mirrors the checks of the `unlock_bond_tokens` instruction
+    let unlockable = bond.calc_unlockable_amount(current_time)?;
+
+    set_return_data(&unlockable.try_to_vec()?);
+
+    Ok(())
+}
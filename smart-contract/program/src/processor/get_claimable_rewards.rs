@@ -0,0 +1,109 @@
+//! Get the claimable rewards of a stake account, without the share of its referrer
+//! This instruction does not modify any state, the result is returned with `set_return_data`
+use crate::error::AccessError;
+use crate::state::{CentralState, StakeAccount, StakePool, Tag};
+use crate::utils::{calc_reward_fp32, check_account_key, check_account_owner};
+use bonfida_utils::fp_math::safe_downcast;
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `get_claimable_rewards` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `get_claimable_rewards` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake account
+    pub stake_account: &'a T,
+
+    /// The central state account
+    pub central_state: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_account: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_get_claimable_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+    let stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
+    let stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+
+    check_account_key(
+        accounts.stake_pool,
+        &stake_account.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+
+    // Same computation as the `claim_rewards` instruction
+    let reward = calc_reward_fp32(
+        central_state.last_snapshot_offset,
+        stake_account.last_claimed_offset,
+        &stake_pool,
+        true,
+        true,
+    )?
+    .checked_mul(stake_account.stake_amount as u128)
+    .map(|r| ((r >> 31) + 1) >> 1)
+    .and_then(safe_downcast)
+    .ok_or(AccessError::Overflow)?;
+
+    // Part of the rewards goes to the referrer of the staker
+    let mut referral_reward = 0;
+    if stake_account.has_referrer() && central_state.referral_reward_share > 0 {
+        referral_reward = reward
+            .checked_mul(central_state.referral_reward_share)
+            .ok_or(AccessError::Overflow)?
+            / 100;
+    }
+    let reward = reward - referral_reward;
+
+    set_return_data(&reward.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Check whether a stake account grants access to a stake pool
+//! This instruction does not modify any state, the result is returned with `set_return_data`
+use crate::cpi::access::has_access;
+use crate::error::AccessError;
+use crate::state::StakeAccount;
+use crate::utils::check_account_owner;
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `has_access` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `has_access` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake account
+    pub stake_account: &'a T,
+
+    /// Optional bond account of the stake account owner
+    pub bond_account: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_account: next_account_info(accounts_iter)?,
+            bond_account: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+        if let Some(bond_account) = accounts.bond_account {
+            check_account_owner(
+                bond_account,
+                program_id,
+                AccessError::WrongBondAccountOwner,
+            )?
+        }
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_has_access(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+
+    let result = has_access(
+        &stake_account.owner,
+        accounts.stake_pool,
+        accounts.stake_account,
+        accounts.bond_account,
+    )?;
+
+    set_return_data(&result.try_to_vec()?);
+
+    Ok(())
+}
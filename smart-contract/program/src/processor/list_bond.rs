@@ -0,0 +1,152 @@
+//! List a bond
+//! This instruction escrows a bond in a listing, any wallet can then buy it for the price set by the seller
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::state::{BondAccount, BondListing, Tag};
+use crate::utils::{
+    assert_uninitialized, check_account_key, check_account_owner, check_signer,
+    check_token_program_owner, unpack_token_account,
+};
+use crate::{cpi::Cpi, error::AccessError};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `list_bond` instruction
+pub struct Params {
+    /// The price of the bond in quote tokens
+    pub price: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `list_bond` instruction
+pub struct Accounts<'a, T> {
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The owner of the bond
+    #[cons(writable, signer)]
+    pub seller: &'a T,
+
+    /// The bond listing account
+    #[cons(writable)]
+    pub bond_listing: &'a T,
+
+    /// The token account of the seller receiving the quote tokens
+    pub seller_token_account: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_account: next_account_info(accounts_iter)?,
+            seller: next_account_info(accounts_iter)?,
+            bond_listing: next_account_info(accounts_iter)?,
+            seller_token_account: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+        check_token_program_owner(
+            accounts.seller_token_account,
+            AccessError::WrongTokenAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_list_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
+
+    if !bond.is_active() {
+        msg!("Only active bonds can be listed");
+        return Err(AccessError::DataTypeMismatch.into());
+    }
+
+    check_account_key(
+        accounts.seller,
+        &bond.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+
+    let seller_token_acc = unpack_token_account(accounts.seller_token_account)?;
+    if &seller_token_acc.owner != accounts.seller.key {
+        return Err(AccessError::WrongQuoteDestination.into());
+    }
+
+    let (derived_key, nonce) = BondListing::find_key(accounts.bond_account.key, program_id);
+    check_account_key(
+        accounts.bond_listing,
+        &derived_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    assert_uninitialized(accounts.bond_listing)?;
+
+    let listing = BondListing::new(
+        *accounts.bond_account.key,
+        bond.owner,
+        *accounts.seller_token_account.key,
+        seller_token_acc.mint,
+        params.price,
+    );
+
+    msg!("Listing bond for {} {}", listing.price, listing.quote_mint);
+
+    Cpi::create_account(
+        program_id,
+        accounts.system_program,
+        accounts.seller,
+        accounts.bond_listing,
+        &[
+            BondListing::SEED,
+            &accounts.bond_account.key.to_bytes(),
+            &[nonce],
+        ],
+        listing.borsh_len(),
+    )?;
+
+    listing.save(&mut accounts.bond_listing.data.borrow_mut())?;
+
+    // The bond can no longer be unlocked, claimed or transferred while it is listed
+    bond.tag = Tag::ListedBondAccount;
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Migrate an account to the current layout
+//! This instruction is permissionless, the fee payer tops up the rent of the grown account
+//! The central state must be migrated before the stake pools
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_traits::FromPrimitive;
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+use std::convert::TryInto;
+use std::mem::size_of;
+
+use crate::cpi::Cpi;
+use crate::error::AccessError;
+use crate::state::{
+    BondAccount, CentralState, RewardsTuple, StakeAccount, StakePool, StakePoolHeader, Tag,
+    DEFAULT_POOL_WEIGHT, STAKE_BUFFER_LEN,
+};
+use crate::utils::{check_account_key, check_account_owner};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `migrate_account` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `migrate_account` instruction
+pub struct Accounts<'a, T> {
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The central state, stake pool, stake account or bond to migrate
+    #[cons(writable)]
+    pub account: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The fee account
+    #[cons(writable, signer)]
+    pub fee_payer: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            account: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            fee_payer: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.account, program_id, AccessError::WrongOwner)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let (central_state_key, _) = CentralState::find_key(program_id);
+    check_account_key(
+        accounts.central_state,
+        &central_state_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+
+    let tag =
+        Tag::from_u8(accounts.account.data.borrow()[0]).ok_or(ProgramError::InvalidAccountData)?;
+    let data_len = accounts.account.data_len();
+
+    match tag {
+        Tag::CentralState => {
+            if data_len != CentralState::LEGACY_LEN {
+                return Err(AccessError::NoOp.into());
+            }
+            realloc(&accounts, CentralState::LEN)?;
+
+            // Every legacy pool gets the default weight, so the weighted stake of all of them is
+            // seeded at once, the other new fields start at zero
+            let mut central_state = CentralState::from_account_info(accounts.account)?;
+            central_state.total_weighted_staked = (central_state.total_staked as u128)
+                .checked_mul(DEFAULT_POOL_WEIGHT as u128)
+                .ok_or(AccessError::Overflow)?;
+            central_state.total_weighted_staked_snapshot = (central_state.total_staked_snapshot
+                as u128)
+                .checked_mul(DEFAULT_POOL_WEIGHT as u128)
+                .ok_or(AccessError::Overflow)?;
+            central_state.save(&mut accounts.account.data.borrow_mut())?;
+        }
+        Tag::StakePool | Tag::InactiveStakePool | Tag::FrozenStakePool => {
+            let buffer_len = size_of::<RewardsTuple>() * STAKE_BUFFER_LEN as usize;
+            if data_len != StakePoolHeader::LEGACY_LEN + buffer_len {
+                return Err(AccessError::NoOp.into());
+            }
+            // The weighted stake of the pool was seeded by the central state migration
+            CentralState::from_account_info(accounts.central_state)?;
+
+            realloc(&accounts, StakePoolHeader::ACCOUNT_LEN)?;
+            {
+                // Move the circular buffer after the new header fields
+                let mut data = accounts.account.data.borrow_mut();
+                let header_len = size_of::<StakePoolHeader>();
+                data.copy_within(
+                    StakePoolHeader::LEGACY_LEN..StakePoolHeader::LEGACY_LEN + buffer_len,
+                    header_len,
+                );
+                data[StakePoolHeader::LEGACY_LEN..header_len].fill(0);
+            }
+
+            let mut stake_pool = StakePool::get_checked(
+                accounts.account,
+                vec![Tag::StakePool, Tag::InactiveStakePool, Tag::FrozenStakePool],
+            )?;
+            // The pool statistics count from the migration onwards
+            stake_pool.header.pool_weight = DEFAULT_POOL_WEIGHT;
+        }
+        Tag::StakeAccount | Tag::FrozenStakeAccount => {
+            // Legacy stake accounts were never referred
+            if data_len != StakeAccount::LEGACY_LEN {
+                return Err(AccessError::NoOp.into());
+            }
+            realloc(&accounts, StakeAccount::LEN)?;
+        }
+        Tag::InactiveBondAccount | Tag::BondAccount | Tag::FrozenBondAccount => {
+            // Legacy bonds use the periodic unlocks, never expire and refund no one
+            let nb_sellers = {
+                let data = accounts.account.data.borrow();
+                let offset = BondAccount::SELLERS_OFFSET;
+                u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize
+            };
+            let account_len = BondAccount::account_len(nb_sellers);
+            if data_len >= account_len {
+                return Err(AccessError::NoOp.into());
+            }
+            realloc(&accounts, account_len)?;
+        }
+        _ => {
+            msg!("This account has no legacy layout");
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+    }
+
+    Ok(())
+}
+
+fn realloc(accounts: &Accounts<AccountInfo>, new_len: usize) -> ProgramResult {
+    msg!(
+        "Migrating {} from {} to {} bytes",
+        accounts.account.key,
+        accounts.account.data_len(),
+        new_len
+    );
+    Cpi::realloc_account(
+        accounts.system_program,
+        accounts.fee_payer,
+        accounts.account,
+        new_len,
+    )
+}
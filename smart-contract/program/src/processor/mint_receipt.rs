@@ -0,0 +1,399 @@
+//! Mint a subscription receipt
+//! This instruction mints a non-transferable NFT naming the stake pool and the tier of the subscription.
+//! It is meant to be sent with the stake that grants the access, a burnt receipt can be minted again with the current tier.
+//! The receipt must be burnt with `burn_receipt` before the stake account is closed, `unstake` burns it when the stake account loses access.
+use bonfida_utils::{BorshSize, InstructionsAccount};
+use borsh::{BorshDeserialize, BorshSerialize};
+use mpl_token_metadata::{
+    instruction::{create_metadata_accounts_v2, update_metadata_accounts_v2},
+    pda::find_metadata_account,
+    state::DataV2,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_token::{
+    instruction::{approve, freeze_account, initialize_mint, mint_to},
+    state::Mint,
+};
+
+use crate::cpi::{access::has_access, Cpi};
+use crate::error::AccessError;
+use crate::state::{CentralState, StakeAccount, StakePool, Tag};
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `mint_receipt` instruction
+pub struct Params {
+    // The URI of the receipt metadata
+    pub uri: String,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `mint_receipt` instruction
+pub struct Accounts<'a, T> {
+    /// The central state account
+    pub central_state: &'a T,
+
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake account
+    #[cons(writable)]
+    pub stake_account: &'a T,
+
+    /// The owner of the stake account
+    #[cons(writable, signer)]
+    pub owner: &'a T,
+
+    /// The receipt mint account
+    #[cons(writable)]
+    pub receipt_mint: &'a T,
+
+    /// The receipt token account of the owner
+    #[cons(writable)]
+    pub receipt_token: &'a T,
+
+    /// The receipt metadata account
+    #[cons(writable)]
+    pub metadata: &'a T,
+
+    /// The metadata program account
+    pub metadata_program: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The associated token program account
+    pub associated_token_program: &'a T,
+
+    /// The system program account
+    pub system_program: &'a T,
+
+    /// The rent sysvar account
+    pub rent_sysvar: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_account: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            receipt_mint: next_account_info(accounts_iter)?,
+            receipt_token: next_account_info(accounts_iter)?,
+            metadata: next_account_info(accounts_iter)?,
+            metadata_program: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            associated_token_program: next_account_info(accounts_iter)?,
+            system_program: next_account_info(accounts_iter)?,
+            rent_sysvar: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_account_key(
+            accounts.metadata_program,
+            &mpl_token_metadata::ID,
+            AccessError::WrongMplProgram,
+        )?;
+        check_account_key(
+            accounts.spl_token_program,
+            &spl_token::ID,
+            AccessError::WrongSplTokenProgramId,
+        )?;
+        check_account_key(
+            accounts.associated_token_program,
+            &spl_associated_token_account::ID,
+            AccessError::WrongAssociatedTokenProgram,
+        )?;
+        check_account_key(
+            accounts.system_program,
+            &system_program::ID,
+            AccessError::WrongSystemProgram,
+        )?;
+        check_account_key(
+            accounts.rent_sysvar,
+            &sysvar::rent::ID,
+            AccessError::WrongRent,
+        )?;
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.stake_account,
+            program_id,
+            AccessError::WrongStakeAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_mint_receipt(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    // Only subscribers get a receipt
+    if !has_access(
+        accounts.owner.key,
+        accounts.stake_pool,
+        accounts.stake_account,
+        None,
+    )? {
+        return Err(AccessError::NotEnoughStake.into());
+    }
+
+    let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
+    if stake_account.has_receipt {
+        msg!("The receipt is already minted");
+        return Err(AccessError::NoOp.into());
+    }
+    let stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let required_amount = std::cmp::min(
+        stake_account.pool_minimum_at_creation,
+        stake_pool.header.minimum_stake_amount,
+    );
+    // The tier is the number of times the stake covers the pool minimum
+    let tier = stake_account
+        .stake_amount
+        .checked_div(required_amount)
+        .unwrap_or(1);
+    drop(stake_pool);
+
+    let (receipt_mint_key, receipt_nonce) =
+        StakeAccount::find_receipt_mint_key(accounts.stake_account.key, program_id);
+    check_account_key(
+        accounts.receipt_mint,
+        &receipt_mint_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    // The mint of a burnt receipt is reused
+    let is_new_mint = accounts.receipt_mint.data_is_empty();
+    if !is_new_mint {
+        check_account_owner(
+            accounts.receipt_mint,
+            &spl_token::ID,
+            AccessError::WrongOwner,
+        )?;
+        if Mint::unpack(&accounts.receipt_mint.data.borrow())?.supply != 0 {
+            msg!("The previous receipt must be burnt");
+            return Err(AccessError::NoOp.into());
+        }
+    }
+    check_account_key(
+        accounts.receipt_token,
+        &get_associated_token_address(accounts.owner.key, &receipt_mint_key),
+        AccessError::AccountNotDeterministic,
+    )?;
+    let (metadata_key, _) = find_metadata_account(&receipt_mint_key);
+    check_account_key(
+        accounts.metadata,
+        &metadata_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+
+    let central_state_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state.signer_nonce]];
+
+    // Create the receipt mint, the central state keeps the freeze authority
+    if is_new_mint {
+        Cpi::create_account(
+            &spl_token::ID,
+            accounts.system_program,
+            accounts.owner,
+            accounts.receipt_mint,
+            &[
+                StakeAccount::RECEIPT_SEED,
+                &accounts.stake_account.key.to_bytes(),
+                &[receipt_nonce],
+            ],
+            Mint::LEN,
+        )?;
+        let ix = initialize_mint(
+            &spl_token::ID,
+            &receipt_mint_key,
+            accounts.central_state.key,
+            Some(accounts.central_state.key),
+            0,
+        )?;
+        invoke(
+            &ix,
+            &[
+                accounts.spl_token_program.clone(),
+                accounts.receipt_mint.clone(),
+                accounts.rent_sysvar.clone(),
+            ],
+        )?;
+    }
+
+    if accounts.receipt_token.data_is_empty() {
+        let ix = create_associated_token_account(
+            accounts.owner.key,
+            accounts.owner.key,
+            &receipt_mint_key,
+            &spl_token::ID,
+        );
+        invoke(
+            &ix,
+            &[
+                accounts.associated_token_program.clone(),
+                accounts.owner.clone(),
+                accounts.receipt_token.clone(),
+                accounts.receipt_mint.clone(),
+                accounts.system_program.clone(),
+                accounts.spl_token_program.clone(),
+                accounts.rent_sysvar.clone(),
+            ],
+        )?;
+    }
+
+    let ix = mint_to(
+        &spl_token::ID,
+        &receipt_mint_key,
+        accounts.receipt_token.key,
+        accounts.central_state.key,
+        &[],
+        1,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.spl_token_program.clone(),
+            accounts.receipt_mint.clone(),
+            accounts.receipt_token.clone(),
+            accounts.central_state.clone(),
+        ],
+        &[central_state_seeds],
+    )?;
+
+    // The central state is made delegate so that the receipt can be burnt without the owner
+    let ix = approve(
+        &spl_token::ID,
+        accounts.receipt_token.key,
+        accounts.central_state.key,
+        accounts.owner.key,
+        &[],
+        1,
+    )?;
+    invoke(
+        &ix,
+        &[
+            accounts.spl_token_program.clone(),
+            accounts.receipt_token.clone(),
+            accounts.central_state.clone(),
+            accounts.owner.clone(),
+        ],
+    )?;
+
+    // Frozen tokens cannot be transferred
+    let ix = freeze_account(
+        &spl_token::ID,
+        accounts.receipt_token.key,
+        &receipt_mint_key,
+        accounts.central_state.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            accounts.spl_token_program.clone(),
+            accounts.receipt_token.clone(),
+            accounts.receipt_mint.clone(),
+            accounts.central_state.clone(),
+        ],
+        &[central_state_seeds],
+    )?;
+
+    let pool_key = accounts.stake_pool.key.to_string();
+    let name = format!("ACCESS {} Tier {}", &pool_key[..8], tier);
+    msg!("Minting receipt {}", name);
+
+    if is_new_mint {
+        let ix = create_metadata_accounts_v2(
+            mpl_token_metadata::ID,
+            metadata_key,
+            receipt_mint_key,
+            *accounts.central_state.key,
+            *accounts.owner.key,
+            *accounts.central_state.key,
+            name,
+            "ACSUB".to_string(),
+            params.uri,
+            None,
+            0,
+            true,
+            true,
+            None,
+            None,
+        );
+        invoke_signed(
+            &ix,
+            &[
+                accounts.metadata_program.clone(),
+                accounts.metadata.clone(),
+                accounts.receipt_mint.clone(),
+                accounts.central_state.clone(),
+                accounts.owner.clone(),
+                accounts.system_program.clone(),
+                accounts.rent_sysvar.clone(),
+            ],
+            &[central_state_seeds],
+        )?;
+    } else {
+        // The metadata of a burnt receipt is updated with the current tier
+        let data = DataV2 {
+            name,
+            symbol: "ACSUB".to_string(),
+            uri: params.uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        };
+        let ix = update_metadata_accounts_v2(
+            mpl_token_metadata::ID,
+            metadata_key,
+            *accounts.central_state.key,
+            None,
+            Some(data),
+            None,
+            None,
+        );
+        invoke_signed(
+            &ix,
+            &[
+                accounts.metadata_program.clone(),
+                accounts.metadata.clone(),
+                accounts.central_state.clone(),
+            ],
+            &[central_state_seeds],
+        )?;
+    }
+
+    stake_account.has_receipt = true;
+    stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
+
+    Ok(())
+}
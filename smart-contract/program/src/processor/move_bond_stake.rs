@@ -0,0 +1,236 @@
+//! Move the stake of a bond to another pool
+//! The locked tokens of the bond are transferred between the pool vaults, rewards must be claimed first
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::state::{BondAccount, CentralState, StakePool, StakePoolHeader};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::utils::{
+    amount_after_transfer_fee, assert_pool_member, check_account_key, check_account_owner,
+    check_signer, check_token_program, transfer_checked,
+};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `move_bond_stake` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `move_bond_stake` instruction
+pub struct Accounts<'a, T> {
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The account of the bond owner
+    #[cons(signer)]
+    pub bond_owner: &'a T,
+
+    /// The stake pool the bond is staked in
+    #[cons(writable)]
+    pub source_stake_pool: &'a T,
+
+    /// The vault of the source stake pool
+    #[cons(writable)]
+    pub source_pool_vault: &'a T,
+
+    /// The stake pool the bond moves to
+    #[cons(writable)]
+    pub destination_stake_pool: &'a T,
+
+    /// The vault of the destination stake pool
+    #[cons(writable)]
+    pub destination_pool_vault: &'a T,
+
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The mint of the ACCESS token
+    pub mint: &'a T,
+
+    /// Optional pool membership of the owner, required by allowlisted destination pools
+    pub pool_membership: Option<&'a T>,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_account: next_account_info(accounts_iter)?,
+            bond_owner: next_account_info(accounts_iter)?,
+            source_stake_pool: next_account_info(accounts_iter)?,
+            source_pool_vault: next_account_info(accounts_iter)?,
+            destination_stake_pool: next_account_info(accounts_iter)?,
+            destination_pool_vault: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            pool_membership: next_account_info(accounts_iter).ok(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.bond_account, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.source_stake_pool,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.destination_stake_pool,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.bond_owner, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_move_bond_stake(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
+
+    if accounts.source_stake_pool.key == accounts.destination_stake_pool.key {
+        msg!("The bond is already staked in this pool");
+        return Err(AccessError::NoOp.into());
+    }
+
+    // Bonds can be moved out of a deprecated pool but not into one
+    let mut source_stake_pool = StakePool::get_checked(
+        accounts.source_stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
+    let mut destination_stake_pool =
+        StakePool::get_checked(accounts.destination_stake_pool, vec![Tag::StakePool])?;
+
+    check_account_key(
+        accounts.mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+    check_account_key(
+        accounts.bond_owner,
+        &bond.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+    check_account_key(
+        accounts.source_stake_pool,
+        &bond.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+    check_account_key(
+        accounts.source_pool_vault,
+        &Pubkey::new(&source_stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+    check_account_key(
+        accounts.destination_pool_vault,
+        &Pubkey::new(&destination_stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+
+    assert_pool_member(
+        &destination_stake_pool,
+        accounts.destination_stake_pool.key,
+        &bond.owner,
+        accounts.pool_membership,
+        program_id,
+    )?;
+
+    let current_offset = central_state.get_current_offset()?;
+    if (source_stake_pool.header.current_day_idx as u64) < current_offset
+        || (destination_stake_pool.header.current_day_idx as u64) < current_offset
+    {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    if bond.last_claimed_offset < current_offset {
+        return Err(AccessError::UnclaimedRewards.into());
+    }
+
+    let amount = bond.total_staked;
+    // The transfer fee of the mint is withheld from the moved tokens, it counts as unlocked
+    let received_amount = amount_after_transfer_fee(accounts.mint, amount)?;
+    msg!(
+        "Moving {} bond tokens, {} received",
+        amount,
+        received_amount
+    );
+
+    // Update the stake pools
+    source_stake_pool.header.withdraw(amount)?;
+    source_stake_pool.header.remove_bond_holder();
+    destination_stake_pool.header.deposit(received_amount)?;
+    destination_stake_pool.header.add_bond_holder();
+    let source_pool_weight = source_stake_pool.header.pool_weight;
+    let destination_pool_weight = destination_stake_pool.header.pool_weight;
+    let destination_minimum = destination_stake_pool.header.minimum_stake_amount;
+
+    let signer_seeds: &[&[u8]] = &[
+        StakePoolHeader::SEED,
+        &source_stake_pool.header.owner.clone(),
+        &[source_stake_pool.header.nonce],
+    ];
+
+    drop(source_stake_pool);
+    drop(destination_stake_pool);
+
+    // Transfer tokens
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.source_pool_vault,
+        accounts.mint,
+        accounts.destination_pool_vault,
+        accounts.source_stake_pool,
+        amount,
+        &[signer_seeds],
+    )?;
+
+    // Update bond state
+    bond.total_unlocked_amount = bond
+        .total_unlocked_amount
+        .checked_add(amount - received_amount)
+        .ok_or(AccessError::Overflow)?;
+    bond.total_staked = received_amount;
+    bond.stake_pool = *accounts.destination_stake_pool.key;
+    bond.pool_minimum_at_creation = destination_minimum;
+    bond.last_claimed_offset = central_state.last_snapshot_offset;
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    // Update central state
+    central_state.withdraw(amount, source_pool_weight)?;
+    central_state.deposit(received_amount, destination_pool_weight)?;
+    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//! Reclaim the leftovers of a reward campaign
+//! This instruction allows the stake pool owner to withdraw the reward tokens that are not owed to stakers once the campaign is over
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::state::{RewardCampaign, StakePool};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, check_token_program, transfer_checked,
+    unpack_token_account,
+};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `reclaim_campaign_rewards` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `reclaim_campaign_rewards` instruction
+pub struct Accounts<'a, T> {
+    /// The reward campaign account
+    #[cons(writable)]
+    pub reward_campaign: &'a T,
+
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The campaign vault account
+    #[cons(writable)]
+    pub vault: &'a T,
+
+    /// The destination of the leftover reward tokens
+    #[cons(writable)]
+    pub destination_token: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The mint of the reward token
+    pub mint: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            reward_campaign: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            vault: next_account_info(accounts_iter)?,
+            destination_token: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.reward_campaign,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.destination_token,
+            accounts.spl_token_program.key,
+            AccessError::WrongTokenAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_reclaim_campaign_rewards(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut campaign = RewardCampaign::from_account_info(accounts.reward_campaign)?;
+    StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    check_account_key(
+        accounts.owner,
+        &campaign.owner,
+        AccessError::WrongStakePoolOwner,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &campaign.stake_pool,
+        AccessError::StakePoolMismatch,
+    )?;
+    check_account_key(accounts.mint, &campaign.mint, AccessError::WrongMint)?;
+    check_account_key(
+        accounts.vault,
+        &campaign.vault,
+        AccessError::CampaignVaultMismatch,
+    )?;
+
+    if current_time < campaign.end_time {
+        msg!("The campaign is still running");
+        return Err(AccessError::CampaignNotOver.into());
+    }
+
+    campaign.update_accumulator(current_time)?;
+
+    let vault = unpack_token_account(accounts.vault)?;
+    let leftover = vault
+        .amount
+        .checked_sub(campaign.outstanding_rewards()?)
+        .ok_or(AccessError::Overflow)?;
+
+    msg!("Reclaiming {} reward tokens", leftover);
+
+    if leftover == 0 {
+        return Err(AccessError::NoOp.into());
+    }
+
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.vault,
+        accounts.mint,
+        accounts.destination_token,
+        accounts.reward_campaign,
+        leftover,
+        &[&[
+            RewardCampaign::SEED,
+            &campaign.stake_pool.to_bytes(),
+            &campaign.mint.to_bytes(),
+            &[campaign.nonce],
+        ]],
+    )?;
+
+    campaign.save(&mut accounts.reward_campaign.data.borrow_mut())?;
+
+    Ok(())
+}
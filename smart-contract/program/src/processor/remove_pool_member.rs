@@ -0,0 +1,103 @@
+//! Remove a member from an allowlisted pool
+//! The stake account of the member is left untouched but it can no longer be topped up
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::state::{PoolMembership, StakePool};
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `remove_pool_member` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `remove_pool_member` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(writable, signer)]
+    pub stake_pool_owner: &'a T,
+
+    /// The pool membership account
+    #[cons(writable)]
+    pub pool_membership: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_pool_owner: next_account_info(accounts_iter)?,
+            pool_membership: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+        check_account_owner(
+            accounts.pool_membership,
+            program_id,
+            AccessError::WrongOwner,
+        )?;
+
+        // Check signer
+        check_signer(
+            accounts.stake_pool_owner,
+            AccessError::StakePoolOwnerMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_remove_pool_member(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::InactiveStakePool],
+    )?;
+    let mut membership = PoolMembership::from_account_info(accounts.pool_membership)?;
+
+    check_account_key(
+        accounts.stake_pool_owner,
+        &Pubkey::new(&stake_pool.header.owner),
+        AccessError::WrongStakePoolOwner,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &membership.stake_pool,
+        AccessError::StakePoolMismatch,
+    )?;
+
+    membership.close();
+    membership.save(&mut accounts.pool_membership.data.borrow_mut())?;
+
+    let mut membership_lamports = accounts.pool_membership.lamports.borrow_mut();
+    let mut owner_lamports = accounts.stake_pool_owner.lamports.borrow_mut();
+
+    **owner_lamports += **membership_lamports;
+    **membership_lamports = 0;
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! Self activate a stake pool
+//! This instruction allows a stake pool owner to activate their pool without the central state authority
+//! by locking the activation deposit in the pool vault
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{CentralState, StakePool, Tag};
+use crate::utils::{
+    amount_with_transfer_fee, check_account_key, check_account_owner, check_signer,
+    check_token_program, transfer_checked,
+};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `self_activate_stake_pool` instruction
+pub struct Params {}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `self_activate_stake_pool` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool to activate
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The owner of the stake pool
+    #[cons(signer)]
+    pub owner: &'a T,
+
+    /// The source account of the deposit
+    #[cons(writable)]
+    pub source_token: &'a T,
+
+    /// The stake pool vault
+    #[cons(writable)]
+    pub pool_vault: &'a T,
+
+    /// The account of the central state
+    pub central_state: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// The mint of the ACCESS token
+    pub mint: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            owner: next_account_info(accounts_iter)?,
+            source_token: next_account_info(accounts_iter)?,
+            pool_vault: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.source_token,
+            accounts.spl_token_program.key,
+            AccessError::WrongTokenAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(accounts.owner, AccessError::StakePoolOwnerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_self_activate_stake_pool(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::InactiveStakePool])?;
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+
+    check_account_key(
+        accounts.mint,
+        &central_state.token_mint,
+        AccessError::WrongMint,
+    )?;
+    check_account_key(
+        accounts.owner,
+        &Pubkey::new(&stake_pool.header.owner),
+        AccessError::WrongStakePoolOwner,
+    )?;
+    check_account_key(
+        accounts.pool_vault,
+        &Pubkey::new(&stake_pool.header.vault),
+        AccessError::StakePoolVaultMismatch,
+    )?;
+
+    if central_state.activation_deposit == 0 {
+        msg!("Stake pools can only be activated by the central state authority");
+        return Err(AccessError::SelfActivationDisabled.into());
+    }
+
+    // The deposit stays in the vault until the pool is closed
+    stake_pool.header.activation_deposit = central_state.activation_deposit;
+    stake_pool.header.tag = Tag::StakePool as u8;
+    stake_pool.header.last_claimed_offset = central_state.last_snapshot_offset;
+
+    drop(stake_pool);
+
+    // The vault must hold the exact deposit for the pool to be closed later
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.source_token,
+        accounts.mint,
+        accounts.pool_vault,
+        accounts.owner,
+        amount_with_transfer_fee(accounts.mint, central_state.activation_deposit)?,
+        &[],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! Turn the allowlist of a pool on or off
+//! This instruction allows a pool owner to restrict the creation of stake accounts and staking to the wallets holding a pool membership
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::state::StakePool;
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `set_pool_allowlist` instruction
+pub struct Params {
+    /// Whether only the pool members can stake
+    pub allowlisted: bool,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `set_pool_allowlist` instruction
+pub struct Accounts<'a, T> {
+    /// The stake pool account
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The stake pool owner account
+    #[cons(signer)]
+    pub stake_pool_owner: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            stake_pool: next_account_info(accounts_iter)?,
+            stake_pool_owner: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(
+            accounts.stake_pool_owner,
+            AccessError::StakePoolOwnerMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_set_pool_allowlist(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let Params { allowlisted } = params;
+
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::InactiveStakePool],
+    )?;
+
+    check_account_key(
+        accounts.stake_pool_owner,
+        &Pubkey::new(&stake_pool.header.owner),
+        AccessError::WrongStakePoolOwner,
+    )?;
+
+    stake_pool.header.allowlisted = allowlisted as u8;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Set the emission weight of a stake pool
+//! This instruction allows the central state authority to boost (or reduce) the share of the daily inflation going to a pool
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::state::{CentralState, StakePool, MAX_POOL_WEIGHT};
+use crate::{error::AccessError, state::Tag};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::utils::{check_account_key, check_account_owner, check_signer};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `set_pool_weight` instruction
+pub struct Params {
+    // The new weight of the pool (100 <-> 1x)
+    pub new_weight: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `set_pool_weight` instruction
+pub struct Accounts<'a, T> {
+    /// The account of the central state
+    #[cons(writable)]
+    pub central_state: &'a T,
+
+    /// The account of the central state authority
+    #[cons(signer)]
+    pub authority: &'a T,
+
+    /// The stake pool account
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            central_state: next_account_info(accounts_iter)?,
+            authority: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+        check_account_owner(
+            accounts.stake_pool,
+            program_id,
+            AccessError::WrongStakePoolAccountOwner,
+        )?;
+
+        // Check signer
+        check_signer(
+            accounts.authority,
+            AccessError::CentralStateAuthorityMustSign,
+        )?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_set_pool_weight(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+    let Params { new_weight } = params;
+
+    let mut central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::InactiveStakePool],
+    )?;
+
+    check_account_key(
+        accounts.authority,
+        &central_state.authority,
+        AccessError::WrongCentralStateAuthority,
+    )?;
+
+    if new_weight > MAX_POOL_WEIGHT {
+        msg!("The pool weight cannot be greater than {}", MAX_POOL_WEIGHT);
+        return Err(AccessError::InvalidPoolWeight.into());
+    }
+
+    // The crank of the day must use the same weight as the snapshot it is compared to
+    if stake_pool.header.total_staked != 0
+        && (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()?
+    {
+        return Err(AccessError::PoolMustBeCranked.into());
+    }
+
+    central_state.total_weighted_staked = central_state
+        .total_weighted_staked
+        .checked_sub(stake_pool.header.weighted_staked()?)
+        .ok_or(AccessError::Overflow)?;
+    stake_pool.header.pool_weight = new_weight;
+    central_state.total_weighted_staked = central_state
+        .total_weighted_staked
+        .checked_add(stake_pool.header.weighted_staked()?)
+        .ok_or(AccessError::Overflow)?;
+
+    central_state.save(&mut accounts.central_state.data.borrow_mut())?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//! Sign a bond round
+//! This instruction is used by authorized sellers to approve all the bonds of a round at once
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::utils::{assert_authorized_seller, check_account_owner, check_signer};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+use crate::error::AccessError;
+use crate::state::{BondRound, BOND_SIGNER_THRESHOLD};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `sign_bond_round` instruction
+pub struct Params {
+    /// Index of the seller in the [`array`][`crate::state::AUTHORIZED_BOND_SELLERS`] of authorized sellers
+    pub seller_index: u64,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `sign_bond_round` instruction
+pub struct Accounts<'a, T> {
+    /// The bond seller account
+    #[cons(signer)]
+    pub seller: &'a T,
+
+    /// The bond round account
+    #[cons(writable)]
+    pub bond_round: &'a T,
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            seller: next_account_info(accounts_iter)?,
+            bond_round: next_account_info(accounts_iter)?,
+        };
+
+        // Check ownership
+        check_account_owner(accounts.bond_round, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.seller, AccessError::BondSellerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_sign_bond_round(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let mut round = BondRound::from_account_info(accounts.bond_round)?;
+    assert_authorized_seller(accounts.seller, params.seller_index as usize)?;
+
+    if round.sellers.len() == BOND_SIGNER_THRESHOLD as usize {
+        msg!("There are enough signers already");
+        return Err(AccessError::NoOp.into());
+    }
+
+    #[cfg(not(feature = "no-bond-signer"))]
+    for current_seller in &round.sellers {
+        if accounts.seller.key == current_seller {
+            msg!("The seller has already signed");
+            return Err(AccessError::BondSellerAlreadySigner.into());
+        }
+    }
+
+    round.sellers.push(*accounts.seller.key);
+
+    round.save(&mut accounts.bond_round.data.borrow_mut())?;
+
+    Ok(())
+}
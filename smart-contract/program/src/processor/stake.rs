@@ -4,20 +4,20 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
-    program::invoke,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
-
-use spl_token::instruction::transfer;
+use std::mem::size_of;
 
 use crate::{
     state::{CentralState, Tag, FEES},
-    utils::{assert_valid_fee, check_account_key, check_account_owner, check_signer},
+    utils::{
+        amount_with_transfer_fee, assert_pool_member, assert_valid_fee, check_account_key,
+        check_account_owner, check_signer, check_token_program, checkpoint_campaign_positions,
+        next_optional_account_info, transfer_tokens, unpack_token_account,
+    },
 };
 use bonfida_utils::{BorshSize, InstructionsAccount};
-use solana_program::program_pack::Pack;
-use spl_token::state::Account;
 
 use crate::error::AccessError;
 use crate::state::{BondAccount, StakeAccount, StakePool};
@@ -27,6 +27,35 @@ use crate::state::{BondAccount, StakeAccount, StakePool};
 pub struct Params {
     // Amount to stake
     pub amount: u64,
+    // Whether the bond account is passed
+    pub has_bond_account: bool,
+    // Whether the mint is passed, it is required by Token-2022 mints
+    pub has_mint: bool,
+    // Whether the pool membership is passed
+    pub has_pool_membership: bool,
+    // Whether the referrer token account is passed
+    pub has_referrer_token: bool,
+}
+
+impl Params {
+    /// Number of accounts that are always passed to the instruction
+    const NB_REQUIRED_ACCOUNTS: usize = 8;
+
+    /// Decodes the parameters, the legacy encoding only holds the amount and the optional bond
+    /// account is the only account that can follow the required ones
+    pub fn unpack(instruction_data: &[u8], nb_accounts: usize) -> Result<Self, ProgramError> {
+        if instruction_data.len() == size_of::<u64>() {
+            return Ok(Params {
+                amount: u64::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+                has_bond_account: nb_accounts > Self::NB_REQUIRED_ACCOUNTS,
+                has_mint: false,
+                has_pool_membership: false,
+                has_referrer_token: false,
+            });
+        }
+        Params::try_from_slice(instruction_data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
 }
 
 #[derive(InstructionsAccount)]
@@ -52,7 +81,7 @@ pub struct Accounts<'a, T> {
     #[cons(writable)]
     pub source_token: &'a T,
 
-    /// The SPL token program account
+    /// The token program of the ACCESS mint
     pub spl_token_program: &'a T,
 
     /// The stake pool vault account
@@ -65,12 +94,27 @@ pub struct Accounts<'a, T> {
 
     /// Optional bond account to be able to stake under the minimum
     pub bond_account: Option<&'a T>,
+
+    /// Optional mint of the ACCESS token, required by Token-2022 mints
+    pub mint: Option<&'a T>,
+
+    /// Optional pool membership of the owner, required by allowlisted pools
+    pub pool_membership: Option<&'a T>,
+
+    /// Optional token account of the referrer receiving part of the fees
+    #[cons(writable)]
+    pub referrer_token: Option<&'a T>,
+
+    /// Pairs of (reward campaign, campaign position) of the stake account
+    #[cons(writable)]
+    pub campaign_positions: &'a [T],
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
     pub fn parse(
         accounts: &'a [AccountInfo<'b>],
         program_id: &Pubkey,
+        params: &Params,
     ) -> Result<Self, ProgramError> {
         let accounts_iter = &mut accounts.iter();
         let accounts = Accounts {
@@ -82,15 +126,15 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             spl_token_program: next_account_info(accounts_iter)?,
             vault: next_account_info(accounts_iter)?,
             fee_account: next_account_info(accounts_iter)?,
-            bond_account: next_account_info(accounts_iter).ok(),
+            bond_account: next_optional_account_info(accounts_iter, params.has_bond_account)?,
+            mint: next_optional_account_info(accounts_iter, params.has_mint)?,
+            pool_membership: next_optional_account_info(accounts_iter, params.has_pool_membership)?,
+            referrer_token: next_optional_account_info(accounts_iter, params.has_referrer_token)?,
+            campaign_positions: accounts_iter.as_slice(),
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
         check_account_owner(
@@ -110,14 +154,21 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         )?;
         check_account_owner(
             accounts.source_token,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongTokenAccountOwner,
         )?;
         check_account_owner(
             accounts.vault,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongTokenAccountOwner,
         )?;
+        if let Some(mint) = accounts.mint {
+            check_account_owner(
+                mint,
+                accounts.spl_token_program.key,
+                AccessError::WrongOwner,
+            )?
+        }
         if let Some(bond_account) = accounts.bond_account {
             check_account_owner(
                 bond_account,
@@ -125,6 +176,13 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
                 AccessError::WrongBondAccountOwner,
             )?
         }
+        if let Some(referrer_token) = accounts.referrer_token {
+            check_account_owner(
+                referrer_token,
+                accounts.spl_token_program.key,
+                AccessError::WrongTokenAccountOwner,
+            )?
+        }
 
         // Check signer
         check_signer(accounts.owner, AccessError::StakeAccountOwnerMustSign)?;
@@ -138,14 +196,23 @@ pub fn process_stake(
     accounts: &[AccountInfo],
     params: Params,
 ) -> ProgramResult {
-    let Params { amount} = params;
-    let accounts = Accounts::parse(accounts, program_id)?;
+    let accounts = Accounts::parse(accounts, program_id, &params)?;
+    let Params { amount, .. } = params;
 
     let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
     let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
     let mut central_state = CentralState::from_account_info(accounts.central_state_account)?;
 
-    let source_token_acc = Account::unpack(&accounts.source_token.data.borrow())?;
+    if let Some(mint) = accounts.mint {
+        check_account_key(mint, &central_state.token_mint, AccessError::WrongMint)?;
+    }
+    // Legacy clients don't pass the mint, they can only stake SPL tokens which have no transfer fee
+    let with_transfer_fee = |amount| match accounts.mint {
+        Some(mint) => amount_with_transfer_fee(mint, amount),
+        None => Ok(amount),
+    };
+
+    let source_token_acc = unpack_token_account(accounts.source_token)?;
     if source_token_acc.mint != central_state.token_mint {
         return Err(AccessError::WrongMint.into());
     }
@@ -169,6 +236,14 @@ pub fn process_stake(
         AccessError::StakePoolVaultMismatch,
     )?;
 
+    assert_pool_member(
+        &stake_pool,
+        accounts.stake_pool.key,
+        accounts.owner.key,
+        accounts.pool_membership,
+        program_id,
+    )?;
+
     let mut amount_in_bonds: u64 = 0;
     if let Some(bond_account) = accounts.bond_account {
         let bond_account = BondAccount::from_account_info(bond_account, false)?;
@@ -187,11 +262,15 @@ pub fn process_stake(
     }
 
     // if we were previously under the minimum stake limit it gets reset to the pool's one
-    if stake_account.stake_amount.checked_add(amount_in_bonds).ok_or(AccessError::Overflow)? < stake_account.pool_minimum_at_creation {
+    if stake_account
+        .stake_amount
+        .checked_add(amount_in_bonds)
+        .ok_or(AccessError::Overflow)?
+        < stake_account.pool_minimum_at_creation
+    {
         stake_account.pool_minimum_at_creation = stake_pool.header.minimum_stake_amount;
     }
 
-
     assert_valid_fee(accounts.fee_account, &central_state.authority)?;
 
     let fees = (amount * FEES) / 100;
@@ -214,42 +293,60 @@ pub fn process_stake(
         stake_account.last_claimed_offset = central_state.get_current_offset()?;
     }
 
-    // Transfer tokens
-    let transfer_instruction = transfer(
-        &spl_token::ID,
-        accounts.source_token.key,
-        accounts.vault.key,
-        accounts.owner.key,
+    // Transfer tokens, the transfer fee of the mint is paid on top so that the vault receives the staked amount
+    transfer_tokens(
+        accounts.spl_token_program,
+        accounts.source_token,
+        accounts.mint,
+        accounts.vault,
+        accounts.owner,
+        with_transfer_fee(amount)?,
         &[],
-        amount,
-    )?;
-    invoke(
-        &transfer_instruction,
-        &[
-            accounts.spl_token_program.clone(),
-            accounts.source_token.clone(),
-            accounts.vault.clone(),
-            accounts.owner.clone(),
-        ],
     )?;
 
+    // Part of the fees goes to the referrer of the staker
+    let mut referral_fees = 0;
+    if let Some(referrer_token) = accounts.referrer_token {
+        let referrer_token_acc = unpack_token_account(referrer_token)?;
+        if referrer_token_acc.mint != central_state.token_mint {
+            return Err(AccessError::WrongMint.into());
+        }
+        if !stake_account.has_referrer() && referrer_token_acc.owner != stake_account.owner {
+            msg!("Recording referrer {}", referrer_token_acc.owner);
+            stake_account.referrer = referrer_token_acc.owner;
+        }
+        if referrer_token_acc.owner != stake_account.referrer {
+            msg!(
+                "The referrer of the stake account is {}",
+                stake_account.referrer
+            );
+            return Err(AccessError::ReferrerMismatch.into());
+        }
+        referral_fees = fees
+            .checked_mul(central_state.referral_fee_rate)
+            .ok_or(AccessError::Overflow)?
+            / 100;
+
+        transfer_tokens(
+            accounts.spl_token_program,
+            accounts.source_token,
+            accounts.mint,
+            referrer_token,
+            accounts.owner,
+            with_transfer_fee(referral_fees)?,
+            &[],
+        )?;
+    }
+
     // Transfer fees
-    let transfer_fees = transfer(
-        &spl_token::ID,
-        accounts.source_token.key,
-        accounts.fee_account.key,
-        accounts.owner.key,
+    transfer_tokens(
+        accounts.spl_token_program,
+        accounts.source_token,
+        accounts.mint,
+        accounts.fee_account,
+        accounts.owner,
+        with_transfer_fee(fees - referral_fees)?,
         &[],
-        fees,
-    )?;
-    invoke(
-        &transfer_fees,
-        &[
-            accounts.spl_token_program.clone(),
-            accounts.source_token.clone(),
-            accounts.fee_account.clone(),
-            accounts.owner.clone(),
-        ],
     )?;
 
     if stake_account
@@ -271,14 +368,21 @@ pub fn process_stake(
     }
 
     // Update stake account
+    if stake_account.stake_amount == 0 {
+        stake_pool.header.add_staker();
+    }
     stake_account.deposit(amount)?;
+    checkpoint_campaign_positions(
+        program_id,
+        accounts.campaign_positions,
+        accounts.stake_account.key,
+        &stake_account,
+    )?;
     stake_pool.header.deposit(amount)?;
+    stake_pool.header.record_fees(fees);
 
     //Update central state
-    central_state.total_staked = central_state
-        .total_staked
-        .checked_add(amount)
-        .ok_or(AccessError::Overflow)?;
+    central_state.deposit(amount, stake_pool.header.pool_weight)?;
 
     // Save states
     stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
@@ -0,0 +1,153 @@
+//! Transfer a bond
+//! This instruction allows the owner of a bond to give it to another wallet, the pending rewards are settled to the current owner first
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::AccessError;
+use crate::state::{BondAccount, CentralState, StakePool, Tag};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, check_token_program, settle_bond_rewards,
+    transfer_user_index_bond,
+};
+use bonfida_utils::{BorshSize, InstructionsAccount};
+
+#[derive(BorshDeserialize, BorshSerialize, BorshSize)]
+/// The required parameters for the `transfer_bond` instruction
+pub struct Params {
+    /// The new owner of the bond
+    pub new_owner: Pubkey,
+}
+
+#[derive(InstructionsAccount)]
+/// The required accounts for the `transfer_bond` instruction
+pub struct Accounts<'a, T> {
+    /// The bond account
+    #[cons(writable)]
+    pub bond_account: &'a T,
+
+    /// The current owner of the bond
+    #[cons(signer)]
+    pub bond_owner: &'a T,
+
+    /// The stake pool of the bond
+    #[cons(writable)]
+    pub stake_pool: &'a T,
+
+    /// The central state account
+    pub central_state: &'a T,
+
+    /// The ACCESS mint token
+    #[cons(writable)]
+    pub mint: &'a T,
+
+    /// The ACCESS token account of the current owner receiving the pending rewards
+    #[cons(writable)]
+    pub rewards_destination: &'a T,
+
+    /// The SPL token program account
+    pub spl_token_program: &'a T,
+
+    /// Optional user indexes of the current and new owners
+    #[cons(writable)]
+    pub user_indexes: &'a [T],
+}
+
+impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
+    pub fn parse(
+        accounts: &'a [AccountInfo<'b>],
+        program_id: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        let accounts_iter = &mut accounts.iter();
+        let accounts = Accounts {
+            bond_account: next_account_info(accounts_iter)?,
+            bond_owner: next_account_info(accounts_iter)?,
+            stake_pool: next_account_info(accounts_iter)?,
+            central_state: next_account_info(accounts_iter)?,
+            mint: next_account_info(accounts_iter)?,
+            rewards_destination: next_account_info(accounts_iter)?,
+            spl_token_program: next_account_info(accounts_iter)?,
+            user_indexes: accounts_iter.as_slice(),
+        };
+
+        // Check keys
+        check_token_program(accounts.spl_token_program)?;
+
+        // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
+        check_account_owner(
+            accounts.bond_account,
+            program_id,
+            AccessError::WrongBondAccountOwner,
+        )?;
+        check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
+        check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
+
+        // Check signer
+        check_signer(accounts.bond_owner, AccessError::BuyerMustSign)?;
+
+        Ok(accounts)
+    }
+}
+
+pub fn process_transfer_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    params: Params,
+) -> ProgramResult {
+    let accounts = Accounts::parse(accounts, program_id)?;
+
+    let central_state = CentralState::from_account_info(accounts.central_state)?;
+    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
+
+    if !bond.is_active() {
+        msg!("Only active bonds can be transferred");
+        return Err(AccessError::DataTypeMismatch.into());
+    }
+
+    check_account_key(
+        accounts.bond_owner,
+        &bond.owner,
+        AccessError::StakeAccountOwnerMismatch,
+    )?;
+    check_account_key(
+        accounts.stake_pool,
+        &bond.stake_pool,
+        AccessError::WrongStakePool,
+    )?;
+
+    settle_bond_rewards(
+        program_id,
+        &mut bond,
+        &mut stake_pool,
+        &central_state,
+        accounts.central_state,
+        accounts.mint,
+        accounts.rewards_destination,
+        accounts.spl_token_program,
+    )?;
+
+    msg!("Transferring bond to {}", params.new_owner);
+    bond.owner = params.new_owner;
+    bond.save(&mut accounts.bond_account.data.borrow_mut())?;
+
+    transfer_user_index_bond(
+        accounts.user_indexes,
+        accounts.bond_account.key,
+        accounts.bond_owner.key,
+        &params.new_owner,
+        program_id,
+    )?;
+
+    Ok(())
+}
@@ -6,7 +6,6 @@ use solana_program::{
     clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
@@ -15,10 +14,11 @@ use solana_program::{
 use crate::state::{BondAccount, CentralState, StakePool, StakePoolHeader};
 use crate::{error::AccessError, state::Tag};
 use bonfida_utils::{BorshSize, InstructionsAccount};
-use solana_program::program_pack::Pack;
-use spl_token::state::Account;
 
-use crate::utils::{assert_bond_derivation, check_account_key, check_account_owner, check_signer};
+use crate::utils::{
+    check_account_key, check_account_owner, check_signer, check_token_program, transfer_checked,
+    unpack_token_account,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, BorshSize)]
 /// The required parameters for the `unlock_bond_tokens` instruction
@@ -76,13 +76,14 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
+        check_account_owner(
+            accounts.mint,
+            accounts.spl_token_program.key,
+            AccessError::WrongOwner,
+        )?;
         check_account_owner(accounts.bond_account, program_id, AccessError::WrongOwner)?;
         check_account_owner(accounts.central_state, program_id, AccessError::WrongOwner)?;
         check_account_owner(accounts.stake_pool, program_id, AccessError::WrongOwner)?;
@@ -102,19 +103,22 @@ pub fn process_unlock_bond_tokens(
     let accounts = Accounts::parse(accounts, program_id)?;
     let mut central_state = CentralState::from_account_info(accounts.central_state)?;
     let mut bond = BondAccount::from_account_info(accounts.bond_account, false)?;
-    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
     let current_time = Clock::get()?.unix_timestamp;
 
-    let destination_token_acc = Account::unpack(&accounts.access_token_destination.data.borrow())?;
+    let destination_token_acc = unpack_token_account(accounts.access_token_destination)?;
     if destination_token_acc.mint != central_state.token_mint {
         return Err(AccessError::WrongMint.into());
     }
 
-    assert_bond_derivation(
-        accounts.bond_account,
-        accounts.bond_owner.key,
-        bond.total_amount_sold,
-        program_id,
+    // Bonds can change hands, the derivation only tells who bought the bond
+    check_account_key(
+        accounts.bond_owner,
+        &bond.owner,
+        AccessError::StakeAccountOwnerMismatch,
     )?;
     check_account_key(
         accounts.stake_pool,
@@ -137,36 +141,62 @@ pub fn process_unlock_bond_tokens(
         return Err(ProgramError::InvalidArgument);
     }
 
-    if current_time < bond.unlock_start_date {
-        msg!("The bond tokens have not started unlocking yet");
-        return Err(ProgramError::InvalidArgument);
-    }
-
     if (stake_pool.header.current_day_idx as u64) < central_state.get_current_offset()? {
         return Err(AccessError::PoolMustBeCranked.into());
     }
 
-    let delta = current_time
-        .checked_sub(bond.last_unlock_time)
-        .ok_or(AccessError::Overflow)?;
-
-    if delta < bond.unlock_period {
-        msg!("Need to wait the end of the current unlock period before unlocking the bond");
-        return Err(ProgramError::InvalidArgument);
-    }
-
     if bond.last_claimed_offset < central_state.get_current_offset()? {
         return Err(AccessError::UnclaimedRewards.into());
     }
 
-    let missed_periods = delta
-        .checked_div(bond.unlock_period)
-        .ok_or(AccessError::Overflow)?;
-
-    let unlock_amount = bond.calc_unlock_amount(missed_periods as u64)?;
+    let unlock_amount = if bond.vesting.is_linear() {
+        if current_time < bond.vesting.cliff_date {
+            msg!("The bond tokens have not reached the cliff yet");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let unlock_amount = bond.calc_vested_unlock_amount(current_time)?;
+        if unlock_amount == 0 {
+            msg!("No vested tokens to unlock");
+            return Err(AccessError::NoOp.into());
+        }
+        bond.last_unlock_time = current_time;
+        unlock_amount
+    } else {
+        if current_time < bond.unlock_start_date {
+            msg!("The bond tokens have not started unlocking yet");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let delta = current_time
+            .checked_sub(bond.last_unlock_time)
+            .ok_or(AccessError::Overflow)?;
+
+        if delta < bond.unlock_period {
+            msg!("Need to wait the end of the current unlock period before unlocking the bond");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let missed_periods = delta
+            .checked_div(bond.unlock_period)
+            .ok_or(AccessError::Overflow)?;
+
+        // bond.last_unlock_time += missed_periods * bond.unlock_period;
+        bond.last_unlock_time = missed_periods
+            .checked_mul(bond.unlock_period)
+            .ok_or(AccessError::Overflow)?
+            .checked_add(bond.last_unlock_time)
+            .ok_or(AccessError::Overflow)?;
+
+        bond.calc_unlock_amount(missed_periods as u64)?
+    };
 
     // Update the stake pool
+    if unlock_amount >= bond.total_staked {
+        stake_pool.header.remove_bond_holder();
+    }
     stake_pool.header.withdraw(unlock_amount)?;
+    let pool_weight = stake_pool.header.pool_weight;
 
     let signer_seeds: &[&[u8]] = &[
         StakePoolHeader::SEED,
@@ -177,34 +207,17 @@ pub fn process_unlock_bond_tokens(
     drop(stake_pool);
 
     // Transfer tokens
-    let transfer_ix = spl_token::instruction::transfer(
-        &spl_token::ID,
-        accounts.pool_vault.key,
-        accounts.access_token_destination.key,
-        accounts.stake_pool.key,
-        &[],
+    transfer_checked(
+        accounts.spl_token_program,
+        accounts.pool_vault,
+        accounts.mint,
+        accounts.access_token_destination,
+        accounts.stake_pool,
         unlock_amount,
-    )?;
-
-    invoke_signed(
-        &transfer_ix,
-        &[
-            accounts.spl_token_program.clone(),
-            accounts.pool_vault.clone(),
-            accounts.access_token_destination.clone(),
-            accounts.stake_pool.clone(),
-        ],
         &[signer_seeds],
     )?;
 
     // Update bond state
-    // bond.last_unlock_time += missed_periods * bond.unlock_period;
-    bond.last_unlock_time = missed_periods
-        .checked_mul(bond.unlock_period)
-        .ok_or(AccessError::Overflow)?
-        .checked_add(bond.last_unlock_time)
-        .ok_or(AccessError::Overflow)?;
-
     // bond.total_unlocked_amount += unlock_amount;
     bond.total_unlocked_amount = bond
         .total_unlocked_amount
@@ -220,10 +233,7 @@ pub fn process_unlock_bond_tokens(
     bond.save(&mut accounts.bond_account.data.borrow_mut())?;
 
     // Update central state
-    central_state.total_staked = central_state
-        .total_staked
-        .checked_sub(unlock_amount)
-        .ok_or(AccessError::Overflow)?;
+    central_state.withdraw(unlock_amount, pool_weight)?;
     central_state.save(&mut accounts.central_state.data.borrow_mut())?;
 
     Ok(())
@@ -1,16 +1,17 @@
 //! Unstake
 use crate::{
     state::{CentralState, Tag},
-    utils::{check_account_key, check_account_owner, check_signer},
+    utils::{
+        burn_receipt_tokens, check_account_key, check_account_owner, check_signer,
+        check_token_program, checkpoint_campaign_positions, next_optional_account_info,
+        transfer_tokens, unpack_token_account,
+    },
 };
 use bonfida_utils::{BorshSize, InstructionsAccount};
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use solana_program::{account_info::{next_account_info, AccountInfo}, entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
-use solana_program::program::invoke_signed;
-use solana_program::program_pack::Pack;
-use spl_token::instruction::transfer;
-use spl_token::state::Account;
+use solana_program::{account_info::{next_account_info, AccountInfo}, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey};
+use std::mem::size_of;
 
 use crate::error::AccessError;
 use crate::state::{BondAccount, StakeAccount, StakePool, StakePoolHeader};
@@ -20,6 +21,32 @@ use crate::state::{BondAccount, StakeAccount, StakePool, StakePoolHeader};
 pub struct Params {
     // Amount to unstake
     pub amount: u64,
+    // Whether the bond account is passed
+    pub has_bond_account: bool,
+    // Whether the mint is passed, it is required by Token-2022 mints
+    pub has_mint: bool,
+    // Whether the receipt accounts are passed, they are required to unstake below the minimum with a receipt
+    pub has_receipt: bool,
+}
+
+impl Params {
+    /// Number of accounts that are always passed to the instruction
+    const NB_REQUIRED_ACCOUNTS: usize = 7;
+
+    /// Decodes the parameters, the legacy encoding only holds the amount and the optional bond
+    /// account is the only account that can follow the required ones
+    pub fn unpack(instruction_data: &[u8], nb_accounts: usize) -> Result<Self, ProgramError> {
+        if instruction_data.len() == size_of::<u64>() {
+            return Ok(Params {
+                amount: u64::try_from_slice(instruction_data)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+                has_bond_account: nb_accounts > Self::NB_REQUIRED_ACCOUNTS,
+                has_mint: false,
+                has_receipt: false,
+            });
+        }
+        Params::try_from_slice(instruction_data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
 }
 
 #[derive(InstructionsAccount)]
@@ -45,7 +72,7 @@ pub struct Accounts<'a, T> {
     #[cons(writable)]
     pub destination_token: &'a T,
 
-    /// The SPL token program account
+    /// The token program of the ACCESS mint
     pub spl_token_program: &'a T,
 
     /// The stake pool vault
@@ -54,12 +81,31 @@ pub struct Accounts<'a, T> {
 
     /// Optional bond account to be able to stake under the minimum
     pub bond_account: Option<&'a T>,
+
+    /// Optional mint of the ACCESS token, required by Token-2022 mints
+    pub mint: Option<&'a T>,
+
+    /// Optional receipt mint of the stake account, burnt when the stake goes below the minimum
+    #[cons(writable)]
+    pub receipt_mint: Option<&'a T>,
+
+    /// Optional receipt token account of the stake account
+    #[cons(writable)]
+    pub receipt_token: Option<&'a T>,
+
+    /// Optional SPL token program of the receipt
+    pub receipt_token_program: Option<&'a T>,
+
+    /// Pairs of (reward campaign, campaign position) of the stake account
+    #[cons(writable)]
+    pub campaign_positions: &'a [T],
 }
 
 impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
     pub fn parse(
         accounts: &'a [AccountInfo<'b>],
         program_id: &Pubkey,
+        params: &Params,
     ) -> Result<Self, ProgramError> {
         let accounts_iter = &mut accounts.iter();
         let accounts = Accounts {
@@ -70,15 +116,16 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
             destination_token: next_account_info(accounts_iter)?,
             spl_token_program: next_account_info(accounts_iter)?,
             vault: next_account_info(accounts_iter)?,
-            bond_account: next_account_info(accounts_iter).ok(),
+            bond_account: next_optional_account_info(accounts_iter, params.has_bond_account)?,
+            mint: next_optional_account_info(accounts_iter, params.has_mint)?,
+            receipt_mint: next_optional_account_info(accounts_iter, params.has_receipt)?,
+            receipt_token: next_optional_account_info(accounts_iter, params.has_receipt)?,
+            receipt_token_program: next_optional_account_info(accounts_iter, params.has_receipt)?,
+            campaign_positions: accounts_iter.as_slice(),
         };
 
         // Check keys
-        check_account_key(
-            accounts.spl_token_program,
-            &spl_token::ID,
-            AccessError::WrongSplTokenProgramId,
-        )?;
+        check_token_program(accounts.spl_token_program)?;
 
         // Check ownership
         check_account_owner(
@@ -98,14 +145,21 @@ impl<'a, 'b: 'a> Accounts<'a, AccountInfo<'b>> {
         )?;
         check_account_owner(
             accounts.destination_token,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongTokenAccountOwner,
         )?;
         check_account_owner(
             accounts.vault,
-            &spl_token::ID,
+            accounts.spl_token_program.key,
             AccessError::WrongTokenAccountOwner,
         )?;
+        if let Some(mint) = accounts.mint {
+            check_account_owner(
+                mint,
+                accounts.spl_token_program.key,
+                AccessError::WrongOwner,
+            )?
+        }
         if let Some(bond_account) = accounts.bond_account {
             check_account_owner(
                 bond_account,
@@ -126,14 +180,21 @@ pub fn process_unstake(
     accounts: &[AccountInfo],
     params: Params,
 ) -> ProgramResult {
-    let Params { amount} = params;
-    let accounts = Accounts::parse(accounts, program_id)?;
+    let accounts = Accounts::parse(accounts, program_id, &params)?;
+    let Params { amount, .. } = params;
 
-    let mut stake_pool = StakePool::get_checked(accounts.stake_pool, vec![Tag::StakePool])?;
+    let mut stake_pool = StakePool::get_checked(
+        accounts.stake_pool,
+        vec![Tag::StakePool, Tag::DeprecatedStakePool],
+    )?;
     let mut stake_account = StakeAccount::from_account_info(accounts.stake_account)?;
     let mut central_state = CentralState::from_account_info(accounts.central_state_account)?;
 
-    let destination_token_acc = Account::unpack(&accounts.destination_token.data.borrow())?;
+    if let Some(mint) = accounts.mint {
+        check_account_key(mint, &central_state.token_mint, AccessError::WrongMint)?;
+    }
+
+    let destination_token_acc = unpack_token_account(accounts.destination_token)?;
     if destination_token_acc.mint != central_state.token_mint {
         return Err(AccessError::WrongMint.into());
     }
@@ -191,10 +252,46 @@ pub fn process_unstake(
     if stake_account.stake_amount != amount && new_total_in_pool < stake_account.pool_minimum_at_creation {
         return Err(AccessError::InvalidUnstakeAmount.into());
     }
+    // The receipt is burnt with the access it stands for
+    if stake_account.has_receipt && new_total_in_pool < stake_account.pool_minimum_at_creation {
+        match (
+            accounts.receipt_mint,
+            accounts.receipt_token,
+            accounts.receipt_token_program,
+        ) {
+            (Some(receipt_mint), Some(receipt_token), Some(receipt_token_program)) => {
+                burn_receipt_tokens(
+                    program_id,
+                    accounts.central_state_account,
+                    &central_state,
+                    accounts.stake_account.key,
+                    receipt_mint,
+                    receipt_token,
+                    receipt_token_program,
+                )?;
+                stake_account.has_receipt = false;
+            }
+            _ => {
+                msg!("The subscription receipt must be burnt before losing access");
+                return Err(AccessError::ReceiptNotBurnt.into());
+            }
+        }
+    }
 
     // Update stake account
     stake_account.withdraw(amount)?;
+    checkpoint_campaign_positions(
+        program_id,
+        accounts.campaign_positions,
+        accounts.stake_account.key,
+        &stake_account,
+    )?;
     stake_pool.header.withdraw(amount)?;
+    if stake_account.stake_amount == 0 {
+        stake_pool.header.remove_staker();
+    }
+
+    let pool_weight = stake_pool.header.pool_weight;
 
     // Transfer tokens
     let signer_seeds: &[&[u8]] = &[
@@ -202,25 +299,16 @@ pub fn process_unstake(
         &stake_pool.header.owner.clone(),
         &[stake_pool.header.nonce],
     ];
-    let transfer_instruction = transfer(
-        &spl_token::ID,
-        accounts.vault.key,
-        accounts.destination_token.key,
-        accounts.stake_pool.key,
-        &[],
-        amount,
-    )?;
 
     drop(stake_pool);
 
-    invoke_signed(
-        &transfer_instruction,
-        &[
-            accounts.spl_token_program.clone(),
-            accounts.vault.clone(),
-            accounts.destination_token.clone(),
-            accounts.stake_pool.clone(),
-        ],
+    transfer_tokens(
+        accounts.spl_token_program,
+        accounts.vault,
+        accounts.mint,
+        accounts.destination_token,
+        accounts.stake_pool,
+        amount,
         &[signer_seeds],
     )?;
 
@@ -228,10 +316,7 @@ pub fn process_unstake(
     stake_account.save(&mut accounts.stake_account.data.borrow_mut())?;
 
     //Update central state
-    central_state.total_staked = central_state
-        .total_staked
-        .checked_sub(amount)
-        .ok_or(AccessError::Overflow)?;
+    central_state.withdraw(amount, pool_weight)?;
     central_state.save(&mut accounts.central_state_account.data.borrow_mut())?;
 
     Ok(())
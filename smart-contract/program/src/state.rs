@@ -7,6 +7,7 @@ use num_traits::FromPrimitive;
 use solana_program::account_info::AccountInfo;
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
+use solana_program::keccak::hashv;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
@@ -44,6 +45,24 @@ pub const MAX_UNSTAKE_REQUEST: usize = 10;
 /// Fees charged on staking instruction in % (i.e FEES = 1 <-> 1% fee charged)
 pub const FEES: u64 = 2;
 
+/// Emission weight of a newly created stake pool (i.e 100 <-> 1x)
+pub const DEFAULT_POOL_WEIGHT: u64 = 100;
+
+/// Maximum emission weight that can be set on a stake pool (i.e 10x)
+pub const MAX_POOL_WEIGHT: u64 = 1_000;
+
+/// Maximum number of wallets a stake account can share its access with
+pub const MAX_DELEGATION_MEMBERS: usize = 20;
+
+/// Maximum number of reward campaigns a stake account can be registered in
+pub const MAX_CAMPAIGN_POSITIONS: u8 = 4;
+
+/// Maximum number of stake accounts and bonds tracked by a user index
+pub const MAX_USER_INDEX_POSITIONS: usize = 64;
+
+/// Number of admin actions kept by the admin log before the oldest ones are overwritten
+pub const ADMIN_LOG_LEN: usize = 128;
+
 #[derive(BorshSerialize, BorshDeserialize, BorshSize, PartialEq, FromPrimitive, ToPrimitive, Debug)]
 #[repr(u8)]
 #[allow(missing_docs)]
@@ -61,6 +80,28 @@ pub enum Tag {
     FrozenStakePool,
     FrozenStakeAccount,
     FrozenBondAccount,
+    // Secondary token rewards funded by a stake pool owner
+    RewardCampaign,
+    CampaignPosition,
+    // Wallets sharing the access of a stake account
+    AccessDelegation,
+    // Wallets allowed to stake in an allowlisted pool
+    PoolMembership,
+    // Bonds escrowed in a listing of the secondary market
+    ListedBondAccount,
+    BondListing,
+    BondAuction,
+    BondRound,
+    // Merkle distributor of ACCESS tokens
+    Distributor,
+    // Stake accounts and bonds of a wallet
+    UserIndex,
+    // Pools winding down: no new stakes nor rewards, withdrawals still allowed
+    DeprecatedStakePool,
+    // Ring buffer of the central state authority actions
+    AdminLog,
+    // Reason and expiry of a frozen account
+    FreezeRecord,
 }
 
 impl Tag {
@@ -78,6 +119,14 @@ impl Tag {
 
         Ok(tag)
     }
+
+    /// Whether the tag is the one of a frozen account
+    pub fn is_frozen(&self) -> bool {
+        matches!(
+            self,
+            Tag::FrozenStakePool | Tag::FrozenStakeAccount | Tag::FrozenBondAccount
+        )
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, BorshSize, Copy, Clone, Pod, Zeroable, Debug)]
@@ -93,8 +142,11 @@ pub struct StakePoolHeader {
     /// Updated by a trustless cranker
     pub current_day_idx: u16,
 
+    /// Set to 1 if only the wallets with a pool membership can stake
+    pub allowlisted: u8,
+
     /// Padding
-    pub _padding: [u8; 4],
+    pub _padding: [u8; 3],
 
     /// Minimum amount to stake to get access to the pool
     pub minimum_stake_amount: u64,
@@ -113,6 +165,30 @@ pub struct StakePoolHeader {
 
     /// Stake pool vault
     pub vault: [u8; 32],
+
+    /// Weight of the pool in the daily inflation split, set by the central state authority
+    pub pool_weight: u64,
+
+    /// Number of stake accounts with tokens staked, a non-empty stake account is always above the pool minimum
+    pub stakers_count: u64,
+
+    /// Number of active bonds staked in the pool
+    pub bond_holders_count: u64,
+
+    /// Lifetime rewards claimed by the stakers and bond holders of the pool
+    pub lifetime_staker_rewards: u64,
+
+    /// Lifetime rewards claimed by the owner of the pool
+    pub lifetime_owner_rewards: u64,
+
+    /// Lifetime staking fees paid by the stakers of the pool
+    pub lifetime_fees: u64,
+
+    /// Tokens locked in the vault by the owner to activate the pool, refunded when the pool is closed
+    pub activation_deposit: u64,
+
+    /// Timestamp after which a frozen pool is active again, 0 if the freeze never expires
+    pub freeze_expiry: i64,
 }
 
 #[allow(missing_docs)]
@@ -144,6 +220,10 @@ impl<'a> StakePoolRef<'a> {
         account_info: &'a AccountInfo<'b>,
         allowed_tags: Vec<Tag>,
     ) -> Result<Self, ProgramError> {
+        if account_info.data_len() != StakePoolHeader::ACCOUNT_LEN {
+            msg!("The stake pool must be migrated to the current layout");
+            return Err(AccessError::AccountNotMigrated.into());
+        }
         let (header, balances) = RefMut::map_split(account_info.data.borrow_mut(), |s| {
             let (hd, rem) = s.split_at_mut(size_of::<StakePoolHeader>());
             (
@@ -152,10 +232,7 @@ impl<'a> StakePoolRef<'a> {
             )
         });
 
-        let tag = FromPrimitive::from_u8(header.tag).ok_or(ProgramError::InvalidAccountData)?;
-        if !allowed_tags.contains(&tag) {
-            return Err(AccessError::DataTypeMismatch.into());
-        }
+        header.check_tag(&allowed_tags)?;
 
         Ok(StakePool { header, balances })
     }
@@ -233,6 +310,13 @@ impl StakePool<(), ()> {
 impl StakePoolHeader {
     pub const SEED: &'static [u8; 10] = b"stake_pool";
 
+    /// Size of the header before the fields following the vault were added
+    pub const LEGACY_LEN: usize = 104;
+
+    /// Size of a stake pool account, the header followed by the circular buffer
+    pub const ACCOUNT_LEN: usize =
+        size_of::<StakePoolHeader>() + size_of::<RewardsTuple>() * STAKE_BUFFER_LEN as usize;
+
     pub fn new(
         owner: Pubkey,
         nonce: u8,
@@ -243,20 +327,116 @@ impl StakePoolHeader {
             tag: Tag::InactiveStakePool as u8,
             total_staked: 0,
             current_day_idx: 0,
-            _padding: [0; 4],
+            allowlisted: 0,
+            _padding: [0; 3],
             last_claimed_offset: 0,
             owner: owner.to_bytes(),
             nonce,
             vault: vault.to_bytes(),
             minimum_stake_amount,
             stakers_part: STAKER_MULTIPLIER,
+            pool_weight: DEFAULT_POOL_WEIGHT,
+            stakers_count: 0,
+            bond_holders_count: 0,
+            lifetime_staker_rewards: 0,
+            lifetime_owner_rewards: 0,
+            lifetime_fees: 0,
+            activation_deposit: 0,
+            freeze_expiry: 0,
         })
     }
 
+    /// Staked amount of the pool multiplied by its emission weight
+    pub fn weighted_staked(&self) -> Result<u128, ProgramError> {
+        (self.total_staked as u128)
+            .checked_mul(self.pool_weight as u128)
+            .ok_or_else(|| AccessError::Overflow.into())
+    }
+
     pub fn close(&mut self) {
         self.tag = Tag::Deleted as u8
     }
 
+    pub fn is_freeze_expired(&self, current_time: i64) -> bool {
+        self.freeze_expiry != 0 && current_time >= self.freeze_expiry
+    }
+
+    /// Reads the header of a stake pool without borrowing the account mutably
+    pub fn get_checked(
+        account_info: &AccountInfo,
+        allowed_tags: Vec<Tag>,
+    ) -> Result<StakePoolHeader, ProgramError> {
+        if account_info.data_len() != Self::ACCOUNT_LEN {
+            msg!("The stake pool must be migrated to the current layout");
+            return Err(AccessError::AccountNotMigrated.into());
+        }
+        let data = account_info.data.borrow();
+        let header = *from_bytes::<StakePoolHeader>(&data[..size_of::<StakePoolHeader>()]);
+        header.check_tag(&allowed_tags)?;
+        Ok(header)
+    }
+
+    fn check_tag(&self, allowed_tags: &[Tag]) -> ProgramResult {
+        let mut tag = FromPrimitive::from_u8(self.tag).ok_or(ProgramError::InvalidAccountData)?;
+        // The pool is active again once its freeze expires, even before the freeze record is closed,
+        // stake accounts and bonds have no room for the expiry and stay frozen until `expire_freeze`
+        if tag == Tag::FrozenStakePool && self.is_freeze_expired(Clock::get()?.unix_timestamp) {
+            tag = Tag::StakePool;
+        }
+        if !allowed_tags.contains(&tag) {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        Ok(())
+    }
+
+    /// Expiry of the freeze of a stake pool account, `None` for pools that are not migrated
+    pub fn get_freeze_expiry(account: &AccountInfo) -> Option<i64> {
+        if account.data_len() != Self::ACCOUNT_LEN {
+            return None;
+        }
+        let data = account.data.borrow();
+        Some(from_bytes::<StakePoolHeader>(&data[..size_of::<StakePoolHeader>()]).freeze_expiry)
+    }
+
+    /// Sets the expiry of the freeze of a stake pool account, pools that are not migrated have no room for it
+    pub fn set_freeze_expiry(account: &AccountInfo, freeze_expiry: i64) {
+        if account.data_len() != Self::ACCOUNT_LEN {
+            return;
+        }
+        let mut data = account.data.borrow_mut();
+        let header = from_bytes_mut::<StakePoolHeader>(&mut data[..size_of::<StakePoolHeader>()]);
+        header.freeze_expiry = freeze_expiry;
+    }
+
+    // The statistics counters saturate instead of failing the instruction
+    pub fn record_staker_rewards(&mut self, amount: u64) {
+        self.lifetime_staker_rewards = self.lifetime_staker_rewards.saturating_add(amount);
+    }
+
+    pub fn record_owner_rewards(&mut self, amount: u64) {
+        self.lifetime_owner_rewards = self.lifetime_owner_rewards.saturating_add(amount);
+    }
+
+    pub fn record_fees(&mut self, amount: u64) {
+        self.lifetime_fees = self.lifetime_fees.saturating_add(amount);
+    }
+
+    pub fn add_staker(&mut self) {
+        self.stakers_count = self.stakers_count.saturating_add(1);
+    }
+
+    pub fn remove_staker(&mut self) {
+        self.stakers_count = self.stakers_count.saturating_sub(1);
+    }
+
+    pub fn add_bond_holder(&mut self) {
+        self.bond_holders_count = self.bond_holders_count.saturating_add(1);
+    }
+
+    pub fn remove_bond_holder(&mut self) {
+        self.bond_holders_count = self.bond_holders_count.saturating_sub(1);
+    }
+
     pub fn deposit(&mut self, amount: u64) -> ProgramResult {
         self.total_staked = self
             .total_staked
@@ -295,11 +475,30 @@ pub struct StakeAccount {
     /// Minimum stakeable amount of the pool when the account
     /// was created
     pub pool_minimum_at_creation: u64,
+
+    /// Wallet that referred the owner, set on the first referred stake and never changed afterwards
+    /// (`Pubkey::default()` if the account was not referred)
+    pub referrer: Pubkey,
+
+    /// Number of reward campaigns the account is registered in, their positions are checkpointed
+    /// on every stake and unstake
+    pub campaign_positions: u8,
+
+    /// Whether the subscription receipt of the account is minted, it must be burnt before the
+    /// account loses access or is closed
+    pub has_receipt: bool,
 }
 
 #[allow(missing_docs)]
 impl StakeAccount {
     pub const SEED: &'static [u8; 13] = b"stake_account";
+    pub const RECEIPT_SEED: &'static [u8; 7] = b"receipt";
+
+    /// Size of the account before the referrer, the campaign positions and the receipt were added
+    pub const LEGACY_LEN: usize = 89;
+
+    /// Size of the account
+    pub const LEN: usize = Self::LEGACY_LEN + 32 + 1 + 1;
 
     pub fn new(owner: Pubkey, stake_pool: Pubkey, pool_minimum_at_creation: u64) -> Self {
         Self {
@@ -309,9 +508,16 @@ impl StakeAccount {
             stake_pool,
             last_claimed_offset: 0,
             pool_minimum_at_creation,
+            referrer: Pubkey::default(),
+            campaign_positions: 0,
+            has_receipt: false,
         }
     }
 
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Pubkey::default()
+    }
+
     pub fn create_key(
         nonce: &u8,
         owner: &Pubkey,
@@ -336,6 +542,12 @@ impl StakeAccount {
         Pubkey::find_program_address(seeds, program_id)
     }
 
+    /// Mint of the subscription receipt NFT of a stake account
+    pub fn find_receipt_mint_key(stake_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[StakeAccount::RECEIPT_SEED, &stake_account.to_bytes()];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
     pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
         self.serialize(&mut dst)
             .map_err(|_| ProgramError::InvalidAccountData)
@@ -346,6 +558,10 @@ impl StakeAccount {
         if data[0] != Tag::StakeAccount as u8 && data[0] != Tag::Uninitialized as u8 {
             return Err(AccessError::DataTypeMismatch.into());
         }
+        if data.len() < StakeAccount::LEN {
+            msg!("The stake account must be migrated to the current layout");
+            return Err(AccessError::AccountNotMigrated.into());
+        }
         let result = StakeAccount::deserialize(&mut data)?;
         Ok(result)
     }
@@ -402,9 +618,42 @@ pub struct CentralState {
 
     /// The offset of the total_staked_snapshot from the creation_time in days
     pub last_snapshot_offset: u64,
+
+    /// Sum of the staked amount of every pool multiplied by the pool weight
+    pub total_weighted_staked: u128,
+
+    /// The daily total_weighted_staked snapshot used to split the inflation between pools
+    pub total_weighted_staked_snapshot: u128,
+
+    /// Amount of tokens minted to whoever cranks a stake pool into a new day,
+    /// taken out of the daily inflation of the cranked pool
+    pub crank_bounty: u64,
+
+    /// The % of the staking fees going to the referrer of the staker
+    pub referral_fee_rate: u64,
+
+    /// The % of the staker rewards going to the referrer of the staker
+    pub referral_reward_share: u64,
+
+    /// The % of the unvested bond tokens unlocked early that is sent to the DAO treasury,
+    /// early unlocks are disabled when set to 0
+    pub early_unlock_penalty: u64,
+
+    /// Amount of tokens a pool owner locks to activate their pool without the authority,
+    /// self activation is disabled when set to 0
+    pub activation_deposit: u64,
+
+    /// The admin log recording the actions of the authority, the default key until it is created
+    pub admin_log: Pubkey,
 }
 
 impl CentralState {
+    /// Size of the account before the fields following `last_snapshot_offset` were added
+    pub const LEGACY_LEN: usize = 106;
+
+    /// Size of the account
+    pub const LEN: usize = Self::LEGACY_LEN + 2 * 16 + 5 * 8 + 32;
+
     #[allow(missing_docs)]
     pub fn new(
         signer_nonce: u8,
@@ -423,6 +672,14 @@ impl CentralState {
             total_staked,
             total_staked_snapshot: 0,
             last_snapshot_offset: 0,
+            total_weighted_staked: 0,
+            total_weighted_staked_snapshot: 0,
+            crank_bounty: 0,
+            referral_fee_rate: 0,
+            referral_reward_share: 0,
+            early_unlock_penalty: 0,
+            activation_deposit: 0,
+            admin_log: Pubkey::default(),
         })
     }
     #[allow(missing_docs)]
@@ -446,6 +703,10 @@ impl CentralState {
         if data[0] != Tag::CentralState as u8 && data[0] != Tag::Uninitialized as u8 {
             return Err(AccessError::DataTypeMismatch.into());
         }
+        if data.len() < CentralState::LEN {
+            msg!("The central state must be migrated to the current layout");
+            return Err(AccessError::AccountNotMigrated.into());
+        }
         let result = CentralState::deserialize(&mut data)?;
         Ok(result)
     }
@@ -454,6 +715,30 @@ impl CentralState {
         let current_time = Clock::get()?.unix_timestamp as u64;
         Ok((current_time - self.creation_time as u64) / SECONDS_IN_DAY)
     }
+    /// Records tokens entering a pool of weight `pool_weight`
+    pub fn deposit(&mut self, amount: u64, pool_weight: u64) -> ProgramResult {
+        self.total_staked = self
+            .total_staked
+            .checked_add(amount)
+            .ok_or(AccessError::Overflow)?;
+        self.total_weighted_staked = (amount as u128)
+            .checked_mul(pool_weight as u128)
+            .and_then(|w| self.total_weighted_staked.checked_add(w))
+            .ok_or(AccessError::Overflow)?;
+        Ok(())
+    }
+    /// Records tokens leaving a pool of weight `pool_weight`
+    pub fn withdraw(&mut self, amount: u64, pool_weight: u64) -> ProgramResult {
+        self.total_staked = self
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(AccessError::Overflow)?;
+        self.total_weighted_staked = (amount as u128)
+            .checked_mul(pool_weight as u128)
+            .and_then(|w| self.total_weighted_staked.checked_sub(w))
+            .ok_or(AccessError::Overflow)?;
+        Ok(())
+    }
 }
 
 /// Number of sellers who need to agree for a bond to be sold
@@ -517,12 +802,89 @@ pub struct BondAccount {
 
     // Sellers who signed for the sell of the bond account
     pub sellers: Vec<Pubkey>,
+
+    // Cliff and linear vesting schedule, replaces the periodic unlocks when set
+    pub vesting: VestingSchedule,
+
+    // Date after which an inactive bond can no longer be claimed, zero if the bond never expires
+    pub expiry_date: i64,
+
+    // Account that paid the rent of the bond, refunded when the bond is cancelled
+    pub fee_payer: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize, Clone, Copy, Default, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct VestingSchedule {
+    /// Date at which `cliff_amount` tokens unlock at once
+    pub cliff_date: i64,
+
+    /// Amount of tokens released at the cliff
+    pub cliff_amount: u64,
+
+    /// Date at which the remaining tokens are fully vested, they vest linearly after the cliff
+    /// A zero date means the bond uses the periodic unlock schedule
+    pub linear_end_date: i64,
+}
+
+#[allow(missing_docs)]
+impl VestingSchedule {
+    pub fn is_linear(&self) -> bool {
+        self.linear_end_date != 0
+    }
+
+    pub fn check(&self, total_amount: u64) -> ProgramResult {
+        if self.linear_end_date < self.cliff_date || self.cliff_amount > total_amount {
+            msg!("Invalid vesting schedule");
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    /// Amount of tokens out of `total_amount` vested at `current_time`
+    pub fn vested_amount(&self, total_amount: u64, current_time: i64) -> Result<u64, ProgramError> {
+        if current_time < self.cliff_date {
+            return Ok(0);
+        }
+        if current_time >= self.linear_end_date {
+            return Ok(total_amount);
+        }
+        // vested = cliff_amount + (total_amount - cliff_amount) * elapsed / duration
+        let linear_amount = total_amount
+            .checked_sub(self.cliff_amount)
+            .ok_or(AccessError::Overflow)?;
+        let elapsed = current_time
+            .checked_sub(self.cliff_date)
+            .ok_or(AccessError::Overflow)? as u128;
+        let duration = self
+            .linear_end_date
+            .checked_sub(self.cliff_date)
+            .ok_or(AccessError::Overflow)? as u128;
+        let linear_vested = (linear_amount as u128)
+            .checked_mul(elapsed)
+            .and_then(|v| v.checked_div(duration))
+            .and_then(|v| v.try_into().ok())
+            .ok_or(AccessError::Overflow)?;
+        self.cliff_amount
+            .checked_add(linear_vested)
+            .ok_or_else(|| AccessError::Overflow.into())
+    }
 }
 
 #[allow(missing_docs)]
 impl BondAccount {
     pub const SEED: &'static [u8; 12] = b"bond_account";
 
+    /// Offset of the sellers, the fields before them have the same layout in every version
+    pub const SELLERS_OFFSET: usize = 209;
+
+    /// Size of the account holding a bond with `nb_sellers` sellers, with room for the missing signers
+    pub fn account_len(nb_sellers: usize) -> usize {
+        let nb_sellers = std::cmp::max(nb_sellers, BOND_SIGNER_THRESHOLD as usize);
+        // sellers, vesting schedule, expiry date and fee payer
+        Self::SELLERS_OFFSET + 4 + 32 * nb_sellers + 24 + 8 + 32
+    }
+
     pub fn create_key(owner: &Pubkey, total_amount_sold: u64, program_id: &Pubkey) -> (Pubkey, u8) {
         let seeds: &[&[u8]] = &[
             BondAccount::SEED,
@@ -532,6 +894,36 @@ impl BondAccount {
         Pubkey::find_program_address(seeds, program_id)
     }
 
+    /// Key of the bond claimed from the leaf at position `leaf_index` of a bond round
+    pub fn create_round_claim_key(
+        bond_round: &Pubkey,
+        leaf_index: u64,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            BondAccount::SEED,
+            &bond_round.to_bytes(),
+            &leaf_index.to_le_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Key of the bond created by the `fill_index`-th fill of a bond auction
+    pub fn create_auction_fill_key(
+        bond_auction: &Pubkey,
+        owner: &Pubkey,
+        fill_index: u64,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            BondAccount::SEED,
+            &bond_auction.to_bytes(),
+            &owner.to_bytes(),
+            &fill_index.to_le_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         owner: Pubkey,
@@ -546,6 +938,9 @@ impl BondAccount {
         pool_minimum_at_creation: u64,
         stake_pool: Pubkey,
         seller: Pubkey,
+        vesting: VestingSchedule,
+        expiry_date: i64,
+        fee_payer: Pubkey,
     ) -> Self {
         let sellers = vec![seller];
         Self {
@@ -563,6 +958,9 @@ impl BondAccount {
             total_unlocked_amount: 0,
             stake_pool,
             last_claimed_offset: 0,
+            vesting,
+            expiry_date,
+            fee_payer,
             sellers,
             pool_minimum_at_creation,
         }
@@ -577,6 +975,14 @@ impl BondAccount {
         self.tag == Tag::BondAccount
     }
 
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.expiry_date != 0 && current_time >= self.expiry_date
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+    }
+
     pub fn activate(&mut self, current_offset: u64) -> ProgramResult {
         self.tag = Tag::BondAccount;
         self.last_claimed_offset = current_offset;
@@ -602,6 +1008,16 @@ impl BondAccount {
         Ok(result)
     }
 
+    /// Deserializes a bond that is listed on the secondary market
+    pub fn from_listed_account_info(a: &AccountInfo) -> Result<BondAccount, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::ListedBondAccount as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = BondAccount::deserialize(&mut data)?;
+        Ok(result)
+    }
+
     pub fn calc_unlock_amount(&self, missed_periods: u64) -> Result<u64, ProgramError> {
         msg!("Missed periods {}", missed_periods);
         let cumulated_unlock_amnt = (missed_periods)
@@ -620,4 +1036,1036 @@ impl BondAccount {
                 .ok_or(AccessError::Overflow)?,
         ))
     }
+
+    /// Amount of tokens that can be unlocked at `current_time` with a linear vesting schedule
+    pub fn calc_vested_unlock_amount(&self, current_time: i64) -> Result<u64, ProgramError> {
+        let vested = self
+            .vesting
+            .vested_amount(self.total_amount_sold, current_time)?;
+        msg!(
+            "Vested amount {} Unlocked amount {}",
+            vested,
+            self.total_unlocked_amount
+        );
+        Ok(vested.saturating_sub(self.total_unlocked_amount))
+    }
+
+    /// Amount of tokens that can be unlocked at `current_time` without a penalty
+    pub fn calc_unlockable_amount(&self, current_time: i64) -> Result<u64, ProgramError> {
+        if self.vesting.is_linear() {
+            return self.calc_vested_unlock_amount(current_time);
+        }
+        let delta = current_time
+            .checked_sub(self.last_unlock_time)
+            .ok_or(AccessError::Overflow)?;
+        if current_time < self.unlock_start_date
+            || delta < self.unlock_period
+            || self.total_amount_sold <= self.total_unlocked_amount
+        {
+            return Ok(0);
+        }
+        let missed_periods = delta
+            .checked_div(self.unlock_period)
+            .ok_or(AccessError::Overflow)?;
+        self.calc_unlock_amount(missed_periods as u64)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct RewardCampaign {
+    /// Tag
+    pub tag: Tag,
+
+    /// Campaign nonce
+    pub nonce: u8,
+
+    /// Stake pool whose stakers are rewarded
+    pub stake_pool: Pubkey,
+
+    /// Owner of the stake pool, the only one allowed to manage the campaign
+    pub owner: Pubkey,
+
+    /// Mint of the reward token
+    pub mint: Pubkey,
+
+    /// Token account holding the campaign rewards
+    pub vault: Pubkey,
+
+    /// Amount of reward tokens distributed to the pool stakers every day
+    pub daily_rate: u64,
+
+    /// Timestamp after which no more rewards are distributed
+    pub end_time: i64,
+
+    /// Last time the accumulator was updated
+    pub last_update_time: i64,
+
+    /// Cumulated rewards per staked token in FP32 format
+    pub reward_per_token_fp32: u128,
+
+    /// Total amount of reward tokens owed to stakers since the campaign started
+    pub total_accrued: u64,
+
+    /// Total amount of reward tokens claimed by stakers
+    pub total_claimed: u64,
+
+    /// Sum of the stake of the registered positions, the rewards are only shared between them
+    pub total_registered_stake: u64,
+}
+
+#[allow(missing_docs)]
+impl RewardCampaign {
+    pub const SEED: &'static [u8; 15] = b"reward_campaign";
+
+    pub fn new(
+        nonce: u8,
+        stake_pool: Pubkey,
+        owner: Pubkey,
+        mint: Pubkey,
+        vault: Pubkey,
+        daily_rate: u64,
+        end_time: i64,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            tag: Tag::RewardCampaign,
+            nonce,
+            stake_pool,
+            owner,
+            mint,
+            vault,
+            daily_rate,
+            end_time,
+            last_update_time: Clock::get()?.unix_timestamp,
+            reward_per_token_fp32: 0,
+            total_accrued: 0,
+            total_claimed: 0,
+            total_registered_stake: 0,
+        })
+    }
+
+    pub fn find_key(stake_pool: &Pubkey, mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            RewardCampaign::SEED,
+            &stake_pool.to_bytes(),
+            &mint.to_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<RewardCampaign, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::RewardCampaign as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = RewardCampaign::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    /// Distributes the rewards emitted since the last update between the registered stake
+    pub fn update_accumulator(&mut self, current_time: i64) -> ProgramResult {
+        let until = std::cmp::min(current_time, self.end_time);
+        if until <= self.last_update_time {
+            return Ok(());
+        }
+        let elapsed = (until - self.last_update_time) as u128;
+        // Nothing is owed when nobody is registered, the tokens stay in the vault and can be reclaimed
+        if self.total_registered_stake != 0 {
+            let emitted = elapsed
+                .checked_mul(self.daily_rate as u128)
+                .ok_or(AccessError::Overflow)?;
+            let reward_per_token = (emitted << 32)
+                .checked_div(SECONDS_IN_DAY as u128)
+                .and_then(|r| r.checked_div(self.total_registered_stake as u128))
+                .ok_or(AccessError::Overflow)?;
+            self.reward_per_token_fp32 = self
+                .reward_per_token_fp32
+                .checked_add(reward_per_token)
+                .ok_or(AccessError::Overflow)?;
+            self.total_accrued = self
+                .total_accrued
+                .checked_add(
+                    (emitted / SECONDS_IN_DAY as u128)
+                        .try_into()
+                        .map_err(|_| AccessError::Overflow)?,
+                )
+                .ok_or(AccessError::Overflow)?;
+        }
+        self.last_update_time = until;
+        Ok(())
+    }
+
+    /// Amount of reward tokens owed to stakers that have not been claimed yet
+    pub fn outstanding_rewards(&self) -> Result<u64, ProgramError> {
+        self.total_accrued
+            .checked_sub(self.total_claimed)
+            .ok_or_else(|| AccessError::Overflow.into())
+    }
+
+    /// Replaces the stake of a registered position, the accumulator must be up to date
+    pub fn update_registered_stake(
+        &mut self,
+        previous_stake: u64,
+        new_stake: u64,
+    ) -> ProgramResult {
+        self.total_registered_stake = self
+            .total_registered_stake
+            .checked_sub(previous_stake)
+            .and_then(|s| s.checked_add(new_stake))
+            .ok_or(AccessError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct CampaignPosition {
+    /// Tag
+    pub tag: Tag,
+
+    /// Campaign the position belongs to
+    pub campaign: Pubkey,
+
+    /// Stake account earning the campaign rewards
+    pub stake_account: Pubkey,
+
+    /// Value of the campaign accumulator at the last checkpoint in FP32 format
+    pub reward_per_token_checkpoint_fp32: u128,
+
+    /// Staked amount of the stake account at the last checkpoint
+    pub stake_amount: u64,
+
+    /// Rewards earned before the last checkpoint that have not been claimed yet
+    pub pending_rewards: u64,
+}
+
+#[allow(missing_docs)]
+impl CampaignPosition {
+    pub const SEED: &'static [u8; 17] = b"campaign_position";
+
+    pub fn new(
+        campaign: Pubkey,
+        stake_account: Pubkey,
+        reward_per_token_checkpoint_fp32: u128,
+        stake_amount: u64,
+    ) -> Self {
+        Self {
+            tag: Tag::CampaignPosition,
+            campaign,
+            stake_account,
+            reward_per_token_checkpoint_fp32,
+            stake_amount,
+            pending_rewards: 0,
+        }
+    }
+
+    pub fn find_key(
+        campaign: &Pubkey,
+        stake_account: &Pubkey,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            CampaignPosition::SEED,
+            &campaign.to_bytes(),
+            &stake_account.to_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<CampaignPosition, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::CampaignPosition as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = CampaignPosition::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    /// Moves the rewards earned by the checkpointed stake since the last checkpoint to the pending
+    /// rewards and sets a new checkpoint
+    pub fn checkpoint(&mut self, reward_per_token_fp32: u128) -> ProgramResult {
+        let reward: u64 = (reward_per_token_fp32
+            .checked_sub(self.reward_per_token_checkpoint_fp32)
+            .and_then(|r| r.checked_mul(self.stake_amount as u128))
+            .ok_or(AccessError::Overflow)?
+            >> 32)
+            .try_into()
+            .map_err(|_| AccessError::Overflow)?;
+        self.pending_rewards = self
+            .pending_rewards
+            .checked_add(reward)
+            .ok_or(AccessError::Overflow)?;
+        self.reward_per_token_checkpoint_fp32 = reward_per_token_fp32;
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct AccessDelegation {
+    /// Tag
+    pub tag: Tag,
+
+    /// Stake pool the delegated access belongs to
+    pub stake_pool: Pubkey,
+
+    /// Stake account whose access is shared
+    pub stake_account: Pubkey,
+
+    /// Owner of the stake account
+    pub owner: Pubkey,
+
+    /// Wallets sharing the access of the stake account
+    pub members: Vec<Pubkey>,
+}
+
+#[allow(missing_docs)]
+impl AccessDelegation {
+    pub const SEED: &'static [u8; 17] = b"access_delegation";
+
+    /// Size of the account, allocated once for the maximum number of members
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 4 + 32 * MAX_DELEGATION_MEMBERS;
+
+    pub fn new(
+        stake_pool: Pubkey,
+        stake_account: Pubkey,
+        owner: Pubkey,
+        members: Vec<Pubkey>,
+    ) -> Result<Self, ProgramError> {
+        let mut delegation = Self {
+            tag: Tag::AccessDelegation,
+            stake_pool,
+            stake_account,
+            owner,
+            members: vec![],
+        };
+        delegation.set_members(members)?;
+        Ok(delegation)
+    }
+
+    pub fn find_key(stake_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[AccessDelegation::SEED, &stake_account.to_bytes()];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn set_members(&mut self, mut members: Vec<Pubkey>) -> ProgramResult {
+        members.sort();
+        members.dedup();
+        if members.len() > MAX_DELEGATION_MEMBERS {
+            msg!(
+                "An access delegation can have at most {} members",
+                MAX_DELEGATION_MEMBERS
+            );
+            return Err(AccessError::TooManyDelegationMembers.into());
+        }
+        self.members = members;
+        Ok(())
+    }
+
+    pub fn is_member(&self, wallet: &Pubkey) -> bool {
+        self.members.binary_search(wallet).is_ok()
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<AccessDelegation, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::AccessDelegation as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = AccessDelegation::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+        self.members = vec![];
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct PoolMembership {
+    /// Tag
+    pub tag: Tag,
+
+    /// Allowlisted stake pool
+    pub stake_pool: Pubkey,
+
+    /// Wallet allowed to stake in the pool
+    pub member: Pubkey,
+}
+
+#[allow(missing_docs)]
+impl PoolMembership {
+    pub const SEED: &'static [u8; 15] = b"pool_membership";
+
+    pub fn new(stake_pool: Pubkey, member: Pubkey) -> Self {
+        Self {
+            tag: Tag::PoolMembership,
+            stake_pool,
+            member,
+        }
+    }
+
+    pub fn find_key(stake_pool: &Pubkey, member: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            PoolMembership::SEED,
+            &stake_pool.to_bytes(),
+            &member.to_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<PoolMembership, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::PoolMembership as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = PoolMembership::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct BondListing {
+    /// Tag
+    pub tag: Tag,
+
+    /// Bond escrowed by the listing
+    pub bond_account: Pubkey,
+
+    /// Owner of the bond when it was listed
+    pub seller: Pubkey,
+
+    /// Token account receiving the sale proceeds
+    pub seller_token_account: Pubkey,
+
+    /// Mint of the token the bond is priced in
+    pub quote_mint: Pubkey,
+
+    /// Price of the bond in quote tokens
+    pub price: u64,
+}
+
+#[allow(missing_docs)]
+impl BondListing {
+    pub const SEED: &'static [u8; 12] = b"bond_listing";
+
+    pub fn new(
+        bond_account: Pubkey,
+        seller: Pubkey,
+        seller_token_account: Pubkey,
+        quote_mint: Pubkey,
+        price: u64,
+    ) -> Self {
+        Self {
+            tag: Tag::BondListing,
+            bond_account,
+            seller,
+            seller_token_account,
+            quote_mint,
+            price,
+        }
+    }
+
+    pub fn find_key(bond_account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[BondListing::SEED, &bond_account.to_bytes()];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<BondListing, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::BondListing as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = BondListing::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct BondAuction {
+    /// Tag
+    pub tag: Tag,
+
+    /// Authorized seller who created the auction
+    pub seller: Pubkey,
+
+    /// Stake pool in which the bonds are staked
+    pub stake_pool: Pubkey,
+
+    /// Mint of the token the bonds are priced in
+    pub quote_mint: Pubkey,
+
+    /// Token account receiving the sale proceeds
+    pub seller_token_account: Pubkey,
+
+    /// Amount of ACCESS tokens sold by the auction
+    pub total_amount: u64,
+
+    /// Amount of ACCESS tokens left to sell
+    pub remaining_amount: u64,
+
+    /// Price of `total_amount` tokens at `start_time`, in quote tokens
+    pub start_price: u64,
+
+    /// Price of `total_amount` tokens from `end_time` onwards, in quote tokens
+    pub floor_price: u64,
+
+    /// Date at which the auction opens
+    pub start_time: i64,
+
+    /// Date at which the price reaches the floor price
+    pub end_time: i64,
+
+    /// Unlock start date of the bonds
+    pub unlock_start_date: i64,
+
+    /// Unlock period of the bonds
+    pub unlock_period: i64,
+
+    /// Number of unlock periods over which the bond tokens unlock
+    pub unlock_periods: u64,
+
+    /// Vesting template of the bonds, the cliff amount is scaled to the size of each fill
+    pub vesting: VestingSchedule,
+
+    /// Number of fills of the auction, used to derive the bond account of the next fill
+    pub fill_count: u64,
+}
+
+#[allow(missing_docs)]
+impl BondAuction {
+    pub const SEED: &'static [u8; 12] = b"bond_auction";
+
+    pub fn find_key(
+        seller: &Pubkey,
+        stake_pool: &Pubkey,
+        start_time: i64,
+        program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[
+            BondAuction::SEED,
+            &seller.to_bytes(),
+            &stake_pool.to_bytes(),
+            &start_time.to_le_bytes(),
+        ];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<BondAuction, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::BondAuction as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = BondAuction::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    /// Price of `total_amount` tokens at `current_time`, decays linearly from the start price to the floor price
+    pub fn current_price(&self, current_time: i64) -> Result<u64, ProgramError> {
+        if current_time <= self.start_time {
+            return Ok(self.start_price);
+        }
+        if current_time >= self.end_time {
+            return Ok(self.floor_price);
+        }
+        // price = start_price - (start_price - floor_price) * elapsed / duration
+        let elapsed = (current_time - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        let decay = ((self.start_price - self.floor_price) as u128)
+            .checked_mul(elapsed)
+            .and_then(|d| d.checked_div(duration))
+            .ok_or(AccessError::Overflow)?;
+        Ok(self.start_price - decay as u64)
+    }
+
+    /// Quote amount paid for `amount` tokens at `current_time`, rounded up
+    pub fn quote_amount(&self, amount: u64, current_time: i64) -> Result<u64, ProgramError> {
+        let price = self.current_price(current_time)? as u128;
+        let total_amount = self.total_amount as u128;
+        price
+            .checked_mul(amount as u128)
+            .and_then(|q| q.checked_add(total_amount - 1))
+            .and_then(|q| q.checked_div(total_amount))
+            .and_then(|q| q.try_into().ok())
+            .ok_or_else(|| AccessError::Overflow.into())
+    }
+
+    /// Vesting schedule of a bond of `amount` tokens
+    pub fn vesting_for(&self, amount: u64) -> Result<VestingSchedule, ProgramError> {
+        let cliff_amount = (self.vesting.cliff_amount as u128)
+            .checked_mul(amount as u128)
+            .and_then(|c| c.checked_div(self.total_amount as u128))
+            .ok_or(AccessError::Overflow)? as u64;
+        Ok(VestingSchedule {
+            cliff_amount,
+            ..self.vesting
+        })
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct BondRound {
+    /// Tag
+    pub tag: Tag,
+
+    /// Root of the Merkle tree of the bonds, see [`BondRound::leaf`]
+    pub merkle_root: [u8; 32],
+
+    /// Stake pool in which the bonds are staked
+    pub stake_pool: Pubkey,
+
+    /// Mint of the token used to buy the bonds
+    pub quote_mint: Pubkey,
+
+    /// Token account receiving the sale proceeds
+    pub seller_token_account: Pubkey,
+
+    /// Unlock start date of the bonds
+    pub unlock_start_date: i64,
+
+    /// Unlock period of the bonds
+    pub unlock_period: i64,
+
+    /// Number of unlock periods over which the bond tokens unlock
+    pub unlock_periods: u64,
+
+    /// Sellers who signed the round
+    pub sellers: Vec<Pubkey>,
+
+    /// Number of leaves of the Merkle tree
+    pub num_nodes: u64,
+
+    /// One bit per leaf, set once the bond of the leaf has been claimed
+    pub claimed_bitmap: Vec<u8>,
+}
+
+#[allow(missing_docs)]
+impl BondRound {
+    pub const SEED: &'static [u8; 10] = b"bond_round";
+
+    pub fn find_key(merkle_root: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[BondRound::SEED, merkle_root];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Leaf of the Merkle tree for a bond of `total_amount_sold` tokens sold to `buyer` for `total_quote_amount` at position `index`
+    pub fn leaf(
+        index: u64,
+        buyer: &Pubkey,
+        total_amount_sold: u64,
+        total_quote_amount: u64,
+    ) -> [u8; 32] {
+        hashv(&[
+            &[0],
+            &index.to_le_bytes(),
+            &buyer.to_bytes(),
+            &total_amount_sold.to_le_bytes(),
+            &total_quote_amount.to_le_bytes(),
+        ])
+        .0
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<BondRound, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::BondRound as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = BondRound::deserialize(&mut data)?;
+        Ok(result)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct Distributor {
+    /// Tag
+    pub tag: Tag,
+
+    /// Root of the Merkle tree of the distribution, see [`Distributor::leaf`]
+    pub merkle_root: [u8; 32],
+
+    /// Maximum amount of tokens that can be claimed from the distributor
+    pub max_total_claim: u64,
+
+    /// Amount of tokens claimed so far
+    pub total_claimed: u64,
+
+    /// Number of leaves of the Merkle tree
+    pub num_nodes: u64,
+
+    /// One bit per leaf, set once the leaf has been claimed
+    pub claimed_bitmap: Vec<u8>,
+}
+
+#[allow(missing_docs)]
+impl Distributor {
+    pub const SEED: &'static [u8; 11] = b"distributor";
+
+    pub fn new(merkle_root: [u8; 32], max_total_claim: u64, num_nodes: u64) -> Self {
+        Self {
+            tag: Tag::Distributor,
+            merkle_root,
+            max_total_claim,
+            total_claimed: 0,
+            num_nodes,
+            claimed_bitmap: vec![0; ((num_nodes + 7) / 8) as usize],
+        }
+    }
+
+    pub fn find_key(merkle_root: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[Distributor::SEED, merkle_root];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Leaf of the Merkle tree for `amount` tokens distributed to `recipient` at position `index`
+    pub fn leaf(index: u64, recipient: &Pubkey, amount: u64) -> [u8; 32] {
+        hashv(&[
+            &[0],
+            &index.to_le_bytes(),
+            &recipient.to_bytes(),
+            &amount.to_le_bytes(),
+        ])
+        .0
+    }
+
+    pub fn is_claimed(&self, index: u64) -> bool {
+        self.claimed_bitmap[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u64) {
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<Distributor, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::Distributor as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = Distributor::deserialize(&mut data)?;
+        Ok(result)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct UserIndex {
+    /// Tag
+    pub tag: Tag,
+
+    /// Owner of the indexed positions
+    pub owner: Pubkey,
+
+    /// Stake accounts of the owner
+    pub stake_accounts: Vec<Pubkey>,
+
+    /// Bond accounts of the owner
+    pub bond_accounts: Vec<Pubkey>,
+}
+
+#[allow(missing_docs)]
+impl UserIndex {
+    pub const SEED: &'static [u8; 10] = b"user_index";
+
+    /// Size of the account, allocated once for the maximum number of positions
+    pub const LEN: usize = 1 + 32 + 4 + 4 + 32 * MAX_USER_INDEX_POSITIONS;
+
+    pub fn new(owner: Pubkey) -> Self {
+        Self {
+            tag: Tag::UserIndex,
+            owner,
+            stake_accounts: vec![],
+            bond_accounts: vec![],
+        }
+    }
+
+    pub fn find_key(owner: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[UserIndex::SEED, &owner.to_bytes()];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    fn check_capacity(&self) -> ProgramResult {
+        if self.stake_accounts.len() + self.bond_accounts.len() >= MAX_USER_INDEX_POSITIONS {
+            msg!(
+                "A user index can track at most {} positions",
+                MAX_USER_INDEX_POSITIONS
+            );
+            return Err(AccessError::TooManyIndexedPositions.into());
+        }
+        Ok(())
+    }
+
+    pub fn add_stake_account(&mut self, key: Pubkey) -> ProgramResult {
+        if !self.stake_accounts.contains(&key) {
+            self.check_capacity()?;
+            self.stake_accounts.push(key);
+        }
+        Ok(())
+    }
+
+    pub fn remove_stake_account(&mut self, key: &Pubkey) {
+        self.stake_accounts.retain(|k| k != key);
+    }
+
+    pub fn add_bond_account(&mut self, key: Pubkey) -> ProgramResult {
+        if !self.bond_accounts.contains(&key) {
+            self.check_capacity()?;
+            self.bond_accounts.push(key);
+        }
+        Ok(())
+    }
+
+    pub fn remove_bond_account(&mut self, key: &Pubkey) {
+        self.bond_accounts.retain(|k| k != key);
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<UserIndex, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::UserIndex as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = UserIndex::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+        self.stake_accounts = vec![];
+        self.bond_accounts = vec![];
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize, PartialEq, Clone, Copy, Debug)]
+#[repr(u8)]
+#[allow(missing_docs)]
+pub enum AdminAction {
+    Freeze,
+    Unfreeze,
+    Mint,
+    ChangeInflation,
+    ChangeCentralStateAuthority,
+    ActivateStakePool,
+    EditMetadata,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize, Clone, Debug)]
+#[allow(missing_docs)]
+pub struct AdminLogEntry {
+    /// Slot of the action
+    pub slot: u64,
+
+    /// The action taken by the authority
+    pub action: AdminAction,
+
+    /// The account the action applies to
+    pub target: Pubkey,
+
+    /// Keccak hash of the serialized parameters of the action
+    pub params_hash: [u8; 32],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct AdminLog {
+    /// Tag
+    pub tag: Tag,
+
+    /// Number of actions recorded since the creation of the log
+    pub total_entries: u64,
+
+    /// Ring buffer of the last `ADMIN_LOG_LEN` actions, the next one overwrites `total_entries % ADMIN_LOG_LEN`
+    pub entries: Vec<AdminLogEntry>,
+}
+
+#[allow(missing_docs)]
+impl AdminLog {
+    pub const SEED: &'static [u8; 9] = b"admin_log";
+
+    /// Size of the account, allocated once for the whole ring buffer
+    pub const LEN: usize = 1 + 8 + 4 + (8 + 1 + 32 + 32) * ADMIN_LOG_LEN;
+
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            tag: Tag::AdminLog,
+            total_entries: 0,
+            entries: vec![],
+        }
+    }
+
+    pub fn find_key(program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[AdminLog::SEED];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn push(&mut self, entry: AdminLogEntry) {
+        if self.entries.len() < ADMIN_LOG_LEN {
+            self.entries.push(entry);
+        } else {
+            self.entries[(self.total_entries % ADMIN_LOG_LEN as u64) as usize] = entry;
+        }
+        self.total_entries = self.total_entries.saturating_add(1);
+    }
+
+    /// The recorded entries from the oldest to the most recent
+    pub fn entries_in_order(&self) -> Vec<&AdminLogEntry> {
+        let start = if self.entries.len() < ADMIN_LOG_LEN {
+            0
+        } else {
+            (self.total_entries % ADMIN_LOG_LEN as u64) as usize
+        };
+        self.entries[start..]
+            .iter()
+            .chain(self.entries[..start].iter())
+            .collect()
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<AdminLog, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::AdminLog as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = AdminLog::deserialize(&mut data)?;
+        Ok(result)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, BorshSize)]
+#[allow(missing_docs)]
+pub struct FreezeRecord {
+    /// Tag
+    pub tag: Tag,
+
+    /// The frozen account
+    pub account: Pubkey,
+
+    /// Reason code chosen by the central state authority
+    pub reason: u8,
+
+    /// Freeze timestamp
+    pub frozen_at: i64,
+
+    /// Timestamp after which anyone can unfreeze the account, 0 if the freeze never expires
+    pub expiry: i64,
+
+    /// The account that paid for the record and gets the lamports back when the freeze expires
+    pub fee_payer: Pubkey,
+}
+
+#[allow(missing_docs)]
+impl FreezeRecord {
+    pub const SEED: &'static [u8; 13] = b"freeze_record";
+
+    pub fn new(
+        account: Pubkey,
+        reason: u8,
+        frozen_at: i64,
+        expiry: i64,
+        fee_payer: Pubkey,
+    ) -> Self {
+        Self {
+            tag: Tag::FreezeRecord,
+            account,
+            reason,
+            frozen_at,
+            expiry,
+            fee_payer,
+        }
+    }
+
+    pub fn find_key(account: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]] = &[FreezeRecord::SEED, &account.to_bytes()];
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expiry != 0 && now >= self.expiry
+    }
+
+    pub fn save(&self, mut dst: &mut [u8]) -> ProgramResult {
+        self.serialize(&mut dst)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    pub fn from_account_info(a: &AccountInfo) -> Result<FreezeRecord, ProgramError> {
+        let mut data = &a.data.borrow() as &[u8];
+        if data[0] != Tag::FreezeRecord as u8 {
+            return Err(AccessError::DataTypeMismatch.into());
+        }
+        let result = FreezeRecord::deserialize(&mut data)?;
+        Ok(result)
+    }
+
+    pub fn close(&mut self) {
+        self.tag = Tag::Deleted;
+    }
 }
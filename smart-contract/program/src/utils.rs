@@ -1,11 +1,28 @@
 use crate::error::AccessError;
-use crate::state::{BondAccount, AUTHORIZED_BOND_SELLERS};
-use crate::state::{StakeAccount, StakePoolRef, ACCESS_MINT, STAKE_BUFFER_LEN};
+use crate::state::{AdminAction, AdminLog, AdminLogEntry};
+use crate::state::{BondAccount, CentralState, PoolMembership, AUTHORIZED_BOND_SELLERS};
+use crate::state::{CampaignPosition, RewardCampaign};
+use crate::state::{StakeAccount, StakePoolRef, UserIndex, ACCESS_MINT, STAKE_BUFFER_LEN};
+use bonfida_utils::fp_math::safe_downcast;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    program_pack::Pack, pubkey::Pubkey,
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint::ProgramResult,
+    keccak::hashv,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        StateWithExtensions,
+    },
+    instruction::mint_to,
+    state::{Account, Mint},
 };
-use spl_token::state::Account;
 
 /// Cumulate the claimable rewards from the last claimed day to the present.
 /// Result is in FP32 format.
@@ -82,6 +99,243 @@ pub fn check_signer(account: &AccountInfo, error: AccessError) -> ProgramResult
     Ok(())
 }
 
+/// Returns the next account when the instruction parameters say it is passed
+pub fn next_optional_account_info<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    iter: &mut I,
+    is_passed: bool,
+) -> Result<Option<I::Item>, ProgramError> {
+    if is_passed {
+        next_account_info(iter).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Checks that the account is either the SPL token program or the Token-2022 program
+pub fn check_token_program(account: &AccountInfo) -> ProgramResult {
+    if account.key != &spl_token::ID && account.key != &spl_token_2022::ID {
+        msg!("Unsupported token program {}", account.key);
+        return Err(AccessError::WrongSplTokenProgramId.into());
+    }
+    Ok(())
+}
+
+/// Checks that a mint or a token account is owned by either token program
+pub fn check_token_program_owner(account: &AccountInfo, error: AccessError) -> ProgramResult {
+    if account.owner != &spl_token::ID && account.owner != &spl_token_2022::ID {
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+/// Unpacks a token account of either token program, extensions are ignored
+pub fn unpack_token_account(account: &AccountInfo) -> Result<Account, ProgramError> {
+    let data = account.data.borrow();
+    Ok(StateWithExtensions::<Account>::unpack(&data)?.base)
+}
+
+/// Returns the transfer fee of the current epoch when the mint has the transfer fee extension
+fn get_transfer_fee(mint: &AccountInfo) -> Result<Option<TransferFee>, ProgramError> {
+    let data = mint.data.borrow();
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    match mint.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(Some(*config.get_epoch_fee(Clock::get()?.epoch))),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Returns the amount received by the destination of a transfer of `amount` tokens once the
+/// transfer fee of the mint is withheld
+pub fn amount_after_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64, ProgramError> {
+    let transfer_fee = match get_transfer_fee(mint)? {
+        Some(transfer_fee) => transfer_fee,
+        None => return Ok(amount),
+    };
+    let fee = transfer_fee
+        .calculate_fee(amount)
+        .ok_or(AccessError::Overflow)?;
+    Ok(amount - fee)
+}
+
+/// Returns the amount to send so that the destination receives exactly `amount` once the transfer fee
+/// of the mint is withheld
+pub fn amount_with_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64, ProgramError> {
+    let transfer_fee = match get_transfer_fee(mint)? {
+        Some(transfer_fee) => transfer_fee,
+        None => return Ok(amount),
+    };
+    let basis_points = u16::from(transfer_fee.transfer_fee_basis_points) as u128;
+    if basis_points == 0 {
+        return Ok(amount);
+    }
+    let maximum_fee = u64::from(transfer_fee.maximum_fee);
+    let mut pre_fee_amount = if basis_points >= 10_000 {
+        amount.checked_add(maximum_fee)
+    } else {
+        (amount as u128)
+            .checked_mul(10_000)
+            .map(|a| a / (10_000 - basis_points))
+            .and_then(safe_downcast)
+            .map(|a| std::cmp::min(a, amount.saturating_add(maximum_fee)))
+    }
+    .ok_or(AccessError::Overflow)?;
+    // The fee is rounded up, the net amount can be short by a few tokens
+    loop {
+        let fee = transfer_fee
+            .calculate_fee(pre_fee_amount)
+            .ok_or(AccessError::Overflow)?;
+        if pre_fee_amount.saturating_sub(fee) >= amount {
+            return Ok(pre_fee_amount);
+        }
+        pre_fee_amount = pre_fee_amount.checked_add(1).ok_or(AccessError::Overflow)?;
+    }
+}
+
+/// Transfers tokens with `transfer_checked`, which is required by Token-2022 mints with extensions
+pub fn transfer_checked<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.data.borrow())?
+        .base
+        .decimals;
+    let transfer_ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        source.key,
+        mint.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    invoke_signed(
+        &transfer_ix,
+        &[
+            token_program.clone(),
+            source.clone(),
+            mint.clone(),
+            destination.clone(),
+            authority.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Transfers tokens with `transfer_checked` when the mint is passed, legacy clients that don't pass
+/// the mint can only move SPL tokens
+pub fn transfer_tokens<'a>(
+    token_program: &AccountInfo<'a>,
+    source: &AccountInfo<'a>,
+    mint: Option<&AccountInfo<'a>>,
+    destination: &AccountInfo<'a>,
+    authority: &AccountInfo<'a>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    if let Some(mint) = mint {
+        return transfer_checked(
+            token_program,
+            source,
+            mint,
+            destination,
+            authority,
+            amount,
+            signer_seeds,
+        );
+    }
+    if token_program.key != &spl_token::ID {
+        msg!("The mint must be passed to transfer Token-2022 tokens");
+        return Err(AccessError::WrongSplTokenProgramId.into());
+    }
+    let transfer_ix = spl_token::instruction::transfer(
+        token_program.key,
+        source.key,
+        destination.key,
+        authority.key,
+        &[],
+        amount,
+    )?;
+    invoke_signed(
+        &transfer_ix,
+        &[
+            token_program.clone(),
+            source.clone(),
+            destination.clone(),
+            authority.clone(),
+        ],
+        signer_seeds,
+    )
+}
+
+/// Thaws and burns the subscription receipt of a stake account, the central state was approved as
+/// delegate of the receipt token account when the receipt was minted
+pub fn burn_receipt_tokens<'a>(
+    program_id: &Pubkey,
+    central_state_account: &AccountInfo<'a>,
+    central_state: &CentralState,
+    stake_account_key: &Pubkey,
+    receipt_mint: &AccountInfo<'a>,
+    receipt_token: &AccountInfo<'a>,
+    spl_token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    check_account_key(
+        spl_token_program,
+        &spl_token::ID,
+        AccessError::WrongSplTokenProgramId,
+    )?;
+    check_account_owner(
+        receipt_token,
+        &spl_token::ID,
+        AccessError::WrongTokenAccountOwner,
+    )?;
+    let (receipt_mint_key, _) = StakeAccount::find_receipt_mint_key(stake_account_key, program_id);
+    check_account_key(
+        receipt_mint,
+        &receipt_mint_key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    let receipt_token_acc = unpack_token_account(receipt_token)?;
+    if receipt_token_acc.mint != receipt_mint_key {
+        return Err(AccessError::WrongMint.into());
+    }
+    if receipt_token_acc.amount == 0 {
+        return Err(AccessError::NoOp.into());
+    }
+
+    let central_state_seeds: &[&[u8]] = &[&program_id.to_bytes(), &[central_state.signer_nonce]];
+    let account_infos = &[
+        spl_token_program.clone(),
+        receipt_token.clone(),
+        receipt_mint.clone(),
+        central_state_account.clone(),
+    ];
+
+    let ix = spl_token::instruction::thaw_account(
+        &spl_token::ID,
+        receipt_token.key,
+        &receipt_mint_key,
+        central_state_account.key,
+        &[],
+    )?;
+    invoke_signed(&ix, account_infos, &[central_state_seeds])?;
+
+    let ix = spl_token::instruction::burn(
+        &spl_token::ID,
+        receipt_token.key,
+        &receipt_mint_key,
+        central_state_account.key,
+        &[],
+        receipt_token_acc.amount,
+    )?;
+    invoke_signed(&ix, account_infos, &[central_state_seeds])
+}
+
 pub fn assert_empty_stake_pool(stake_pool: &StakePoolRef) -> ProgramResult {
     if stake_pool.header.total_staked != 0 {
         msg!("The stake pool must be empty");
@@ -95,11 +349,20 @@ pub fn assert_empty_stake_account(stake_account: &StakeAccount) -> ProgramResult
         msg!("The stake account must be empty");
         return Err(AccessError::StakeAccountMustBeEmpty.into());
     }
+    if stake_account.campaign_positions != 0 {
+        msg!("The campaign rewards must be claimed first");
+        return Err(AccessError::StakeAccountMustBeEmpty.into());
+    }
+    if stake_account.has_receipt {
+        msg!("The subscription receipt must be burnt first");
+        return Err(AccessError::ReceiptNotBurnt.into());
+    }
     Ok(())
 }
 
 pub fn assert_valid_vault(account: &AccountInfo, vault_signer: &Pubkey) -> ProgramResult {
-    let acc = Account::unpack(&account.data.borrow())?;
+    check_token_program_owner(account, AccessError::WrongTokenAccountOwner)?;
+    let acc = unpack_token_account(account)?;
     if &acc.owner != vault_signer {
         msg!("The vault account should be owned by the stake pool signer");
         return Err(ProgramError::InvalidArgument);
@@ -116,6 +379,25 @@ pub fn assert_valid_vault(account: &AccountInfo, vault_signer: &Pubkey) -> Progr
     Ok(())
 }
 
+pub fn assert_valid_campaign_vault(
+    account: &AccountInfo,
+    campaign: &Pubkey,
+    mint: &Pubkey,
+) -> ProgramResult {
+    check_token_program_owner(account, AccessError::WrongTokenAccountOwner)?;
+    let acc = unpack_token_account(account)?;
+    if &acc.owner != campaign {
+        msg!("The vault account should be owned by the reward campaign");
+        return Err(ProgramError::InvalidArgument);
+    }
+    if &acc.mint != mint {
+        msg!("The vault account should hold the reward token");
+        return Err(AccessError::WrongMint.into());
+    }
+    assert_no_close_or_delegate(&acc)?;
+    Ok(())
+}
+
 pub fn assert_uninitialized(account: &AccountInfo) -> ProgramResult {
     if !account.data_is_empty() {
         return Err(ProgramError::AccountAlreadyInitialized);
@@ -145,8 +427,8 @@ pub fn assert_bond_derivation(
 }
 
 pub fn assert_valid_fee(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
-    check_account_owner(account, &spl_token::ID, AccessError::WrongOwner)?;
-    let acc = Account::unpack(&account.data.borrow())?;
+    check_token_program_owner(account, AccessError::WrongOwner)?;
+    let acc = unpack_token_account(account)?;
     if &acc.owner != owner {
         msg!("Invalid fee account owner");
         return Err(ProgramError::IllegalOwner);
@@ -162,3 +444,296 @@ pub fn assert_no_close_or_delegate(token_account: &Account) -> ProgramResult {
     }
     Ok(())
 }
+
+/// Only the members of an allowlisted pool can stake in it
+pub fn assert_pool_member(
+    stake_pool: &StakePoolRef,
+    stake_pool_key: &Pubkey,
+    member: &Pubkey,
+    pool_membership: Option<&AccountInfo>,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    if stake_pool.header.allowlisted == 0 {
+        return Ok(());
+    }
+    let pool_membership = pool_membership.ok_or(AccessError::NotAllowlisted)?;
+    check_account_owner(pool_membership, program_id, AccessError::NotAllowlisted)?;
+    let (key, _nonce) = PoolMembership::find_key(stake_pool_key, member, program_id);
+    check_account_key(pool_membership, &key, AccessError::AccountNotDeterministic)?;
+    PoolMembership::from_account_info(pool_membership).map_err(|_| AccessError::NotAllowlisted)?;
+    Ok(())
+}
+
+/// Applies `update` to the user index of `owner` when it is passed to the instruction
+pub fn update_user_index<F>(
+    user_index: Option<&AccountInfo>,
+    owner: &Pubkey,
+    program_id: &Pubkey,
+    update: F,
+) -> ProgramResult
+where
+    F: FnOnce(&mut UserIndex) -> ProgramResult,
+{
+    let user_index_account = match user_index {
+        Some(a) => a,
+        None => return Ok(()),
+    };
+    check_account_owner(user_index_account, program_id, AccessError::WrongOwner)?;
+    let (key, _nonce) = UserIndex::find_key(owner, program_id);
+    check_account_key(
+        user_index_account,
+        &key,
+        AccessError::AccountNotDeterministic,
+    )?;
+    let mut index = UserIndex::from_account_info(user_index_account)?;
+    update(&mut index)?;
+    index.save(&mut user_index_account.data.borrow_mut())
+}
+
+/// Moves a bond from the user index of `from` to the user index of `to`, for each of the two indexes passed to the instruction
+pub fn transfer_user_index_bond(
+    user_indexes: &[AccountInfo],
+    bond_account: &Pubkey,
+    from: &Pubkey,
+    to: &Pubkey,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    if user_indexes.len() > 2 {
+        msg!("At most the user indexes of the previous and new owners can be passed");
+        return Err(ProgramError::InvalidArgument);
+    }
+    let (from_key, _nonce) = UserIndex::find_key(from, program_id);
+    for user_index in user_indexes {
+        if user_index.key == &from_key {
+            update_user_index(Some(user_index), from, program_id, |index| {
+                index.remove_bond_account(bond_account);
+                Ok(())
+            })?;
+        } else {
+            update_user_index(Some(user_index), to, program_id, |index| {
+                index.add_bond_account(*bond_account)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Checkpoints the campaign positions of a stake account when its stake changes, every position
+/// of the account must be passed as a (reward campaign, campaign position) pair
+pub fn checkpoint_campaign_positions(
+    program_id: &Pubkey,
+    campaign_accounts: &[AccountInfo],
+    stake_account_key: &Pubkey,
+    stake_account: &StakeAccount,
+) -> ProgramResult {
+    if campaign_accounts.len() != 2 * stake_account.campaign_positions as usize {
+        msg!(
+            "Expected {} campaign positions",
+            stake_account.campaign_positions
+        );
+        return Err(AccessError::MissingCampaignPositions.into());
+    }
+    let current_time = Clock::get()?.unix_timestamp;
+    for (i, pair) in campaign_accounts.chunks(2).enumerate() {
+        let (campaign_account, position_account) = (&pair[0], &pair[1]);
+        check_account_owner(campaign_account, program_id, AccessError::WrongOwner)?;
+        check_account_owner(position_account, program_id, AccessError::WrongOwner)?;
+        if campaign_accounts[..2 * i]
+            .chunks(2)
+            .any(|p| p[1].key == position_account.key)
+        {
+            return Err(AccessError::MissingCampaignPositions.into());
+        }
+
+        let mut campaign = RewardCampaign::from_account_info(campaign_account)?;
+        let mut position = CampaignPosition::from_account_info(position_account)?;
+        if &position.campaign != campaign_account.key
+            || &position.stake_account != stake_account_key
+        {
+            return Err(AccessError::WrongCampaignPosition.into());
+        }
+
+        campaign.update_accumulator(current_time)?;
+        position.checkpoint(campaign.reward_per_token_fp32)?;
+        campaign.update_registered_stake(position.stake_amount, stake_account.stake_amount)?;
+        position.stake_amount = stake_account.stake_amount;
+
+        campaign.save(&mut campaign_account.data.borrow_mut())?;
+        position.save(&mut position_account.data.borrow_mut())?;
+    }
+    Ok(())
+}
+
+/// Appends an admin action to the admin log, the log is required once it has been created
+pub fn log_admin_action(
+    program_id: &Pubkey,
+    admin_log: Option<&AccountInfo>,
+    central_state: &CentralState,
+    action: AdminAction,
+    target: &Pubkey,
+    params: &[u8],
+) -> ProgramResult {
+    if central_state.admin_log == Pubkey::default() {
+        // The log has not been created yet, its slot can only hold the admin log address
+        if let Some(admin_log_account) = admin_log {
+            let (admin_log_key, _) = AdminLog::find_key(program_id);
+            check_account_key(
+                admin_log_account,
+                &admin_log_key,
+                AccessError::WrongAdminLog,
+            )?;
+        }
+        return Ok(());
+    }
+    let admin_log_account = admin_log.ok_or_else(|| {
+        msg!("The admin log must be passed to admin instructions");
+        ProgramError::NotEnoughAccountKeys
+    })?;
+    check_account_key(
+        admin_log_account,
+        &central_state.admin_log,
+        AccessError::WrongAdminLog,
+    )?;
+    let mut log = AdminLog::from_account_info(admin_log_account)?;
+    log.push(AdminLogEntry {
+        slot: Clock::get()?.slot,
+        action,
+        target: *target,
+        params_hash: hashv(&[params]).0,
+    });
+    log.save(&mut admin_log_account.data.borrow_mut())
+}
+
+/// Mints the pending staking rewards of a bond to its owner, before the bond changes hands
+#[allow(clippy::too_many_arguments)]
+pub fn settle_bond_rewards<'a>(
+    program_id: &Pubkey,
+    bond: &mut BondAccount,
+    stake_pool: &mut StakePoolRef,
+    central_state: &CentralState,
+    central_state_account: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    rewards_destination: &AccountInfo<'a>,
+    spl_token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    check_account_key(mint, &central_state.token_mint, AccessError::WrongMint)?;
+    check_account_owner(
+        rewards_destination,
+        spl_token_program.key,
+        AccessError::WrongOwner,
+    )?;
+    let destination_token_acc = unpack_token_account(rewards_destination)?;
+    if destination_token_acc.mint != central_state.token_mint {
+        return Err(AccessError::WrongMint.into());
+    }
+    if destination_token_acc.owner != bond.owner {
+        msg!("The rewards must be settled to the bond owner");
+        return Err(AccessError::WrongOwner.into());
+    }
+    assert_no_close_or_delegate(&destination_token_acc)?;
+
+    let reward = if cfg!(feature = "claim-bond-rewards-off") {
+        0
+    } else {
+        calc_reward_fp32(
+            central_state.last_snapshot_offset,
+            bond.last_claimed_offset,
+            stake_pool,
+            true,
+            true,
+        )?
+        .checked_mul(bond.total_staked as u128)
+        .map(|r| ((r >> 31) + 1) >> 1)
+        .and_then(safe_downcast)
+        .ok_or(AccessError::Overflow)?
+    };
+
+    msg!("Settling bond rewards {}", reward);
+
+    if reward > 0 {
+        let transfer_ix = mint_to(
+            spl_token_program.key,
+            mint.key,
+            rewards_destination.key,
+            central_state_account.key,
+            &[],
+            reward,
+        )?;
+        invoke_signed(
+            &transfer_ix,
+            &[
+                spl_token_program.clone(),
+                mint.clone(),
+                central_state_account.clone(),
+                rewards_destination.clone(),
+            ],
+            &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+        )?;
+    }
+
+    stake_pool.header.record_staker_rewards(reward);
+    bond.last_claimed_offset = central_state.last_snapshot_offset;
+
+    Ok(())
+}
+
+/// Activates a bond and mints its tokens into the vault of its stake pool
+#[allow(clippy::too_many_arguments)]
+pub fn activate_bond<'a>(
+    program_id: &Pubkey,
+    bond: &mut BondAccount,
+    bond_account: &AccountInfo<'a>,
+    stake_pool: &mut StakePoolRef,
+    central_state: &mut CentralState,
+    central_state_account: &AccountInfo<'a>,
+    access_mint: &AccountInfo<'a>,
+    pool_vault: &AccountInfo<'a>,
+    spl_token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    bond.activate(central_state.last_snapshot_offset)?;
+    bond.save(&mut bond_account.data.borrow_mut())?;
+
+    // Mint ACCESS tokens into the pool vault
+    let mint_ix = mint_to(
+        spl_token_program.key,
+        access_mint.key,
+        pool_vault.key,
+        central_state_account.key,
+        &[],
+        bond.total_amount_sold,
+    )?;
+
+    invoke_signed(
+        &mint_ix,
+        &[
+            spl_token_program.clone(),
+            access_mint.clone(),
+            pool_vault.clone(),
+            central_state_account.clone(),
+        ],
+        &[&[&program_id.to_bytes(), &[central_state.signer_nonce]]],
+    )?;
+
+    stake_pool.header.deposit(bond.total_amount_sold)?;
+    stake_pool.header.add_bond_holder();
+
+    // Update central state
+    central_state.deposit(bond.total_amount_sold, stake_pool.header.pool_weight)?;
+    central_state.save(&mut central_state_account.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Returns `true` if `leaf` belongs to the Merkle tree of root `root`
+///
+/// Pairs of nodes are hashed in sorted order, a proof is the list of the siblings from the leaf to the root
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_root = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&[1], &node, sibling]).0
+        } else {
+            hashv(&[&[1], sibling, &node]).0
+        }
+    });
+    &computed_root == root
+}
@@ -1,13 +1,17 @@
 use std::error::Error;
+use std::mem::size_of;
 
 use borsh::BorshDeserialize;
+use num_traits::FromPrimitive;
 use solana_program::{pubkey::Pubkey, system_program};
 
 use solana_program_test::{processor, ProgramTest};
+use solana_sdk::account::{AccountSharedData, WritableAccount};
 use solana_sdk::signer::{keypair::Keypair, Signer};
 use solana_sdk::sysvar::clock;
 use solana_test_framework::*;
-use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account};
+use spl_associated_token_account::{get_associated_token_address, get_associated_token_address_with_program_id, instruction::create_associated_token_account};
+use spl_token_2022::extension::StateWithExtensions;
 
 use access_protocol::{
     entrypoint::process_instruction,
@@ -18,10 +22,10 @@ use access_protocol::{
         create_stake_pool, stake, unstake,
     },
 };
-use access_protocol::instruction::{change_central_state_authority, change_inflation, change_pool_minimum, change_pool_multiplier, claim_bond, claim_bond_rewards, create_bond, unlock_bond_tokens};
-use access_protocol::state::{BondAccount, CentralState, StakeAccount, StakePoolHeader, Tag};
+use access_protocol::instruction::{add_pool_member, admin_freeze, change_activation_deposit, change_central_state_authority, change_crank_bounty, change_early_unlock_penalty, change_inflation, change_pool_minimum, change_pool_multiplier, change_referral_rates, cancel_bond, cancel_bond_listing, claim_bond, claim_bond_rewards, claim_campaign_rewards, claim_distribution, claim_multiple_rewards, claim_round_bond, close_access_delegation, close_stake_account, close_stake_pool, close_user_index, create_access_delegation, create_admin_log, create_bond, create_bond_auction, create_bond_round, create_distributor, create_reward_campaign, create_user_index, deprecate_stake_pool, early_unlock_bond, edit_access_delegation, expire_freeze, extend_reward_campaign, fill_bond_auction, get_claimable_rewards, has_access, burn_receipt, mint_receipt, fill_bond_listing, fund_reward_campaign, list_bond, migrate_account, move_bond_stake, reclaim_campaign_rewards, remove_pool_member, self_activate_stake_pool, set_pool_allowlist, set_pool_weight, sign_bond_round, transfer_bond, unlock_bond_tokens};
+use access_protocol::state::{AccessDelegation, AdminLog, BondAccount, BondAuction, BondListing, BondRound, CampaignPosition, CentralState, Distributor, FreezeRecord, UserIndex, PoolMembership, RewardCampaign, StakeAccount, StakePoolHeader, Tag, VestingSchedule};
 
-use crate::common::utils::{mint_bootstrap, sign_send_instructions};
+use crate::common::utils::{mint_bootstrap, mint_bootstrap_with_transfer_fee, sign_send_instructions, simulate_instruction};
 
 pub struct TestRunner {
     pub program_id: Pubkey,
@@ -30,9 +34,19 @@ pub struct TestRunner {
     authority_ata: Pubkey,
     central_state: Pubkey,
     mint : Pubkey,
+    // the token program of the ACCESS mint
+    token_program: Pubkey,
     // hashmap from user pubkey to a bond account
     bond_accounts: std::collections::HashMap<String, Pubkey>,
     bond_seller: Keypair,
+    // hashmap from pool + member pubkeys to a pool membership
+    pool_memberships: std::collections::HashMap<String, Pubkey>,
+    // hashmap from pool + staker pubkeys to the referrer of the staker
+    referrers: std::collections::HashMap<String, Pubkey>,
+    // hashmap from owner pubkey to the user index of the owner
+    user_indexes: std::collections::HashMap<String, Pubkey>,
+    // the admin log once it has been created
+    admin_log: Option<Pubkey>,
 }
 
 pub struct StakerStats {
@@ -57,6 +71,15 @@ pub struct CentralStateStats {
 
 impl TestRunner {
     pub async fn new(daily_inflation: u64) -> Result<Self, BanksClientError> {
+        Self::new_with_token_program(daily_inflation, spl_token::ID, 0).await
+    }
+
+    // Sets up a Token-2022 ACCESS mint with a transfer fee
+    pub async fn new_with_transfer_fee(daily_inflation: u64, transfer_fee_basis_points: u16) -> Result<Self, BanksClientError> {
+        Self::new_with_token_program(daily_inflation, spl_token_2022::ID, transfer_fee_basis_points).await
+    }
+
+    async fn new_with_token_program(daily_inflation: u64, token_program: Pubkey, transfer_fee_basis_points: u16) -> Result<Self, BanksClientError> {
         // Create program and test environment
         let program_id = access_protocol::ID;
 
@@ -71,6 +94,18 @@ impl TestRunner {
         println!("added access_protocol::ID {:?}", access_protocol::ID);
 
         program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+        if token_program == spl_token_2022::ID {
+            program_test.add_program(
+                "spl_token_2022",
+                spl_token_2022::ID,
+                processor!(spl_token_2022::processor::Processor::process),
+            );
+            program_test.add_program(
+                "spl_associated_token_account",
+                spl_associated_token_account::ID,
+                processor!(spl_associated_token_account::processor::process_instruction),
+            );
+        }
 
         //
         // Derive central vault
@@ -81,7 +116,11 @@ impl TestRunner {
         //
         // Create mint
         //
-        let (mint, _) = mint_bootstrap(Some("acsT7dFjiyevrBbvpsD7Vqcwj1QN96fbWKdq49wcdWZ"), 6, &mut program_test, &central_state);
+        let mint = if token_program == spl_token_2022::ID {
+            mint_bootstrap_with_transfer_fee(Some("acsT7dFjiyevrBbvpsD7Vqcwj1QN96fbWKdq49wcdWZ"), 6, &mut program_test, &central_state, transfer_fee_basis_points)
+        } else {
+            mint_bootstrap(Some("acsT7dFjiyevrBbvpsD7Vqcwj1QN96fbWKdq49wcdWZ"), 6, &mut program_test, &central_state).0
+        };
 
         ////
         // Create test context
@@ -116,11 +155,11 @@ impl TestRunner {
             &prg_test_ctx.payer.pubkey(),
             &prg_test_ctx.payer.pubkey(),
             &mint,
-            &spl_token::ID,
+            &token_program,
         );
         sign_send_instructions(&mut prg_test_ctx, vec![ix], vec![])
             .await?;
-        let authority_ata = get_associated_token_address(&prg_test_ctx.payer.pubkey(), &mint);
+        let authority_ata = get_associated_token_address_with_program_id(&prg_test_ctx.payer.pubkey(), &mint, &token_program);
 
         // Create bond seller
         let bond_seller = Keypair::new();
@@ -128,7 +167,7 @@ impl TestRunner {
             &prg_test_ctx.payer.pubkey(),
             &bond_seller.pubkey(),
             &mint,
-            &spl_token::ID,
+            &token_program,
         );
         sign_send_instructions(
             &mut prg_test_ctx,
@@ -144,8 +183,13 @@ impl TestRunner {
             authority_ata,
             central_state,
             mint,
+            token_program,
             bond_accounts: std::collections::HashMap::new(),
             bond_seller,
+            pool_memberships: std::collections::HashMap::new(),
+            referrers: std::collections::HashMap::new(),
+            user_indexes: std::collections::HashMap::new(),
+            admin_log: None,
         })
     }
 
@@ -155,7 +199,7 @@ impl TestRunner {
             &self.prg_test_ctx.payer.pubkey(),
             &owner.pubkey(),
             &self.mint,
-            &spl_token::ID,
+            &self.token_program,
         );
         sign_send_instructions(
             &mut self.prg_test_ctx,
@@ -167,7 +211,7 @@ impl TestRunner {
     }
 
     pub async fn mint(&mut self, destination: &Pubkey, amount: u64) -> Result<(), BanksClientError> {
-        let destination_ata = get_associated_token_address(destination, &self.mint);
+        let destination_ata = self.get_ata(destination);
         let admin_mint_ix = admin_mint(
             self.program_id,
             admin_mint::Accounts {
@@ -175,7 +219,8 @@ impl TestRunner {
                 mint: &self.mint,
                 access_token_destination: &destination_ata,
                 central_state: &self.central_state,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
+                admin_log: self.admin_log.as_ref(),
             },
             admin_mint::Params {
                 amount,
@@ -188,13 +233,13 @@ impl TestRunner {
     pub async fn create_stake_pool(&mut self, stake_pool_owner: &Pubkey, minimum_stake_amount: u64) -> Result<(), BanksClientError>  {
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
         let create_associated_instruction =
-            create_associated_token_account(&self.prg_test_ctx.payer.pubkey(), &stake_pool_key, &self.mint, &spl_token::ID);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
+            create_associated_token_account(&self.prg_test_ctx.payer.pubkey(), &stake_pool_key, &self.mint, &self.token_program);
+        let pool_vault = self.get_ata(&stake_pool_key);
         let create_ata_pool_vault_ix = create_associated_token_account(
             &self.prg_test_ctx.payer.pubkey(),
             &stake_pool_key,
             &self.mint,
-            &spl_token::ID,
+            &self.token_program,
         );
         sign_send_instructions(
             &mut self.prg_test_ctx,
@@ -234,6 +279,7 @@ impl TestRunner {
                 authority: &self.prg_test_ctx.payer.pubkey(),
                 stake_pool: &stake_pool_key,
                 central_state: &self.central_state,
+                admin_log: self.admin_log.as_ref(),
             },
             activate_stake_pool::Params {},
         );
@@ -242,6 +288,28 @@ impl TestRunner {
             .await
     }
 
+    pub async fn self_activate_stake_pool(&mut self, stake_pool_owner: &Keypair) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let owner_token_acc = self.get_ata(&stake_pool_owner.pubkey());
+        let self_activate_stake_pool_ix = self_activate_stake_pool(
+            self.program_id,
+            self_activate_stake_pool::Accounts {
+                stake_pool: &stake_pool_key,
+                owner: &stake_pool_owner.pubkey(),
+                source_token: &owner_token_acc,
+                pool_vault: &pool_vault,
+                central_state: &self.central_state,
+                spl_token_program: &self.token_program,
+                mint: &self.mint,
+            },
+            self_activate_stake_pool::Params {},
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![self_activate_stake_pool_ix], vec![stake_pool_owner])
+            .await
+    }
+
     pub fn get_stake_account_pda(&mut self, stake_pool_key: &Pubkey, staker_key: &Pubkey) -> (Pubkey, u8) {
         let (stake_acc_key, stake_nonce) = Pubkey::find_program_address(
             &[
@@ -268,6 +336,11 @@ impl TestRunner {
     pub async fn create_stake_account(&mut self, stake_pool_owner_key: &Pubkey, staker_key: &Pubkey) -> Result<(), BanksClientError>  {
         let stake_pool_key = self.get_pool_pda(stake_pool_owner_key);
         let (stake_acc_key, stake_nonce) = self.get_stake_account_pda(&stake_pool_key, staker_key);
+        let user_index = self.user_indexes.get(staker_key.to_string().as_str());
+        // The pool membership slot must be filled to pass the user index
+        let pool_membership = self.pool_memberships
+            .get((stake_pool_owner_key.to_string() + &staker_key.to_string()).as_str())
+            .or_else(|| user_index.map(|_| &stake_pool_key));
         let create_stake_account_ix = create_stake_account(
             self.program_id,
             create_stake_account::Accounts {
@@ -275,6 +348,8 @@ impl TestRunner {
                 system_program: &system_program::ID,
                 fee_payer: &self.prg_test_ctx.payer.pubkey(),
                 stake_pool: &stake_pool_key,
+                pool_membership,
+                user_index,
             },
             create_stake_account::Params {
                 nonce: stake_nonce,
@@ -295,10 +370,15 @@ impl TestRunner {
         let staker_key = staker.pubkey();
         let stake_pool_key = self.get_pool_pda(stake_pool_owner_key);
         let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker_key);
-        let staker_token_acc = get_associated_token_address(&staker_key, &self.mint);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
+        let staker_token_acc = self.get_ata(&staker_key);
+        let pool_vault = self.get_ata(&stake_pool_key);
         // get the staker's bond from the hash map if it exists
-        let staker_bond: Option<&Pubkey> = self.bond_accounts.get((stake_pool_owner_key.to_string() + &staker_key.to_string()).as_str());
+        let staker_bond: Option<Pubkey> = self.bond_accounts.get((stake_pool_owner_key.to_string() + &staker_key.to_string()).as_str()).copied();
+        let referrer_token_acc = self.referrers
+            .get((stake_pool_owner_key.to_string() + &staker_key.to_string()).as_str())
+            .map(|referrer| self.get_ata(referrer));
+        let pool_membership = self.pool_memberships.get((stake_pool_owner_key.to_string() + &staker_key.to_string()).as_str()).copied();
+        let campaign_positions = self.campaign_position_accounts(stake_pool_owner_key, &staker_key).await;
 
         let stake_ix = stake(
             self.program_id,
@@ -307,16 +387,63 @@ impl TestRunner {
                 stake_pool: &stake_pool_key,
                 owner: &staker_key,
                 source_token: &staker_token_acc,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
+                vault: &pool_vault,
+                central_state_account: &self.central_state,
+                fee_account: &self.authority_ata,
+                bond_account: staker_bond.as_ref(),
+                mint: Some(&self.mint),
+                pool_membership: pool_membership.as_ref(),
+                referrer_token: referrer_token_acc.as_ref(),
+                campaign_positions: &campaign_positions,
+            },
+            stake::Params {
+                amount: token_amount,
+                has_bond_account: staker_bond.is_some(),
+                has_mint: true,
+                has_pool_membership: pool_membership.is_some(),
+                has_referrer_token: referrer_token_acc.is_some(),
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![stake_ix], vec![staker])
+            .await
+    }
+
+    // Stakes with the encoding of the clients that predate the optional accounts flags
+    pub async fn stake_legacy(&mut self, stake_pool_owner_key: &Pubkey, staker: &Keypair, token_amount: u64) -> Result<(), BanksClientError> {
+        let staker_key = staker.pubkey();
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner_key);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker_key);
+        let staker_token_acc = self.get_ata(&staker_key);
+        let pool_vault = self.get_ata(&stake_pool_key);
+
+        let mut stake_ix = stake(
+            self.program_id,
+            stake::Accounts {
+                stake_account: &stake_acc_key,
+                stake_pool: &stake_pool_key,
+                owner: &staker_key,
+                source_token: &staker_token_acc,
+                spl_token_program: &self.token_program,
                 vault: &pool_vault,
                 central_state_account: &self.central_state,
                 fee_account: &self.authority_ata,
-                bond_account: staker_bond,
+                bond_account: None,
+                mint: None,
+                pool_membership: None,
+                referrer_token: None,
+                campaign_positions: &[],
             },
             stake::Params {
                 amount: token_amount,
+                has_bond_account: false,
+                has_mint: false,
+                has_pool_membership: false,
+                has_referrer_token: false,
             },
         );
+        // Only keep the tag and the amount
+        stake_ix.data.truncate(1 + size_of::<u64>());
         sign_send_instructions(&mut self.prg_test_ctx, vec![stake_ix], vec![staker])
             .await
     }
@@ -328,6 +455,28 @@ impl TestRunner {
             crank::Accounts {
                 stake_pool: &stake_pool_key,
                 central_state: &self.central_state,
+                bounty_destination: None,
+                mint: None,
+                spl_token_program: None,
+            },
+            crank::Params {},
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![crank_ix], vec![])
+            .await
+    }
+
+    pub async fn crank_pool_with_bounty(&mut self, stake_pool_owner_key: &Pubkey, cranker_key: &Pubkey) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner_key);
+        let cranker_token_acc = self.get_ata(cranker_key);
+        let crank_ix = crank(
+            self.program_id,
+            crank::Accounts {
+                stake_pool: &stake_pool_key,
+                central_state: &self.central_state,
+                bounty_destination: Some(&cranker_token_acc),
+                mint: Some(&self.mint),
+                spl_token_program: Some(&self.token_program),
             },
             crank::Params {},
         );
@@ -338,7 +487,7 @@ impl TestRunner {
 
     pub async fn claim_pool_rewards(&mut self, stake_pool_owner: &Keypair) -> Result<(), BanksClientError> {
         let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
-        let stake_pool_owner_token_acc = get_associated_token_address(&stake_pool_owner.pubkey(), &self.mint);
+        let stake_pool_owner_token_acc = self.get_ata(&stake_pool_owner.pubkey());
         let claim_stake_pool_ix = claim_pool_rewards(
             self.program_id,
             claim_pool_rewards::Accounts {
@@ -347,7 +496,7 @@ impl TestRunner {
                 rewards_destination: &stake_pool_owner_token_acc,
                 central_state: &self.central_state,
                 mint: &self.mint,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
             },
             claim_pool_rewards::Params {},
             true,
@@ -364,7 +513,10 @@ impl TestRunner {
     pub async fn claim_staker_rewards(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair) -> Result<(), BanksClientError>  {
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
         let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
-        let staker_token_acc = get_associated_token_address(&staker.pubkey(), &self.mint);
+        let staker_token_acc = self.get_ata(&staker.pubkey());
+        let referrer_token_acc = self.referrers
+            .get((stake_pool_owner.to_string() + &staker.pubkey().to_string()).as_str())
+            .map(|referrer| self.get_ata(referrer));
 
         let claim_ix = claim_rewards(
             self.program_id,
@@ -375,7 +527,8 @@ impl TestRunner {
                 rewards_destination: &staker_token_acc,
                 central_state: &self.central_state,
                 mint: &self.mint,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
+                referrer_rewards_destination: referrer_token_acc.as_ref(),
             },
             claim_rewards::Params {
                 allow_zero_rewards: true,
@@ -390,11 +543,12 @@ impl TestRunner {
     pub async fn unstake(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, token_amount: u64) -> Result<(), BanksClientError> {
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
         let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
-        let staker_token_acc = get_associated_token_address(&staker.pubkey(), &self.mint);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
+        let staker_token_acc = self.get_ata(&staker.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
 
         // get the staker's bond from the hash map if it exists
-        let staker_bond: Option<&Pubkey> = self.bond_accounts.get((stake_pool_owner.to_string() + &staker.pubkey().to_string()).as_str());
+        let staker_bond: Option<Pubkey> = self.bond_accounts.get((stake_pool_owner.to_string() + &staker.pubkey().to_string()).as_str()).copied();
+        let campaign_positions = self.campaign_position_accounts(stake_pool_owner, &staker.pubkey()).await;
 
         // Request Unstake
         let unstake_ix = unstake(
@@ -404,13 +558,21 @@ impl TestRunner {
                 stake_pool: &stake_pool_key,
                 owner: &staker.pubkey(),
                 destination_token: &staker_token_acc,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
                 central_state_account: &self.central_state,
                 vault: &pool_vault,
-                bond_account: staker_bond,
+                bond_account: staker_bond.as_ref(),
+                mint: Some(&self.mint),
+                receipt_mint: None,
+                receipt_token: None,
+                receipt_token_program: None,
+                campaign_positions: &campaign_positions,
             },
             unstake::Params {
                 amount: token_amount,
+                has_bond_account: staker_bond.is_some(),
+                has_mint: true,
+                has_receipt: false,
             },
         );
         // if error, return
@@ -418,15 +580,139 @@ impl TestRunner {
             .await
     }
 
+    // Unstakes with the receipt accounts so that the receipt is burnt when the stake goes below the minimum
+    pub async fn unstake_with_receipt(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, token_amount: u64) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        let staker_token_acc = self.get_ata(&staker.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let (receipt_mint, _) = StakeAccount::find_receipt_mint_key(&stake_acc_key, &self.program_id);
+        let receipt_token = get_associated_token_address(&staker.pubkey(), &receipt_mint);
+        let campaign_positions = self.campaign_position_accounts(stake_pool_owner, &staker.pubkey()).await;
+
+        let unstake_ix = unstake(
+            self.program_id,
+            unstake::Accounts {
+                stake_account: &stake_acc_key,
+                stake_pool: &stake_pool_key,
+                owner: &staker.pubkey(),
+                destination_token: &staker_token_acc,
+                spl_token_program: &self.token_program,
+                central_state_account: &self.central_state,
+                vault: &pool_vault,
+                bond_account: None,
+                mint: Some(&self.mint),
+                receipt_mint: Some(&receipt_mint),
+                receipt_token: Some(&receipt_token),
+                receipt_token_program: Some(&spl_token::ID),
+                campaign_positions: &campaign_positions,
+            },
+            unstake::Params {
+                amount: token_amount,
+                has_bond_account: false,
+                has_mint: true,
+                has_receipt: true,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![unstake_ix], vec![staker])
+            .await
+    }
+
+    // Unstakes with the encoding of the clients that predate the optional accounts flags
+    pub async fn unstake_legacy(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, token_amount: u64) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        let staker_token_acc = self.get_ata(&staker.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
+
+        let mut unstake_ix = unstake(
+            self.program_id,
+            unstake::Accounts {
+                stake_account: &stake_acc_key,
+                stake_pool: &stake_pool_key,
+                owner: &staker.pubkey(),
+                destination_token: &staker_token_acc,
+                spl_token_program: &self.token_program,
+                central_state_account: &self.central_state,
+                vault: &pool_vault,
+                bond_account: None,
+                mint: None,
+                receipt_mint: None,
+                receipt_token: None,
+                receipt_token_program: None,
+                campaign_positions: &[],
+            },
+            unstake::Params {
+                amount: token_amount,
+                has_bond_account: false,
+                has_mint: false,
+                has_receipt: false,
+            },
+        );
+        // Only keep the tag and the amount
+        unstake_ix.data.truncate(1 + size_of::<u64>());
+        sign_send_instructions(&mut self.prg_test_ctx, vec![unstake_ix], vec![staker])
+            .await
+    }
+
     pub async fn staker_stats(&mut self, staker_key: Pubkey) -> Result<StakerStats, BanksClientError> {
-        let staker_token_acc = get_associated_token_address(&staker_key, &self.mint);
-        let balance = self.local_env.get_packed_account_data::<spl_token::state::Account>(staker_token_acc).await?.amount;
+        let staker_token_acc = self.get_ata(&staker_key);
+        let balance = self.token_balance(&staker_token_acc).await?;
         Ok(StakerStats {
             balance
         })
     }
 
 
+    pub async fn get_claimable_rewards(&mut self, stake_pool_owner: &Pubkey, staker: &Pubkey) -> Result<u64, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker);
+        let get_claimable_rewards_ix = get_claimable_rewards(
+            self.program_id,
+            get_claimable_rewards::Accounts {
+                stake_pool: &stake_pool_key,
+                stake_account: &stake_acc_key,
+                central_state: &self.central_state,
+            },
+            get_claimable_rewards::Params {},
+        );
+        let data = simulate_instruction(&mut self.prg_test_ctx, get_claimable_rewards_ix).await?;
+        Ok(u64::try_from_slice(&data)?)
+    }
+
+    pub async fn has_access(&mut self, stake_pool_owner: &Pubkey, staker: &Pubkey) -> Result<bool, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker);
+        let staker_bond = self.bond_accounts.get((stake_pool_owner.to_string() + &staker.to_string()).as_str()).copied();
+        let has_access_ix = has_access(
+            self.program_id,
+            has_access::Accounts {
+                stake_pool: &stake_pool_key,
+                stake_account: &stake_acc_key,
+                bond_account: staker_bond.as_ref(),
+            },
+            has_access::Params {},
+        );
+        let data = simulate_instruction(&mut self.prg_test_ctx, has_access_ix).await?;
+        Ok(bool::try_from_slice(&data)?)
+    }
+
+    // Campaign position accounts of a stake account that must be checkpointed by stake and unstake
+    async fn campaign_position_accounts(&mut self, stake_pool_owner: &Pubkey, staker: &Pubkey) -> Vec<Pubkey> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker);
+        let stake_account = match self.prg_test_ctx.banks_client.get_account(stake_acc_key).await.unwrap() {
+            Some(acc) if acc.data.len() == StakeAccount::LEN => StakeAccount::deserialize(&mut &acc.data[..]).unwrap(),
+            _ => return vec![],
+        };
+        if stake_account.campaign_positions == 0 {
+            return vec![];
+        }
+        let campaign_key = self.get_campaign_pda(stake_pool_owner);
+        let (position_key, _) = CampaignPosition::find_key(&campaign_key, &stake_acc_key, &self.program_id);
+        vec![campaign_key, position_key]
+    }
+
     pub async fn stake_account_stats(&mut self, staker: Pubkey, stake_pool_owner: Pubkey) -> Result<StakeAccount, BanksClientError> {
         let stake_pool_key = self.get_pool_pda(&stake_pool_owner);
         let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker);
@@ -442,12 +728,12 @@ impl TestRunner {
     }
 
     pub async fn pool_stats(&mut self, stake_pool_owner: Pubkey) -> Result<PoolOwnerStats, BanksClientError> {
-        let stake_pool_owner_token_acc = get_associated_token_address(&stake_pool_owner, &self.mint);
-        let balance = self.local_env.get_packed_account_data::<spl_token::state::Account>(stake_pool_owner_token_acc).await?.amount;
+        let stake_pool_owner_token_acc = self.get_ata(&stake_pool_owner);
+        let balance = self.token_balance(&stake_pool_owner_token_acc).await?;
 
         let stake_pool_key = self.get_pool_pda(&stake_pool_owner);
-        let stake_pool_associated_token_account = get_associated_token_address(&stake_pool_key, &self.mint);
-        let total_pool_staked = self.local_env.get_packed_account_data::<spl_token::state::Account>(stake_pool_associated_token_account).await?.amount;
+        let stake_pool_associated_token_account = self.get_ata(&stake_pool_key);
+        let total_pool_staked = self.token_balance(&stake_pool_associated_token_account).await?;
 
         let acc = self.prg_test_ctx
             .banks_client
@@ -480,6 +766,17 @@ impl TestRunner {
         Ok(bond_account)
     }
 
+    pub async fn bond_account_stats(&mut self, bond_key: Pubkey) -> Result<BondAccount, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(bond_key)
+            .await
+            .unwrap()
+            .unwrap();
+        let bond_account = BondAccount::deserialize(&mut &acc.data[..])?;
+        Ok(bond_account)
+    }
+
     pub async fn central_state_stats(&mut self) -> Result<CentralState, Box<dyn Error>> {
         let acc = self.prg_test_ctx
             .banks_client
@@ -496,7 +793,7 @@ impl TestRunner {
             BondAccount::create_key(bond_owner, total_amount, &self.program_id);
 
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
-        let seller_token_account = get_associated_token_address(&self.bond_seller.pubkey(), &self.mint);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
         self.mint(&self.bond_seller.pubkey(), total_amount).await?;
         let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
 
@@ -519,6 +816,8 @@ impl TestRunner {
                 unlock_amount: total_amount / payout_count,
                 unlock_start_date: current_time + unlock_after,
                 seller_index: 0,
+                vesting: None,
+                expiry_date: 0,
             },
         );
 
@@ -530,12 +829,115 @@ impl TestRunner {
         Ok(())
     }
 
+    pub async fn create_bond_with_vesting(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Pubkey, total_amount: u64, cliff_after: i64, cliff_amount: u64, vesting_duration: i64) -> Result<(), BanksClientError> {
+        let (bond_key, _bond_nonce) =
+            BondAccount::create_key(bond_owner, total_amount, &self.program_id);
+
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
+        self.mint(&self.bond_seller.pubkey(), total_amount).await?;
+        let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
+
+        let create_bond_ix = create_bond(
+            self.program_id,
+            create_bond::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_account: &bond_key,
+                stake_pool: &stake_pool_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_bond::Params {
+                buyer: *bond_owner,
+                total_amount_sold: total_amount,
+                seller_token_account,
+                total_quote_amount: 0,
+                quote_mint: self.mint,
+                unlock_period: 0,
+                unlock_amount: 0,
+                unlock_start_date: current_time + cliff_after,
+                seller_index: 0,
+                vesting: Some(VestingSchedule {
+                    cliff_date: current_time + cliff_after,
+                    cliff_amount,
+                    linear_end_date: current_time + cliff_after + vesting_duration,
+                }),
+                expiry_date: 0,
+            },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_bond_ix], vec![&self.bond_seller])
+            .await?;
+
+        // add bond account to the map
+        self.bond_accounts.insert(stake_pool_owner.to_string() + bond_owner.to_string().as_str(), bond_key);
+        Ok(())
+    }
+
+    pub async fn create_expiring_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Pubkey, total_amount: u64, expire_after: i64) -> Result<(), BanksClientError> {
+        let (bond_key, _bond_nonce) =
+            BondAccount::create_key(bond_owner, total_amount, &self.program_id);
+
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
+        let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
+
+        let create_bond_ix = create_bond(
+            self.program_id,
+            create_bond::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_account: &bond_key,
+                stake_pool: &stake_pool_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_bond::Params {
+                buyer: *bond_owner,
+                total_amount_sold: total_amount,
+                seller_token_account,
+                total_quote_amount: 0,
+                quote_mint: self.mint,
+                unlock_period: 1,
+                unlock_amount: total_amount,
+                unlock_start_date: current_time,
+                seller_index: 0,
+                vesting: None,
+                expiry_date: current_time + expire_after,
+            },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_bond_ix], vec![&self.bond_seller])
+            .await?;
+
+        // add bond account to the map
+        self.bond_accounts.insert(stake_pool_owner.to_string() + bond_owner.to_string().as_str(), bond_key);
+        Ok(())
+    }
+
+    pub async fn cancel_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Pubkey) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.to_string()).as_str()).unwrap();
+
+        let cancel_bond_ix = cancel_bond(
+            self.program_id,
+            cancel_bond::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_account: &bond_key,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+                user_index: self.user_indexes.get(bond_owner.to_string().as_str()),
+            },
+            cancel_bond::Params { seller_index: 0 },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![cancel_bond_ix], vec![&self.bond_seller])
+            .await
+    }
+
     pub async fn claim_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Pubkey) -> Result<(), BanksClientError> {
         let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.to_string()).as_str()).unwrap();
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
-        let seller_token_acc = get_associated_token_address(&self.bond_seller.pubkey(), &self.mint);
-        let bond_owner_ata = get_associated_token_address(bond_owner, &self.mint);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
+        let seller_token_acc = self.get_ata(&self.bond_seller.pubkey());
+        let bond_owner_ata = self.get_ata(bond_owner);
+        let pool_vault = self.get_ata(&stake_pool_key);
 
         let mut claim_bond_ix = claim_bond(
             self.program_id,
@@ -548,9 +950,14 @@ impl TestRunner {
                 access_mint: &self.mint,
                 pool_vault: &pool_vault,
                 central_state: &self.central_state,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
+                quote_mint: None,
+                quote_token_program: None,
+                user_index: self.user_indexes.get(bond_owner.to_string().as_str()),
+            },
+            claim_bond::Params {
+                has_quote_mint: false,
             },
-            claim_bond::Params {},
         );
         claim_bond_ix.accounts[1].is_signer = false;
 
@@ -564,7 +971,7 @@ impl TestRunner {
             BondAccount::create_key(bond_owner, bond_amount, &self.program_id);
 
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
-        let seller_token_account = get_associated_token_address(&self.bond_seller.pubkey(), &self.mint);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
         self.mint(&self.bond_seller.pubkey(), bond_amount).await?;
         let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
 
@@ -587,6 +994,8 @@ impl TestRunner {
                 unlock_amount: bond_amount,
                 unlock_start_date: current_time + unlock_after,
                 seller_index: 0,
+                vesting: None,
+                expiry_date: 0,
             },
         );
 
@@ -601,9 +1010,9 @@ impl TestRunner {
     pub async fn claim_bond_with_quote(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair) -> Result<(), BanksClientError> {
         let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + bond_owner.pubkey().to_string().as_str()).as_str()).unwrap();
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
-        let seller_token_acc = get_associated_token_address(&self.bond_seller.pubkey(), &self.mint);
-        let bond_owner_ata = get_associated_token_address(&bond_owner.pubkey(), &self.mint);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
+        let seller_token_acc = self.get_ata(&self.bond_seller.pubkey());
+        let bond_owner_ata = self.get_ata(&bond_owner.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
 
         let claim_bond_ix = claim_bond(
             self.program_id,
@@ -616,9 +1025,14 @@ impl TestRunner {
                 access_mint: &self.mint,
                 pool_vault: &pool_vault,
                 central_state: &self.central_state,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
+                quote_mint: Some(&self.mint),
+                quote_token_program: Some(&self.token_program),
+                user_index: self.user_indexes.get(bond_owner.pubkey().to_string().as_str()),
+            },
+            claim_bond::Params {
+                has_quote_mint: true,
             },
-            claim_bond::Params {},
         );
 
         sign_send_instructions(&mut self.prg_test_ctx, vec![claim_bond_ix], vec![bond_owner])
@@ -628,8 +1042,8 @@ impl TestRunner {
     pub async fn unlock_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair) -> Result<(), BanksClientError> {
         let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
-        let pool_vault = get_associated_token_address(&stake_pool_key, &self.mint);
-        let bond_owner_ata = get_associated_token_address(&bond_owner.pubkey(), &self.mint);
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let bond_owner_ata = self.get_ata(&bond_owner.pubkey());
 
         let unlock_ix = unlock_bond_tokens(
             self.program_id,
@@ -639,7 +1053,7 @@ impl TestRunner {
                 mint: &self.mint,
                 access_token_destination: &bond_owner_ata,
                 central_state: &self.central_state,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
                 stake_pool: &stake_pool_key,
                 pool_vault: &pool_vault,
             },
@@ -650,10 +1064,66 @@ impl TestRunner {
             .await
     }
 
+    pub async fn early_unlock_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair, amount: u64) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let bond_owner_ata = self.get_ata(&bond_owner.pubkey());
+
+        let early_unlock_ix = early_unlock_bond(
+            self.program_id,
+            early_unlock_bond::Accounts {
+                bond_account: &bond_key,
+                bond_owner: &bond_owner.pubkey(),
+                mint: &self.mint,
+                access_token_destination: &bond_owner_ata,
+                fee_account: &self.authority_ata,
+                central_state: &self.central_state,
+                stake_pool: &stake_pool_key,
+                pool_vault: &pool_vault,
+                spl_token_program: &self.token_program,
+            },
+            early_unlock_bond::Params { amount },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![early_unlock_ix], vec![bond_owner])
+            .await
+    }
+
+    pub async fn move_bond_stake(&mut self, source_pool_owner: &Pubkey, destination_pool_owner: &Pubkey, bond_owner: &Keypair) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((source_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
+        let source_pool_key = self.get_pool_pda(source_pool_owner);
+        let destination_pool_key = self.get_pool_pda(destination_pool_owner);
+        let source_pool_vault = self.get_ata(&source_pool_key);
+        let destination_pool_vault = self.get_ata(&destination_pool_key);
+
+        let move_bond_stake_ix = move_bond_stake(
+            self.program_id,
+            move_bond_stake::Accounts {
+                bond_account: &bond_key,
+                bond_owner: &bond_owner.pubkey(),
+                source_stake_pool: &source_pool_key,
+                source_pool_vault: &source_pool_vault,
+                destination_stake_pool: &destination_pool_key,
+                destination_pool_vault: &destination_pool_vault,
+                central_state: &self.central_state,
+                spl_token_program: &self.token_program,
+                mint: &self.mint,
+                pool_membership: self.pool_memberships.get((destination_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()),
+            },
+            move_bond_stake::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![move_bond_stake_ix], vec![bond_owner])
+            .await?;
+
+        self.bond_accounts.insert(destination_pool_owner.to_string() + &bond_owner.pubkey().to_string(), bond_key);
+        Ok(())
+    }
+
     pub async fn claim_bond_rewards(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair) -> Result<(), BanksClientError> {
         let stake_pool_key = self.get_pool_pda(stake_pool_owner);
         let bond_key = self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
-        let seller_token_acc = get_associated_token_address(&bond_owner.pubkey(), &self.mint);
+        let seller_token_acc = self.get_ata(&bond_owner.pubkey());
 
         let claim_bond_rewards_ix = claim_bond_rewards(
             self.program_id,
@@ -664,7 +1134,7 @@ impl TestRunner {
                 rewards_destination: &seller_token_acc,
                 central_state: &self.central_state,
                 mint: &self.mint,
-                spl_token_program: &spl_token::ID,
+                spl_token_program: &self.token_program,
             },
             claim_bond_rewards::Params {},
             false,
@@ -682,6 +1152,10 @@ impl TestRunner {
         self.mint
     }
 
+    pub fn get_central_state(&self) -> Pubkey {
+        self.central_state
+    }
+
     pub async fn get_current_time(&mut self) -> i64 {
         self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp
     }
@@ -690,8 +1164,22 @@ impl TestRunner {
         self.bond_seller.pubkey()
     }
 
+    // The ACCESS token account of the owner
+    pub fn get_ata(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(owner, &self.mint, &self.token_program)
+    }
+
+    pub async fn token_balance(&mut self, key: &Pubkey) -> Result<u64, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(*key)
+            .await?
+            .unwrap();
+        Ok(StateWithExtensions::<spl_token_2022::state::Account>::unpack(&acc.data).unwrap().base.amount)
+    }
+
     pub fn get_bond_seller_ata(&self) -> Pubkey {
-        get_associated_token_address(&self.bond_seller.pubkey(), &self.mint)
+        self.get_ata(&self.bond_seller.pubkey())
     }
 
     pub async fn change_pool_minimum(&mut self, stake_pool_owner: &Keypair, new_minimum: u64) -> Result<(), BanksClientError> {
@@ -736,12 +1224,76 @@ impl TestRunner {
             .await
     }
 
-    pub async fn change_inflation(&mut self, new_inflation: u64) -> Result<(), BanksClientError> {
-        let change_inflation_ix = change_inflation(
+    pub async fn set_pool_weight(&mut self, stake_pool_owner: &Pubkey, new_weight: u64) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let set_pool_weight_ix = set_pool_weight(
             self.program_id,
-            change_inflation::Accounts {
+            set_pool_weight::Accounts {
                 central_state: &self.central_state,
                 authority: &self.prg_test_ctx.payer.pubkey(),
+                stake_pool: &stake_pool_key,
+            },
+            set_pool_weight::Params {
+                new_weight,
+            },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![set_pool_weight_ix], vec![])
+            .await
+    }
+
+    pub async fn deprecate_stake_pool(&mut self, stake_pool_owner: &Pubkey) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (freeze_record_key, _) = FreezeRecord::find_key(&stake_pool_key, &self.program_id);
+        let deprecate_stake_pool_ix = deprecate_stake_pool(
+            self.program_id,
+            deprecate_stake_pool::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                stake_pool: &stake_pool_key,
+                freeze_record: &freeze_record_key,
+                fee_payer: Some(&self.prg_test_ctx.payer.pubkey()),
+            },
+            deprecate_stake_pool::Params {},
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![deprecate_stake_pool_ix], vec![])
+            .await
+    }
+
+    pub async fn close_stake_pool(&mut self, stake_pool_owner: &Keypair) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let owner_token_acc = self.get_ata(&stake_pool_owner.pubkey());
+        let close_stake_pool_ix = close_stake_pool(
+            self.program_id,
+            close_stake_pool::Accounts {
+                stake_pool_account: &stake_pool_key,
+                pool_vault: &pool_vault,
+                owner: &stake_pool_owner.pubkey(),
+                deposit_destination: Some(&owner_token_acc),
+                spl_token_program: Some(&self.token_program),
+                mint: Some(&self.mint),
+            },
+            close_stake_pool::Params {},
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![close_stake_pool_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn change_inflation(&mut self, new_inflation: u64) -> Result<(), BanksClientError> {
+        let admin_log = self.admin_log;
+        self.change_inflation_with_admin_log(new_inflation, admin_log.as_ref()).await
+    }
+
+    pub async fn change_inflation_with_admin_log(&mut self, new_inflation: u64, admin_log: Option<&Pubkey>) -> Result<(), BanksClientError> {
+        let change_inflation_ix = change_inflation(
+            self.program_id,
+            change_inflation::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                admin_log,
             },
             change_inflation::Params {
                 daily_inflation: new_inflation,
@@ -752,12 +1304,45 @@ impl TestRunner {
             .await
     }
 
+    pub async fn change_crank_bounty(&mut self, crank_bounty: u64) -> Result<(), BanksClientError> {
+        let change_crank_bounty_ix = change_crank_bounty(
+            self.program_id,
+            change_crank_bounty::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+            },
+            change_crank_bounty::Params {
+                crank_bounty,
+            },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![change_crank_bounty_ix], vec![])
+            .await
+    }
+
+    pub async fn change_activation_deposit(&mut self, activation_deposit: u64) -> Result<(), BanksClientError> {
+        let change_activation_deposit_ix = change_activation_deposit(
+            self.program_id,
+            change_activation_deposit::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+            },
+            change_activation_deposit::Params {
+                activation_deposit,
+            },
+        );
+
+        sign_send_instructions(&mut self.prg_test_ctx, vec![change_activation_deposit_ix], vec![])
+            .await
+    }
+
     pub async fn change_central_state_authority(&mut self, new_authority: &Keypair) -> Result<(), BanksClientError> {
         let ix = change_central_state_authority(
             self.program_id,
             change_central_state_authority::Accounts {
                 central_state: &self.central_state,
                 authority: &self.prg_test_ctx.payer.pubkey(),
+                admin_log: self.admin_log.as_ref(),
             },
             change_central_state_authority::Params {
                 new_authority: new_authority.pubkey(),
@@ -766,8 +1351,1011 @@ impl TestRunner {
         sign_send_instructions(&mut self.prg_test_ctx, vec![ix], vec![])
             .await?;
 
-        let authority_ata = get_associated_token_address(&self.prg_test_ctx.payer.pubkey(), &self.mint);
+        let authority_ata = self.get_ata(&self.prg_test_ctx.payer.pubkey());
         self.authority_ata = authority_ata;
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub fn get_campaign_pda(&mut self, stake_pool_owner: &Pubkey) -> Pubkey {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (campaign_key, _) = RewardCampaign::find_key(&stake_pool_key, &self.mint, &self.program_id);
+        campaign_key
+    }
+
+    pub async fn create_reward_campaign(&mut self, stake_pool_owner: &Keypair, daily_rate: u64, end_time: i64) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let campaign_key = self.get_campaign_pda(&stake_pool_owner.pubkey());
+        let campaign_vault = self.get_ata(&campaign_key);
+        let create_ata_campaign_vault_ix = create_associated_token_account(
+            &self.prg_test_ctx.payer.pubkey(),
+            &campaign_key,
+            &self.mint,
+            &self.token_program,
+        );
+        sign_send_instructions(
+            &mut self.prg_test_ctx,
+            vec![create_ata_campaign_vault_ix],
+            vec![],
+        )
+            .await?;
+
+        let create_campaign_ix = create_reward_campaign(
+            self.program_id,
+            create_reward_campaign::Accounts {
+                stake_pool: &stake_pool_key,
+                owner: &stake_pool_owner.pubkey(),
+                reward_campaign: &campaign_key,
+                vault: &campaign_vault,
+                mint: &self.mint,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_reward_campaign::Params {
+                daily_rate,
+                end_time,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_campaign_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn fund_reward_campaign(&mut self, stake_pool_owner: &Keypair, amount: u64) -> Result<(), BanksClientError> {
+        let campaign_key = self.get_campaign_pda(&stake_pool_owner.pubkey());
+        let campaign_vault = self.get_ata(&campaign_key);
+        let source_token = self.get_ata(&stake_pool_owner.pubkey());
+        let fund_campaign_ix = fund_reward_campaign(
+            self.program_id,
+            fund_reward_campaign::Accounts {
+                reward_campaign: &campaign_key,
+                owner: &stake_pool_owner.pubkey(),
+                source_token: &source_token,
+                vault: &campaign_vault,
+                spl_token_program: &self.token_program,
+                mint: &self.mint,
+            },
+            fund_reward_campaign::Params {
+                amount,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![fund_campaign_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn extend_reward_campaign(&mut self, stake_pool_owner: &Keypair, new_daily_rate: u64, new_end_time: i64) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let campaign_key = self.get_campaign_pda(&stake_pool_owner.pubkey());
+        let campaign_vault = self.get_ata(&campaign_key);
+        let extend_campaign_ix = extend_reward_campaign(
+            self.program_id,
+            extend_reward_campaign::Accounts {
+                reward_campaign: &campaign_key,
+                stake_pool: &stake_pool_key,
+                owner: &stake_pool_owner.pubkey(),
+                vault: &campaign_vault,
+            },
+            extend_reward_campaign::Params {
+                new_end_time,
+                new_daily_rate,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![extend_campaign_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn claim_campaign_rewards(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        let campaign_key = self.get_campaign_pda(stake_pool_owner);
+        let (position_key, _) = CampaignPosition::find_key(&campaign_key, &stake_acc_key, &self.program_id);
+        let campaign_vault = self.get_ata(&campaign_key);
+        let rewards_destination = self.get_ata(&staker.pubkey());
+        let claim_campaign_ix = claim_campaign_rewards(
+            self.program_id,
+            claim_campaign_rewards::Accounts {
+                reward_campaign: &campaign_key,
+                stake_pool: &stake_pool_key,
+                stake_account: &stake_acc_key,
+                owner: &staker.pubkey(),
+                campaign_position: &position_key,
+                vault: &campaign_vault,
+                rewards_destination: &rewards_destination,
+                spl_token_program: &self.token_program,
+                mint: &self.mint,
+                system_program: &system_program::ID,
+            },
+            claim_campaign_rewards::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![claim_campaign_ix], vec![staker])
+            .await
+    }
+
+    pub async fn reclaim_campaign_rewards(&mut self, stake_pool_owner: &Keypair) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let campaign_key = self.get_campaign_pda(&stake_pool_owner.pubkey());
+        let campaign_vault = self.get_ata(&campaign_key);
+        let destination_token = self.get_ata(&stake_pool_owner.pubkey());
+        let reclaim_ix = reclaim_campaign_rewards(
+            self.program_id,
+            reclaim_campaign_rewards::Accounts {
+                reward_campaign: &campaign_key,
+                stake_pool: &stake_pool_key,
+                owner: &stake_pool_owner.pubkey(),
+                vault: &campaign_vault,
+                destination_token: &destination_token,
+                spl_token_program: &self.token_program,
+                mint: &self.mint,
+            },
+            reclaim_campaign_rewards::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![reclaim_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn campaign_stats(&mut self, stake_pool_owner: Pubkey) -> Result<RewardCampaign, BanksClientError> {
+        let campaign_key = self.get_campaign_pda(&stake_pool_owner);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(campaign_key)
+            .await
+            .unwrap()
+            .unwrap();
+        let campaign = RewardCampaign::deserialize(&mut &acc.data[..])?;
+        Ok(campaign)
+    }
+
+    pub fn get_access_delegation_pda(&mut self, stake_pool_owner: &Pubkey, staker: &Pubkey) -> Pubkey {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker);
+        AccessDelegation::find_key(&stake_acc_key, &self.program_id).0
+    }
+
+    pub async fn create_access_delegation(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, members: Vec<Pubkey>) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        let delegation_key = self.get_access_delegation_pda(stake_pool_owner, &staker.pubkey());
+        let create_delegation_ix = create_access_delegation(
+            self.program_id,
+            create_access_delegation::Accounts {
+                stake_account: &stake_acc_key,
+                access_delegation: &delegation_key,
+                owner: &staker.pubkey(),
+                system_program: &system_program::ID,
+            },
+            create_access_delegation::Params { members },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_delegation_ix], vec![staker])
+            .await
+    }
+
+    pub async fn edit_access_delegation(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, members: Vec<Pubkey>) -> Result<(), BanksClientError> {
+        let delegation_key = self.get_access_delegation_pda(stake_pool_owner, &staker.pubkey());
+        let edit_delegation_ix = edit_access_delegation(
+            self.program_id,
+            edit_access_delegation::Accounts {
+                access_delegation: &delegation_key,
+                owner: &staker.pubkey(),
+            },
+            edit_access_delegation::Params { members },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![edit_delegation_ix], vec![staker])
+            .await
+    }
+
+    pub async fn close_access_delegation(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair) -> Result<(), BanksClientError> {
+        let delegation_key = self.get_access_delegation_pda(stake_pool_owner, &staker.pubkey());
+        let close_delegation_ix = close_access_delegation(
+            self.program_id,
+            close_access_delegation::Accounts {
+                access_delegation: &delegation_key,
+                owner: &staker.pubkey(),
+            },
+            close_access_delegation::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![close_delegation_ix], vec![staker])
+            .await
+    }
+
+    pub async fn access_delegation_stats(&mut self, stake_pool_owner: Pubkey, staker: Pubkey) -> Result<Option<AccessDelegation>, BanksClientError> {
+        let delegation_key = self.get_access_delegation_pda(&stake_pool_owner, &staker);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(delegation_key)
+            .await?;
+        match acc {
+            Some(acc) => Ok(Some(AccessDelegation::deserialize(&mut &acc.data[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn set_pool_allowlist(&mut self, stake_pool_owner: &Keypair, allowlisted: bool) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let set_allowlist_ix = set_pool_allowlist(
+            self.program_id,
+            set_pool_allowlist::Accounts {
+                stake_pool: &stake_pool_key,
+                stake_pool_owner: &stake_pool_owner.pubkey(),
+            },
+            set_pool_allowlist::Params { allowlisted },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![set_allowlist_ix], vec![stake_pool_owner])
+            .await
+    }
+
+    pub async fn add_pool_member(&mut self, stake_pool_owner: &Keypair, member: &Pubkey) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let (membership_key, _) = PoolMembership::find_key(&stake_pool_key, member, &self.program_id);
+        let add_member_ix = add_pool_member(
+            self.program_id,
+            add_pool_member::Accounts {
+                stake_pool: &stake_pool_key,
+                stake_pool_owner: &stake_pool_owner.pubkey(),
+                pool_membership: &membership_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            add_pool_member::Params { member: *member },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![add_member_ix], vec![stake_pool_owner])
+            .await?;
+        self.pool_memberships.insert(stake_pool_owner.pubkey().to_string() + &member.to_string(), membership_key);
+        Ok(())
+    }
+
+    pub async fn remove_pool_member(&mut self, stake_pool_owner: &Keypair, member: &Pubkey) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(&stake_pool_owner.pubkey());
+        let (membership_key, _) = PoolMembership::find_key(&stake_pool_key, member, &self.program_id);
+        let remove_member_ix = remove_pool_member(
+            self.program_id,
+            remove_pool_member::Accounts {
+                stake_pool: &stake_pool_key,
+                stake_pool_owner: &stake_pool_owner.pubkey(),
+                pool_membership: &membership_key,
+            },
+            remove_pool_member::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![remove_member_ix], vec![stake_pool_owner])
+            .await?;
+        self.pool_memberships.remove((stake_pool_owner.pubkey().to_string() + &member.to_string()).as_str());
+        Ok(())
+    }
+
+    pub fn set_referrer(&mut self, stake_pool_owner: &Pubkey, staker: &Pubkey, referrer: &Pubkey) {
+        self.referrers.insert(stake_pool_owner.to_string() + &staker.to_string(), *referrer);
+    }
+
+    pub async fn change_referral_rates(&mut self, referral_fee_rate: u64, referral_reward_share: u64) -> Result<(), BanksClientError> {
+        let change_referral_rates_ix = change_referral_rates(
+            self.program_id,
+            change_referral_rates::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+            },
+            change_referral_rates::Params {
+                referral_fee_rate,
+                referral_reward_share,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![change_referral_rates_ix], vec![])
+            .await
+    }
+
+    pub fn get_bond_listing_pda(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Pubkey) -> Pubkey {
+        let bond_key = self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.to_string()).as_str()).unwrap();
+        let (key, _) = BondListing::find_key(bond_key, &self.program_id);
+        key
+    }
+
+    pub async fn transfer_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair, new_owner: &Pubkey) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let bond_owner_ata = self.get_ata(&bond_owner.pubkey());
+        let user_indexes = self.transfer_user_indexes(&bond_owner.pubkey(), new_owner);
+
+        let transfer_bond_ix = transfer_bond(
+            self.program_id,
+            transfer_bond::Accounts {
+                bond_account: &bond_key,
+                bond_owner: &bond_owner.pubkey(),
+                stake_pool: &stake_pool_key,
+                central_state: &self.central_state,
+                mint: &self.mint,
+                rewards_destination: &bond_owner_ata,
+                spl_token_program: &self.token_program,
+                user_indexes: &user_indexes,
+            },
+            transfer_bond::Params {
+                new_owner: *new_owner,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![transfer_bond_ix], vec![bond_owner])
+            .await?;
+
+        self.bond_accounts.insert(stake_pool_owner.to_string() + &new_owner.to_string(), bond_key);
+        Ok(())
+    }
+
+    pub async fn list_bond(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair, price: u64) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
+        let bond_listing_key = self.get_bond_listing_pda(stake_pool_owner, &bond_owner.pubkey());
+        let bond_owner_ata = self.get_ata(&bond_owner.pubkey());
+
+        let list_bond_ix = list_bond(
+            self.program_id,
+            list_bond::Accounts {
+                bond_account: &bond_key,
+                seller: &bond_owner.pubkey(),
+                bond_listing: &bond_listing_key,
+                seller_token_account: &bond_owner_ata,
+                system_program: &system_program::ID,
+            },
+            list_bond::Params { price },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![list_bond_ix], vec![bond_owner])
+            .await
+    }
+
+    pub async fn cancel_bond_listing(&mut self, stake_pool_owner: &Pubkey, bond_owner: &Keypair) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &bond_owner.pubkey().to_string()).as_str()).unwrap();
+        let bond_listing_key = self.get_bond_listing_pda(stake_pool_owner, &bond_owner.pubkey());
+
+        let cancel_bond_listing_ix = cancel_bond_listing(
+            self.program_id,
+            cancel_bond_listing::Accounts {
+                bond_listing: &bond_listing_key,
+                bond_account: &bond_key,
+                seller: &bond_owner.pubkey(),
+            },
+            cancel_bond_listing::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![cancel_bond_listing_ix], vec![bond_owner])
+            .await
+    }
+
+    pub async fn fill_bond_listing(&mut self, stake_pool_owner: &Pubkey, seller: &Pubkey, buyer: &Keypair) -> Result<(), BanksClientError> {
+        let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &seller.to_string()).as_str()).unwrap();
+        let bond_listing_key = self.get_bond_listing_pda(stake_pool_owner, seller);
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let seller_ata = self.get_ata(seller);
+        let buyer_ata = self.get_ata(&buyer.pubkey());
+        let user_indexes = self.transfer_user_indexes(seller, &buyer.pubkey());
+
+        let fill_bond_listing_ix = fill_bond_listing(
+            self.program_id,
+            fill_bond_listing::Accounts {
+                bond_listing: &bond_listing_key,
+                bond_account: &bond_key,
+                buyer: &buyer.pubkey(),
+                buyer_token_account: &buyer_ata,
+                seller,
+                seller_token_account: &seller_ata,
+                seller_rewards_destination: &seller_ata,
+                stake_pool: &stake_pool_key,
+                central_state: &self.central_state,
+                mint: &self.mint,
+                spl_token_program: &self.token_program,
+                quote_mint: &self.mint,
+                quote_token_program: &self.token_program,
+                user_indexes: &user_indexes,
+            },
+            fill_bond_listing::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![fill_bond_listing_ix], vec![buyer])
+            .await?;
+
+        self.bond_accounts.insert(stake_pool_owner.to_string() + &buyer.pubkey().to_string(), bond_key);
+        Ok(())
+    }
+
+    pub async fn bond_listing_stats(&mut self, stake_pool_owner: Pubkey, bond_owner: Pubkey) -> Result<Option<BondListing>, BanksClientError> {
+        let key = self.get_bond_listing_pda(&stake_pool_owner, &bond_owner);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(key)
+            .await?;
+        match acc {
+            Some(acc) => Ok(Some(BondListing::deserialize(&mut &acc.data[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn change_early_unlock_penalty(&mut self, early_unlock_penalty: u64) -> Result<(), BanksClientError> {
+        let change_early_unlock_penalty_ix = change_early_unlock_penalty(
+            self.program_id,
+            change_early_unlock_penalty::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+            },
+            change_early_unlock_penalty::Params {
+                early_unlock_penalty,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![change_early_unlock_penalty_ix], vec![])
+            .await
+    }
+
+    pub async fn create_bond_auction(&mut self, stake_pool_owner: &Pubkey, total_amount: u64, start_price: u64, floor_price: u64, duration: i64) -> Result<Pubkey, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
+        let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
+        let (bond_auction_key, _) = BondAuction::find_key(&self.bond_seller.pubkey(), &stake_pool_key, current_time, &self.program_id);
+
+        let create_bond_auction_ix = create_bond_auction(
+            self.program_id,
+            create_bond_auction::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_auction: &bond_auction_key,
+                stake_pool: &stake_pool_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_bond_auction::Params {
+                total_amount,
+                quote_mint: self.mint,
+                seller_token_account,
+                start_price,
+                floor_price,
+                start_time: current_time,
+                end_time: current_time + duration,
+                unlock_start_date: current_time + 10 * 86400,
+                unlock_period: 1,
+                unlock_periods: 1,
+                vesting: None,
+                seller_index: 0,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_bond_auction_ix], vec![&self.bond_seller])
+            .await?;
+        Ok(bond_auction_key)
+    }
+
+    pub async fn fill_bond_auction(&mut self, stake_pool_owner: &Pubkey, bond_auction_key: &Pubkey, buyer: &Keypair, amount: u64, max_quote_amount: u64) -> Result<Pubkey, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let seller_token_acc = self.get_ata(&self.bond_seller.pubkey());
+        let buyer_ata = self.get_ata(&buyer.pubkey());
+        let fill_count = self.bond_auction_stats(*bond_auction_key).await?.fill_count;
+        let (bond_key, _) = BondAccount::create_auction_fill_key(bond_auction_key, &buyer.pubkey(), fill_count, &self.program_id);
+
+        let fill_bond_auction_ix = fill_bond_auction(
+            self.program_id,
+            fill_bond_auction::Accounts {
+                bond_auction: bond_auction_key,
+                bond_account: &bond_key,
+                buyer: &buyer.pubkey(),
+                quote_token_source: &buyer_ata,
+                quote_token_destination: &seller_token_acc,
+                stake_pool: &stake_pool_key,
+                access_mint: &self.mint,
+                pool_vault: &pool_vault,
+                central_state: &self.central_state,
+                spl_token_program: &self.token_program,
+                quote_mint: &self.mint,
+                quote_token_program: &self.token_program,
+                system_program: &system_program::ID,
+                user_index: self.user_indexes.get(buyer.pubkey().to_string().as_str()),
+            },
+            fill_bond_auction::Params {
+                amount,
+                max_quote_amount,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![fill_bond_auction_ix], vec![buyer])
+            .await?;
+
+        self.bond_accounts.insert(stake_pool_owner.to_string() + &buyer.pubkey().to_string(), bond_key);
+        Ok(bond_key)
+    }
+
+    pub async fn bond_auction_stats(&mut self, bond_auction_key: Pubkey) -> Result<BondAuction, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(bond_auction_key)
+            .await
+            .unwrap()
+            .unwrap();
+        Ok(BondAuction::deserialize(&mut &acc.data[..])?)
+    }
+
+    pub async fn bond_round_stats(&mut self, bond_round_key: Pubkey) -> Result<BondRound, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(bond_round_key)
+            .await
+            .unwrap()
+            .unwrap();
+        Ok(BondRound::deserialize(&mut &acc.data[..])?)
+    }
+
+    pub fn get_bond_round_pda(&mut self, merkle_root: &[u8; 32]) -> Pubkey {
+        let (bond_round_key, _) = BondRound::find_key(merkle_root, &self.program_id);
+        bond_round_key
+    }
+
+    pub async fn create_bond_round(&mut self, stake_pool_owner: &Pubkey, merkle_root: [u8; 32], num_nodes: u64) -> Result<Pubkey, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let seller_token_account = self.get_ata(&self.bond_seller.pubkey());
+        let bond_round_key = self.get_bond_round_pda(&merkle_root);
+        let current_time = self.local_env.get_sysvar::<clock::Clock>().await.unwrap().unix_timestamp;
+
+        let create_bond_round_ix = create_bond_round(
+            self.program_id,
+            create_bond_round::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_round: &bond_round_key,
+                stake_pool: &stake_pool_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_bond_round::Params {
+                merkle_root,
+                quote_mint: self.mint,
+                seller_token_account,
+                unlock_start_date: current_time + 10 * 86400,
+                unlock_period: 1,
+                unlock_periods: 1,
+                seller_index: 0,
+                num_nodes,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_bond_round_ix], vec![&self.bond_seller])
+            .await?;
+        Ok(bond_round_key)
+    }
+
+    pub async fn sign_bond_round(&mut self, bond_round_key: &Pubkey) -> Result<(), BanksClientError> {
+        let sign_bond_round_ix = sign_bond_round(
+            self.program_id,
+            sign_bond_round::Accounts {
+                seller: &self.bond_seller.pubkey(),
+                bond_round: bond_round_key,
+            },
+            sign_bond_round::Params {
+                seller_index: 0,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![sign_bond_round_ix], vec![&self.bond_seller])
+            .await
+    }
+
+    pub async fn claim_round_bond(&mut self, stake_pool_owner: &Pubkey, bond_round_key: &Pubkey, buyer: &Keypair, index: u64, total_amount_sold: u64, total_quote_amount: u64, proof: Vec<[u8; 32]>) -> Result<Pubkey, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let pool_vault = self.get_ata(&stake_pool_key);
+        let seller_token_acc = self.get_ata(&self.bond_seller.pubkey());
+        let buyer_ata = self.get_ata(&buyer.pubkey());
+        let (bond_key, _) = BondAccount::create_round_claim_key(bond_round_key, index, &self.program_id);
+
+        let claim_round_bond_ix = claim_round_bond(
+            self.program_id,
+            claim_round_bond::Accounts {
+                bond_round: bond_round_key,
+                bond_account: &bond_key,
+                buyer: &buyer.pubkey(),
+                quote_token_source: &buyer_ata,
+                quote_token_destination: &seller_token_acc,
+                stake_pool: &stake_pool_key,
+                access_mint: &self.mint,
+                pool_vault: &pool_vault,
+                central_state: &self.central_state,
+                spl_token_program: &self.token_program,
+                quote_mint: &self.mint,
+                quote_token_program: &self.token_program,
+                system_program: &system_program::ID,
+                user_index: self.user_indexes.get(buyer.pubkey().to_string().as_str()),
+            },
+            claim_round_bond::Params {
+                index,
+                total_amount_sold,
+                total_quote_amount,
+                proof,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![claim_round_bond_ix], vec![buyer])
+            .await?;
+
+        self.bond_accounts.insert(stake_pool_owner.to_string() + &buyer.pubkey().to_string(), bond_key);
+        Ok(bond_key)
+    }
+
+    pub fn get_distributor_pda(&mut self, merkle_root: &[u8; 32]) -> Pubkey {
+        let (distributor_key, _) = Distributor::find_key(merkle_root, &self.program_id);
+        distributor_key
+    }
+
+    pub async fn create_distributor(&mut self, merkle_root: [u8; 32], max_total_claim: u64, num_nodes: u64) -> Result<Pubkey, BanksClientError> {
+        let distributor_key = self.get_distributor_pda(&merkle_root);
+        let create_distributor_ix = create_distributor(
+            self.program_id,
+            create_distributor::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                distributor: &distributor_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_distributor::Params {
+                merkle_root,
+                max_total_claim,
+                num_nodes,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_distributor_ix], vec![])
+            .await?;
+        Ok(distributor_key)
+    }
+
+    pub async fn claim_distribution(&mut self, distributor_key: &Pubkey, recipient: &Pubkey, index: u64, amount: u64, proof: Vec<[u8; 32]>) -> Result<(), BanksClientError> {
+        let recipient_ata = self.get_ata(recipient);
+        let claim_distribution_ix = claim_distribution(
+            self.program_id,
+            claim_distribution::Accounts {
+                distributor: distributor_key,
+                recipient,
+                recipient_token_account: &recipient_ata,
+                central_state: &self.central_state,
+                mint: &self.mint,
+                spl_token_program: &self.token_program,
+            },
+            claim_distribution::Params {
+                index,
+                amount,
+                proof,
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![claim_distribution_ix], vec![])
+            .await
+    }
+
+    pub async fn distributor_stats(&mut self, distributor_key: Pubkey) -> Result<Distributor, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(distributor_key)
+            .await
+            .unwrap()
+            .unwrap();
+        Ok(Distributor::deserialize(&mut &acc.data[..])?)
+    }
+
+    pub async fn claim_multiple_rewards(&mut self, owner: &Keypair, stake_pool_owners: &[Pubkey], bond_pool_owners: &[Pubkey]) -> Result<(), BanksClientError> {
+        let owner_token_acc = self.get_ata(&owner.pubkey());
+        let mut claim_accounts = vec![];
+        for stake_pool_owner in stake_pool_owners {
+            let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+            let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &owner.pubkey());
+            claim_accounts.push(stake_pool_key);
+            claim_accounts.push(stake_acc_key);
+            if let Some(referrer) = self.referrers.get((stake_pool_owner.to_string() + &owner.pubkey().to_string()).as_str()) {
+                claim_accounts.push(self.get_ata(referrer));
+            }
+        }
+        for stake_pool_owner in bond_pool_owners {
+            let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+            let bond_key = *self.bond_accounts.get((stake_pool_owner.to_string() + &owner.pubkey().to_string()).as_str()).unwrap();
+            claim_accounts.push(stake_pool_key);
+            claim_accounts.push(bond_key);
+        }
+
+        let claim_multiple_rewards_ix = claim_multiple_rewards(
+            self.program_id,
+            claim_multiple_rewards::Accounts {
+                owner: &owner.pubkey(),
+                rewards_destination: &owner_token_acc,
+                central_state: &self.central_state,
+                mint: &self.mint,
+                spl_token_program: &self.token_program,
+                claim_accounts: &claim_accounts,
+            },
+            claim_multiple_rewards::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![claim_multiple_rewards_ix], vec![owner])
+            .await
+    }
+
+    pub fn get_user_index_pda(&mut self, owner: &Pubkey) -> Pubkey {
+        let (user_index_key, _) = UserIndex::find_key(owner, &self.program_id);
+        user_index_key
+    }
+
+    pub async fn create_user_index(&mut self, owner: &Keypair, positions: Vec<Pubkey>) -> Result<(), BanksClientError> {
+        let user_index_key = self.get_user_index_pda(&owner.pubkey());
+        let create_user_index_ix = create_user_index(
+            self.program_id,
+            create_user_index::Accounts {
+                user_index: &user_index_key,
+                owner: &owner.pubkey(),
+                system_program: &system_program::ID,
+                positions: &positions,
+            },
+            create_user_index::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_user_index_ix], vec![owner])
+            .await?;
+        self.user_indexes.insert(owner.pubkey().to_string(), user_index_key);
+        Ok(())
+    }
+
+    pub async fn close_user_index(&mut self, owner: &Keypair) -> Result<(), BanksClientError> {
+        let user_index_key = self.get_user_index_pda(&owner.pubkey());
+        let close_user_index_ix = close_user_index(
+            self.program_id,
+            close_user_index::Accounts {
+                user_index: &user_index_key,
+                owner: &owner.pubkey(),
+            },
+            close_user_index::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![close_user_index_ix], vec![owner])
+            .await?;
+        self.user_indexes.remove(owner.pubkey().to_string().as_str());
+        Ok(())
+    }
+
+    pub async fn close_stake_account(&mut self, stake_pool_owner: &Pubkey, owner: &Keypair) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &owner.pubkey());
+        let close_stake_account_ix = close_stake_account(
+            self.program_id,
+            close_stake_account::Accounts {
+                stake_account: &stake_acc_key,
+                owner: &owner.pubkey(),
+                user_index: self.user_indexes.get(owner.pubkey().to_string().as_str()),
+            },
+            close_stake_account::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![close_stake_account_ix], vec![owner])
+            .await
+    }
+
+    // User indexes of the previous and new owners of a bond, when they exist
+    pub fn transfer_user_indexes(&self, from: &Pubkey, to: &Pubkey) -> Vec<Pubkey> {
+        [from, to]
+            .iter()
+            .filter_map(|owner| self.user_indexes.get(owner.to_string().as_str()).copied())
+            .collect()
+    }
+
+    pub async fn user_index_stats(&mut self, owner: Pubkey) -> Result<Option<UserIndex>, BanksClientError> {
+        let user_index_key = self.get_user_index_pda(&owner);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(user_index_key)
+            .await?;
+        match acc {
+            Some(acc) => Ok(Some(UserIndex::deserialize(&mut &acc.data[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn create_admin_log(&mut self) -> Result<(), BanksClientError> {
+        let (admin_log_key, _) = AdminLog::find_key(&self.program_id);
+        let create_admin_log_ix = create_admin_log(
+            self.program_id,
+            create_admin_log::Accounts {
+                central_state: &self.central_state,
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                admin_log: &admin_log_key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            create_admin_log::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![create_admin_log_ix], vec![])
+            .await?;
+        self.admin_log = Some(admin_log_key);
+        Ok(())
+    }
+
+    pub async fn admin_freeze(&mut self, accounts_to_freeze: &[Pubkey], reason: u8, expiry: i64) -> Result<(), BanksClientError> {
+        let (account_to_freeze, other_accounts) = accounts_to_freeze.split_first().unwrap();
+        let (freeze_record_key, _) = FreezeRecord::find_key(account_to_freeze, &self.program_id);
+        let mut pairs = vec![];
+        for account in other_accounts {
+            let (other_freeze_record_key, _) = FreezeRecord::find_key(account, &self.program_id);
+            pairs.push(*account);
+            pairs.push(other_freeze_record_key);
+        }
+        let admin_freeze_ix = admin_freeze(
+            self.program_id,
+            admin_freeze::Accounts {
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                account_to_freeze,
+                central_state: &self.central_state,
+                admin_log: self.admin_log.as_ref(),
+                system_program: Some(&system_program::ID),
+                fee_payer: Some(&self.prg_test_ctx.payer.pubkey()),
+                freeze_record: Some(&freeze_record_key),
+                accounts: &pairs,
+            },
+            admin_freeze::Params {
+                reason,
+                expiry,
+                has_admin_log: self.admin_log.is_some(),
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![admin_freeze_ix], vec![])
+            .await
+    }
+
+    // Freezes or unfreezes a single account the way clients did before the freeze records
+    pub async fn legacy_admin_freeze(&mut self, account_to_freeze: &Pubkey) -> Result<(), BanksClientError> {
+        let admin_freeze_ix = admin_freeze(
+            self.program_id,
+            admin_freeze::Accounts {
+                authority: &self.prg_test_ctx.payer.pubkey(),
+                account_to_freeze,
+                central_state: &self.central_state,
+                admin_log: self.admin_log.as_ref(),
+                system_program: None,
+                fee_payer: None,
+                freeze_record: None,
+                accounts: &[],
+            },
+            admin_freeze::Params {
+                reason: 0,
+                expiry: 0,
+                has_admin_log: self.admin_log.is_some(),
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![admin_freeze_ix], vec![])
+            .await
+    }
+
+    pub async fn expire_freeze(&mut self, frozen_account: &Pubkey) -> Result<(), BanksClientError> {
+        let (freeze_record_key, _) = FreezeRecord::find_key(frozen_account, &self.program_id);
+        let expire_freeze_ix = expire_freeze(
+            self.program_id,
+            expire_freeze::Accounts {
+                frozen_account,
+                freeze_record: &freeze_record_key,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            expire_freeze::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![expire_freeze_ix], vec![])
+            .await
+    }
+
+    pub async fn freeze_record_stats(&mut self, frozen_account: &Pubkey) -> Result<Option<FreezeRecord>, BanksClientError> {
+        let (freeze_record_key, _) = FreezeRecord::find_key(frozen_account, &self.program_id);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(freeze_record_key)
+            .await?;
+        match acc {
+            Some(acc) => Ok(Some(FreezeRecord::deserialize(&mut &acc.data[..])?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn account_tag(&mut self, key: &Pubkey) -> Result<u8, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(*key)
+            .await?
+            .unwrap();
+        Ok(acc.data[0])
+    }
+
+    pub async fn admin_log_stats(&mut self) -> Result<AdminLog, BanksClientError> {
+        let (admin_log_key, _) = AdminLog::find_key(&self.program_id);
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(admin_log_key)
+            .await?
+            .unwrap();
+        Ok(AdminLog::deserialize(&mut &acc.data[..])?)
+    }
+
+    // Rewrites an account with the layout it had before the new fields were appended
+    pub async fn set_legacy_layout(&mut self, key: &Pubkey) -> Result<(), BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(*key)
+            .await?
+            .unwrap();
+        let data = acc.data.clone();
+        let legacy_data = match Tag::from_u8(data[0]).unwrap() {
+            Tag::CentralState => data[..CentralState::LEGACY_LEN].to_vec(),
+            Tag::StakeAccount => data[..StakeAccount::LEGACY_LEN].to_vec(),
+            Tag::StakePool | Tag::InactiveStakePool => {
+                let mut legacy_data = data[..StakePoolHeader::LEGACY_LEN].to_vec();
+                legacy_data.extend_from_slice(&data[size_of::<StakePoolHeader>()..]);
+                legacy_data
+            }
+            Tag::InactiveBondAccount | Tag::BondAccount => {
+                let bond = BondAccount::deserialize(&mut &data[..])?;
+                data[..BondAccount::SELLERS_OFFSET + 4 + 32 * bond.sellers.len()].to_vec()
+            }
+            _ => panic!("The account has no legacy layout"),
+        };
+        let mut legacy_acc = AccountSharedData::new(acc.lamports, legacy_data.len(), &acc.owner);
+        legacy_acc.data_as_mut_slice().copy_from_slice(&legacy_data);
+        self.prg_test_ctx.set_account(key, &legacy_acc);
+        Ok(())
+    }
+
+    pub async fn account_len(&mut self, key: &Pubkey) -> Result<usize, BanksClientError> {
+        let acc = self.prg_test_ctx
+            .banks_client
+            .get_account(*key)
+            .await?
+            .unwrap();
+        Ok(acc.data.len())
+    }
+
+    pub async fn migrate_account(&mut self, key: &Pubkey) -> Result<(), BanksClientError> {
+        let migrate_account_ix = migrate_account(
+            self.program_id,
+            migrate_account::Accounts {
+                central_state: &self.central_state,
+                account: key,
+                system_program: &system_program::ID,
+                fee_payer: &self.prg_test_ctx.payer.pubkey(),
+            },
+            migrate_account::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![migrate_account_ix], vec![])
+            .await
+    }
+
+    pub async fn mint_receipt(&mut self, stake_pool_owner: &Pubkey, staker: &Keypair, uri: &str) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        let (receipt_mint, _) = StakeAccount::find_receipt_mint_key(&stake_acc_key, &self.program_id);
+        let receipt_token = get_associated_token_address(&staker.pubkey(), &receipt_mint);
+        let (metadata, _) = mpl_token_metadata::pda::find_metadata_account(&receipt_mint);
+        let mint_receipt_ix = mint_receipt(
+            self.program_id,
+            mint_receipt::Accounts {
+                central_state: &self.central_state,
+                stake_pool: &stake_pool_key,
+                stake_account: &stake_acc_key,
+                owner: &staker.pubkey(),
+                receipt_mint: &receipt_mint,
+                receipt_token: &receipt_token,
+                metadata: &metadata,
+                metadata_program: &mpl_token_metadata::ID,
+                spl_token_program: &spl_token::ID,
+                associated_token_program: &spl_associated_token_account::ID,
+                system_program: &system_program::ID,
+                rent_sysvar: &solana_program::sysvar::rent::ID,
+            },
+            mint_receipt::Params {
+                uri: uri.to_string(),
+            },
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![mint_receipt_ix], vec![staker])
+            .await
+    }
+
+    // Burns the receipt of a stake account, signed by its owner when passed
+    pub async fn burn_receipt(&mut self, stake_pool_owner: &Pubkey, staker_key: &Pubkey, owner: Option<&Keypair>) -> Result<(), BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker_key);
+        let (receipt_mint, _) = StakeAccount::find_receipt_mint_key(&stake_acc_key, &self.program_id);
+        let receipt_token = get_associated_token_address(staker_key, &receipt_mint);
+        let owner_key = owner.map(|o| o.pubkey());
+        let burn_receipt_ix = burn_receipt(
+            self.program_id,
+            burn_receipt::Accounts {
+                central_state: &self.central_state,
+                stake_pool: &stake_pool_key,
+                stake_account: &stake_acc_key,
+                receipt_mint: &receipt_mint,
+                receipt_token: &receipt_token,
+                spl_token_program: &spl_token::ID,
+                owner: owner_key.as_ref(),
+            },
+            burn_receipt::Params {},
+        );
+        sign_send_instructions(&mut self.prg_test_ctx, vec![burn_receipt_ix], owner.into_iter().collect())
+            .await
+    }
+
+    pub async fn receipt_balance(&mut self, stake_pool_owner: &Pubkey, staker_key: &Pubkey) -> Result<u64, BanksClientError> {
+        let stake_pool_key = self.get_pool_pda(stake_pool_owner);
+        let (stake_acc_key, _) = self.get_stake_account_pda(&stake_pool_key, staker_key);
+        let (receipt_mint, _) = StakeAccount::find_receipt_mint_key(&stake_acc_key, &self.program_id);
+        let receipt_token = get_associated_token_address(staker_key, &receipt_mint);
+        Ok(self.local_env.get_packed_account_data::<spl_token::state::Account>(receipt_token).await?.amount)
+    }
+}
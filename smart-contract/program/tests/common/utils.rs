@@ -8,6 +8,8 @@ use solana_sdk::account::Account;
 use solana_sdk::signature::Signer;
 use solana_sdk::{signature::Keypair, transaction::Transaction};
 use spl_token::state::Mint;
+use spl_token_2022::extension::transfer_fee::{TransferFee, TransferFeeConfig};
+use spl_token_2022::extension::{ExtensionType, StateWithExtensionsMut};
 
 // Utils
 pub async fn sign_send_instructions(
@@ -24,6 +26,24 @@ pub async fn sign_send_instructions(
     ctx.banks_client.process_transaction(transaction).await
 }
 
+// Simulates a read-only instruction and returns the data set with `set_return_data`
+pub async fn simulate_instruction(
+    ctx: &mut ProgramTestContext,
+    instruction: Instruction,
+) -> Result<Vec<u8>, BanksClientError> {
+    let mut transaction = Transaction::new_with_payer(&[instruction], Some(&ctx.payer.pubkey()));
+    transaction.partial_sign(&[&ctx.payer], ctx.last_blockhash);
+    let simulation = ctx.banks_client.simulate_transaction(transaction).await?;
+    if let Some(Err(e)) = simulation.result {
+        return Err(BanksClientError::TransactionError(e));
+    }
+    Ok(simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .map(|return_data| return_data.data)
+        .unwrap_or_default())
+}
+
 pub fn mint_bootstrap(
     address: Option<&str>,
     decimals: u8,
@@ -53,4 +73,52 @@ pub fn mint_bootstrap(
         },
     );
     (address, mint_info)
-}
\ No newline at end of file
+}
+
+// Creates a Token-2022 mint with the transfer fee extension
+pub fn mint_bootstrap_with_transfer_fee(
+    address: Option<&str>,
+    decimals: u8,
+    program_test: &mut ProgramTest,
+    mint_authority: &Pubkey,
+    transfer_fee_basis_points: u16,
+) -> Pubkey {
+    let address = address
+        .map(|s| Pubkey::from_str(s).unwrap())
+        .unwrap_or_else(Pubkey::new_unique);
+    let space = ExtensionType::get_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ]);
+    let mut data = vec![0; space];
+    let mut state =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut data)
+            .unwrap();
+    let transfer_fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: u64::MAX.into(),
+        transfer_fee_basis_points: transfer_fee_basis_points.into(),
+    };
+    let extension = state.init_extension::<TransferFeeConfig>().unwrap();
+    extension.older_transfer_fee = transfer_fee;
+    extension.newer_transfer_fee = transfer_fee;
+    state.base = spl_token_2022::state::Mint {
+        mint_authority: Some(*mint_authority).into(),
+        supply: u32::MAX.into(),
+        decimals,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    program_test.add_account(
+        address,
+        Account {
+            lamports: u32::MAX.into(),
+            data,
+            owner: spl_token_2022::ID,
+            executable: false,
+            ..Account::default()
+        },
+    );
+    address
+}
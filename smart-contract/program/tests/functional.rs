@@ -15,7 +15,7 @@ use access_protocol::{
         close_stake_pool, crank, create_bond, create_central_state, create_stake_account,
         create_stake_pool, edit_metadata, stake, unlock_bond_tokens, unstake,
     },
-    state::BondAccount,
+    state::{AdminLog, BondAccount, FreezeRecord},
 };
 use mpl_token_metadata::{instruction::create_metadata_accounts_v3, pda::find_metadata_account};
 use mpl_token_metadata::instruction::update_metadata_accounts;
@@ -154,6 +154,7 @@ async fn functional_10s() {
             authority: &prg_test_ctx.payer.pubkey(),
             metadata: &metadata_key,
             metadata_program: &mpl_token_metadata::ID,
+            admin_log: None,
         },
         edit_metadata::Params {
             name: "New name".to_string(),
@@ -221,6 +222,7 @@ async fn functional_10s() {
             access_token_destination: &staker_token_acc,
             central_state: &central_state,
             spl_token_program: &spl_token::ID,
+            admin_log: None,
         },
         admin_mint::Params {
             amount: 10_000 * 1_000_000,
@@ -280,6 +282,7 @@ async fn functional_10s() {
             authority: &prg_test_ctx.payer.pubkey(),
             stake_pool: &stake_pool_key,
             central_state: &central_state,
+            admin_log: None,
         },
         activate_stake_pool::Params {},
     );
@@ -330,6 +333,8 @@ async fn functional_10s() {
             unlock_amount: bond_amount,
             unlock_start_date: 0,
             seller_index: 0,
+            vesting: None,
+            expiry_date: 0,
         },
     );
 
@@ -360,6 +365,8 @@ async fn functional_10s() {
             unlock_amount: bond_amount,
             unlock_start_date: 0,
             seller_index: 0,
+            vesting: None,
+            expiry_date: 0,
         },
     );
 
@@ -387,8 +394,13 @@ async fn functional_10s() {
             access_mint: &mint,
             pool_vault: &pool_vault,
             central_state: &central_state,
+            quote_mint: None,
+            quote_token_program: None,
+            user_index: None,
+        },
+        claim_bond::Params {
+            has_quote_mint: false,
         },
-        claim_bond::Params {},
     );
 
     sign_send_instructions(&mut prg_test_ctx, vec![claim_bond_ix], vec![&staker])
@@ -414,6 +426,8 @@ async fn functional_10s() {
             system_program: &system_program::ID,
             fee_payer: &prg_test_ctx.payer.pubkey(),
             stake_pool: &stake_pool_key,
+            pool_membership: None,
+            user_index: None,
         },
         create_stake_account::Params {
             nonce: stake_nonce,
@@ -441,9 +455,17 @@ async fn functional_10s() {
             central_state_account: &central_state,
             fee_account: &authority_ata,
             bond_account: None,
+            mint: Some(&mint),
+            pool_membership: None,
+            referrer_token: None,
+            campaign_positions: &[],
         },
         stake::Params {
             amount: token_amount,
+            has_bond_account: false,
+            has_mint: true,
+            has_pool_membership: false,
+            has_referrer_token: false,
         },
     );
     sign_send_instructions(&mut prg_test_ctx, vec![stake_ix], vec![&staker])
@@ -469,6 +491,9 @@ async fn functional_10s() {
         crank::Accounts {
             stake_pool: &stake_pool_key,
             central_state: &central_state,
+            bounty_destination: None,
+            mint: None,
+            spl_token_program: None,
         },
         crank::Params {},
     );
@@ -544,6 +569,7 @@ async fn functional_10s() {
             central_state: &central_state,
             mint: &mint,
             spl_token_program: &spl_token::ID,
+            referrer_rewards_destination: None,
         },
         claim_rewards::Params {
             allow_zero_rewards: false,
@@ -564,6 +590,9 @@ async fn functional_10s() {
         crank::Accounts {
             stake_pool: &stake_pool_key,
             central_state: &central_state,
+            bounty_destination: None,
+            mint: None,
+            spl_token_program: None,
         },
         crank::Params {},
     );
@@ -631,6 +660,7 @@ async fn functional_10s() {
         change_inflation::Accounts {
             central_state: &central_state,
             authority: &prg_test_ctx.payer.pubkey(),
+            admin_log: None,
         },
         change_inflation::Params {
             daily_inflation: new_inflation,
@@ -678,6 +708,7 @@ async fn functional_10s() {
             central_state: &central_state,
             mint: &mint,
             spl_token_program: &spl_token::ID,
+            referrer_rewards_destination: None,
         },
         claim_rewards::Params {
             allow_zero_rewards: false,
@@ -704,9 +735,17 @@ async fn functional_10s() {
             vault: &pool_vault,
             central_state_account: &central_state,
             bond_account: None,
+            mint: Some(&mint),
+            receipt_mint: None,
+            receipt_token: None,
+            receipt_token_program: None,
+            campaign_positions: &[],
         },
         unstake::Params {
             amount: token_amount,
+            has_bond_account: false,
+            has_mint: true,
+            has_receipt: false,
         },
     );
     sign_send_instructions(&mut prg_test_ctx, vec![unstake_ix], vec![&staker])
@@ -721,14 +760,28 @@ async fn functional_10s() {
     // Freeze account
     //
 
+    // No admin log was created, its address fills the slot
+    let (admin_log_key, _) = AdminLog::find_key(&program_id);
+    let (stake_pool_freeze_record, _) = FreezeRecord::find_key(&stake_pool_key, &program_id);
+    let (central_state_freeze_record, _) = FreezeRecord::find_key(&central_state, &program_id);
+
     let freeze_stake_acc_ix = admin_freeze(
         program_id,
         admin_freeze::Accounts {
+            authority: &prg_test_ctx.payer.pubkey(),
             central_state: &central_state,
             account_to_freeze: &stake_pool_key,
-            authority: &prg_test_ctx.payer.pubkey(),
+            admin_log: Some(&admin_log_key),
+            system_program: Some(&system_program::ID),
+            fee_payer: Some(&prg_test_ctx.payer.pubkey()),
+            freeze_record: Some(&stake_pool_freeze_record),
+            accounts: &[],
+        },
+        admin_freeze::Params {
+            reason: 0,
+            expiry: 0,
+            has_admin_log: true,
         },
-        admin_freeze::Params {},
     );
 
     sign_send_instructions(&mut prg_test_ctx, vec![freeze_stake_acc_ix], vec![])
@@ -746,11 +799,20 @@ async fn functional_10s() {
     let freeze_stake_acc_ix = admin_freeze(
         program_id,
         admin_freeze::Accounts {
+            authority: &prg_test_ctx.payer.pubkey(),
             central_state: &central_state,
             account_to_freeze: &stake_pool_key,
-            authority: &prg_test_ctx.payer.pubkey(),
+            admin_log: Some(&admin_log_key),
+            system_program: Some(&system_program::ID),
+            fee_payer: Some(&prg_test_ctx.payer.pubkey()),
+            freeze_record: Some(&stake_pool_freeze_record),
+            accounts: &[],
+        },
+        admin_freeze::Params {
+            reason: 0,
+            expiry: 0,
+            has_admin_log: true,
         },
-        admin_freeze::Params {},
     );
 
     sign_send_instructions(&mut prg_test_ctx, vec![freeze_stake_acc_ix], vec![])
@@ -764,11 +826,20 @@ async fn functional_10s() {
     let freeze_stake_acc_ix = admin_freeze(
         program_id,
         admin_freeze::Accounts {
+            authority: &prg_test_ctx.payer.pubkey(),
             central_state: &central_state,
             account_to_freeze: &central_state,
-            authority: &prg_test_ctx.payer.pubkey(),
+            admin_log: Some(&admin_log_key),
+            system_program: Some(&system_program::ID),
+            fee_payer: Some(&prg_test_ctx.payer.pubkey()),
+            freeze_record: Some(&central_state_freeze_record),
+            accounts: &[],
+        },
+        admin_freeze::Params {
+            reason: 0,
+            expiry: 0,
+            has_admin_log: true,
         },
-        admin_freeze::Params {},
     );
 
     assert!(
@@ -786,6 +857,7 @@ async fn functional_10s() {
         close_stake_account::Accounts {
             stake_account: &stake_acc_key,
             owner: &staker.pubkey(),
+            user_index: None,
         },
         close_stake_account::Params {},
     );
@@ -808,6 +880,9 @@ async fn functional_10s() {
             pool_vault: &pool_vault,
             stake_pool_account: &stake_pool_key,
             owner: &stake_pool_owner.pubkey(),
+            deposit_destination: None,
+            spl_token_program: None,
+            mint: None,
         },
         close_stake_pool::Params {},
     );
@@ -828,6 +903,7 @@ async fn functional_10s() {
         change_central_state_authority::Accounts {
             central_state: &central_state,
             authority: &prg_test_ctx.payer.pubkey(),
+            admin_log: None,
         },
         change_central_state_authority::Params {
             new_authority: Keypair::new().pubkey(),
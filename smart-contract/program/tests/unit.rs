@@ -2,7 +2,10 @@ use solana_program::pubkey::Pubkey;
 use solana_sdk::signer::{keypair::Keypair, Signer};
 use solana_test_framework::*;
 
-use access_protocol::state::Tag;
+use access_protocol::state::{
+    AdminAction, AdminLog, CentralState, StakeAccount, StakePoolHeader, Tag, DEFAULT_POOL_WEIGHT,
+    MAX_DELEGATION_MEMBERS,
+};
 
 use crate::common::test_runner::TestRunner;
 
@@ -198,6 +201,63 @@ mod pool_settings {
         let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
         assert_eq!(stats.header.stakers_part, 20);
     }
+
+    #[tokio::test]
+    async fn can_set_pool_weight() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        // Create stake pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        // Check the default weight
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.pool_weight, 100);
+        // Set the pool weight
+        tr.set_pool_weight(&stake_pool_owner.pubkey(), 250).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.pool_weight, 250);
+        // Weights above the maximum are rejected
+        let result = tr.set_pool_weight(&stake_pool_owner.pubkey(), 1_001).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn inflation_is_split_by_weight() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let pool_owner = tr.create_ata_account().await.unwrap();
+        let pool_owner2 = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pools
+        tr.create_stake_pool(&pool_owner.pubkey(), 1000).await.unwrap();
+        tr.create_stake_pool(&pool_owner2.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&pool_owner.pubkey()).await.unwrap();
+        tr.activate_stake_pool(&pool_owner2.pubkey()).await.unwrap();
+        // Boost the second pool
+        tr.set_pool_weight(&pool_owner2.pubkey(), 300).await.unwrap();
+        // Stake the same amount in both pools
+        tr.create_stake_account(&pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.create_stake_account(&pool_owner2.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.stake(&pool_owner2.pubkey(), &staker, 10_000).await.unwrap();
+        let stats = tr.central_state_stats().await.unwrap();
+        assert_eq!(stats.total_weighted_staked, 4_000_000);
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        // Crank
+        tr.crank_pool(&pool_owner.pubkey()).await.unwrap();
+        tr.crank_pool(&pool_owner2.pubkey()).await.unwrap();
+        // Pool claims
+        tr.claim_pool_rewards(&pool_owner).await.unwrap();
+        tr.claim_pool_rewards(&pool_owner2).await.unwrap();
+        let stats = tr.pool_stats(pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 125_000);
+        let stats = tr.pool_stats(pool_owner2.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 375_000);
+    }
 }
 
 mod bonds {
@@ -239,4 +299,1407 @@ mod bonds {
         // Claim bond with signature should succeed
         tr.claim_bond_with_quote(&stake_pool_owner.pubkey(), &staker).await.unwrap();
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn expired_bond_is_cancelled() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Create a bond expiring in 1 day
+        tr.create_expiring_bond(&stake_pool_owner.pubkey(), &staker.pubkey(), 10_000, 86400).await.unwrap();
+        // Cannot be cancelled before the expiry
+        assert!(tr.cancel_bond(&stake_pool_owner.pubkey(), &staker.pubkey()).await.is_err());
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Cannot be claimed after the expiry
+        assert!(tr.claim_bond(&stake_pool_owner.pubkey(), &staker.pubkey()).await.is_err());
+        let bond = tr.bond_stats(staker.pubkey(), stake_pool_owner.pubkey(), 10_000).await.unwrap();
+        assert_eq!(bond.tag, Tag::InactiveBondAccount);
+        tr.cancel_bond(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn early_unlock_pays_a_penalty() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Bond unlocking in 10 days
+        tr.create_bond(&stake_pool_owner.pubkey(), &staker.pubkey(), 10_000, 1, 10 * 86400, 1).await.unwrap();
+        tr.claim_bond(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        let treasury_balance = tr.staker_stats(tr.get_authority()).await.unwrap().balance;
+        // Early unlocks are disabled until a penalty is set
+        assert!(tr.early_unlock_bond(&stake_pool_owner.pubkey(), &staker, 5_000).await.is_err());
+        tr.change_early_unlock_penalty(20).await.unwrap();
+        // Cannot unlock more than the locked amount
+        assert!(tr.early_unlock_bond(&stake_pool_owner.pubkey(), &staker, 10_001).await.is_err());
+        tr.early_unlock_bond(&stake_pool_owner.pubkey(), &staker, 5_000).await.unwrap();
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 4_000);
+        let stats = tr.staker_stats(tr.get_authority()).await.unwrap();
+        assert_eq!(stats.balance, treasury_balance + 1_000);
+        let bond = tr.bond_stats(staker.pubkey(), stake_pool_owner.pubkey(), 10_000).await.unwrap();
+        assert_eq!(bond.total_unlocked_amount, 5_000);
+        assert_eq!(bond.total_staked, 5_000);
+        let pool_stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(pool_stats.header.total_staked, 5_000);
+        // The vested tokens are unlocked without a penalty
+        tr.sleep(10 * 86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_bond_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let balance = tr.staker_stats(staker.pubkey()).await.unwrap().balance;
+        tr.early_unlock_bond(&stake_pool_owner.pubkey(), &staker, 5_000).await.unwrap();
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, balance + 5_000);
+        let stats = tr.staker_stats(tr.get_authority()).await.unwrap();
+        assert_eq!(stats.balance, treasury_balance + 1_000);
+        let bond = tr.bond_stats(staker.pubkey(), stake_pool_owner.pubkey(), 10_000).await.unwrap();
+        assert_eq!(bond.total_unlocked_amount, 10_000);
+    }
+
+    #[tokio::test]
+    async fn bond_stake_moves_to_another_pool() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let pool_owner = tr.create_ata_account().await.unwrap();
+        let pool_owner2 = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create and activate the pools
+        tr.create_stake_pool(&pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_pool(&pool_owner2.pubkey(), 2000).await.unwrap();
+        tr.activate_stake_pool(&pool_owner2.pubkey()).await.unwrap();
+        // Bond in the first pool
+        tr.create_bond(&pool_owner.pubkey(), &staker.pubkey(), 10_000, 1, 10 * 86400, 1).await.unwrap();
+        tr.claim_bond(&pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Rewards must be claimed before moving
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&pool_owner.pubkey()).await.unwrap();
+        tr.crank_pool(&pool_owner2.pubkey()).await.unwrap();
+        assert!(tr.move_bond_stake(&pool_owner.pubkey(), &pool_owner2.pubkey(), &staker).await.is_err());
+        tr.claim_bond_rewards(&pool_owner.pubkey(), &staker).await.unwrap();
+        tr.move_bond_stake(&pool_owner.pubkey(), &pool_owner2.pubkey(), &staker).await.unwrap();
+        let bond = tr.bond_stats(staker.pubkey(), pool_owner2.pubkey(), 10_000).await.unwrap();
+        assert_eq!(bond.stake_pool, tr.get_pool_pda(&pool_owner2.pubkey()));
+        assert_eq!(bond.pool_minimum_at_creation, 2000);
+        let stats = tr.pool_stats(pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.total_staked, 0);
+        let stats = tr.pool_stats(pool_owner2.pubkey()).await.unwrap();
+        assert_eq!(stats.header.total_staked, 10_000);
+        assert_eq!(stats.total_pool_staked, 10_000);
+    }
+
+    #[tokio::test]
+    async fn cliff_and_linear_vesting() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // 2_000 tokens at a cliff in 1 day, the rest vests linearly over 2 days
+        tr.create_bond_with_vesting(&stake_pool_owner.pubkey(), &staker.pubkey(), 10_000, 86400, 2_000, 2 * 86400).await.unwrap();
+        tr.claim_bond(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Nothing unlocks before the cliff
+        assert!(tr.unlock_bond(&stake_pool_owner.pubkey(), &staker).await.is_err());
+        // Halfway through the linear vesting
+        tr.sleep(2 * 86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_bond_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        tr.unlock_bond(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let bond = tr.bond_stats(staker.pubkey(), stake_pool_owner.pubkey(), 10_000).await.unwrap();
+        assert!(bond.total_unlocked_amount >= 6_000);
+        assert!(bond.total_unlocked_amount < 10_000);
+        // Fully vested
+        tr.sleep(2 * 86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_bond_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        tr.unlock_bond(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let bond = tr.bond_stats(staker.pubkey(), stake_pool_owner.pubkey(), 10_000).await.unwrap();
+        assert_eq!(bond.total_unlocked_amount, 10_000);
+        assert_eq!(bond.total_staked, 0);
+    }
+}
+
+mod reward_campaigns {
+    use super::*;
+
+    #[tokio::test]
+    async fn campaign_rewards_are_paid_to_stakers() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&stake_pool_owner.pubkey(), 100_000).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Create a 2 days campaign
+        let end_time = tr.get_current_time().await + 2 * 86400;
+        tr.create_reward_campaign(&stake_pool_owner, 20_000, end_time).await.unwrap();
+        tr.fund_reward_campaign(&stake_pool_owner, 50_000).await.unwrap();
+        // The campaign cannot be reclaimed before its end
+        assert!(tr.reclaim_campaign_rewards(&stake_pool_owner).await.is_err());
+        // The first claim registers the stake account
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 0);
+        // Wait until the campaign is over
+        tr.sleep(3 * 86400).await.unwrap();
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert!(stats.balance <= 40_000);
+        assert!(stats.balance > 39_990);
+        // The owner gets the leftovers back
+        tr.reclaim_campaign_rewards(&stake_pool_owner).await.unwrap();
+        let campaign = tr.campaign_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(campaign.total_claimed, stats.balance);
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(
+            stats.balance,
+            100_000 - campaign.total_accrued + campaign.total_claimed
+        );
+    }
+
+    #[tokio::test]
+    async fn only_registered_stake_is_rewarded() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let unregistered_staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&stake_pool_owner.pubkey(), 100_000).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        tr.mint(&unregistered_staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        for s in [&staker, &unregistered_staker] {
+            tr.create_stake_account(&stake_pool_owner.pubkey(), &s.pubkey()).await.unwrap();
+            tr.stake(&stake_pool_owner.pubkey(), s, 10_000).await.unwrap();
+        }
+        // Create a 2 days campaign, only one staker registers
+        let end_time = tr.get_current_time().await + 2 * 86400;
+        tr.create_reward_campaign(&stake_pool_owner, 20_000, end_time).await.unwrap();
+        tr.fund_reward_campaign(&stake_pool_owner, 40_000).await.unwrap();
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        assert_eq!(tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap().campaign_positions, 1);
+        assert_eq!(tr.campaign_stats(stake_pool_owner.pubkey()).await.unwrap().total_registered_stake, 10_000);
+        // The stake changes must checkpoint the campaign position
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        assert!(tr.stake_legacy(&stake_pool_owner.pubkey(), &staker, 1_000).await.is_err());
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert_eq!(tr.campaign_stats(stake_pool_owner.pubkey()).await.unwrap().total_registered_stake, 20_000);
+        // The campaign can only be extended once the vault holds the rewards
+        assert!(tr.extend_reward_campaign(&stake_pool_owner, 20_000, end_time + 86400).await.is_err());
+        tr.fund_reward_campaign(&stake_pool_owner, 20_000).await.unwrap();
+        tr.extend_reward_campaign(&stake_pool_owner, 20_000, end_time + 86400).await.unwrap();
+        // The registered staker gets all the rewards and the position is closed after the end
+        tr.sleep(3 * 86400).await.unwrap();
+        let balance_before = tr.staker_stats(staker.pubkey()).await.unwrap().balance;
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let reward = tr.staker_stats(staker.pubkey()).await.unwrap().balance - balance_before;
+        assert!(reward <= 60_000);
+        assert!(reward > 59_990);
+        assert_eq!(tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap().campaign_positions, 0);
+        // It is too late to register
+        assert!(tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &unregistered_staker).await.is_err());
+        let campaign = tr.campaign_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(campaign.total_registered_stake, 0);
+        assert_eq!(campaign.total_claimed, reward);
+    }
+
+    #[tokio::test]
+    async fn positions_of_deprecated_pools_can_be_closed() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&stake_pool_owner.pubkey(), 100_000).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Register the stake account in a 2 days campaign
+        let end_time = tr.get_current_time().await + 2 * 86400;
+        tr.create_reward_campaign(&stake_pool_owner, 20_000, end_time).await.unwrap();
+        tr.fund_reward_campaign(&stake_pool_owner, 50_000).await.unwrap();
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        // Deprecate the pool and withdraw the stake
+        tr.deprecate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        let stake_account = tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stake_account.campaign_positions, 1);
+        // The position is closed and the stake account can be closed
+        tr.claim_campaign_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let stake_account = tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stake_account.campaign_positions, 0);
+        tr.close_stake_account(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        // The owner gets the leftovers back
+        tr.sleep(3 * 86400).await.unwrap();
+        tr.reclaim_campaign_rewards(&stake_pool_owner).await.unwrap();
+    }
+}
+
+mod view_instructions {
+    use super::*;
+
+    #[tokio::test]
+    async fn has_access_follows_the_stake() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // No access without a stake
+        assert!(!tr.has_access(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap());
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert!(tr.has_access(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap());
+        // The access is lost with the stake
+        tr.sleep(1).await.unwrap();
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert!(!tr.has_access(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn claimable_rewards_match_the_claim() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        tr.change_referral_rates(50, 10).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let referred_staker = tr.create_ata_account().await.unwrap();
+        let referrer = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        tr.mint(&referred_staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &referred_staker.pubkey()).await.unwrap();
+        tr.set_referrer(&stake_pool_owner.pubkey(), &referred_staker.pubkey(), &referrer.pubkey());
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &referred_staker, 10_000).await.unwrap();
+        // Nothing to claim before the crank
+        assert_eq!(tr.get_claimable_rewards(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 0);
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // The claimable rewards are the amounts received by the stakers, the referrer share excluded
+        for s in [&staker, &referred_staker] {
+            let claimable = tr.get_claimable_rewards(&stake_pool_owner.pubkey(), &s.pubkey()).await.unwrap();
+            assert!(claimable > 0);
+            let balance_before = tr.staker_stats(s.pubkey()).await.unwrap().balance;
+            tr.claim_staker_rewards(&stake_pool_owner.pubkey(), s).await.unwrap();
+            let balance = tr.staker_stats(s.pubkey()).await.unwrap().balance;
+            assert_eq!(balance - balance_before, claimable);
+        }
+        assert!(
+            tr.staker_stats(staker.pubkey()).await.unwrap().balance
+                > tr.staker_stats(referred_staker.pubkey()).await.unwrap().balance
+        );
+        assert_eq!(tr.get_claimable_rewards(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 0);
+    }
+}
+
+mod crank_bounty {
+    use super::*;
+
+    #[tokio::test]
+    async fn bounty_is_taken_out_of_the_pool_inflation() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        tr.change_crank_bounty(1_000).await.unwrap();
+        let stats = tr.central_state_stats().await.unwrap();
+        assert_eq!(stats.crank_bounty, 1_000);
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let cranker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        // Crank and collect the bounty
+        tr.crank_pool_with_bounty(&stake_pool_owner.pubkey(), &cranker.pubkey()).await.unwrap();
+        let stats = tr.staker_stats(cranker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 1_000);
+        // Cranking the same day again does not pay anything
+        let _ = tr.crank_pool_with_bounty(&stake_pool_owner.pubkey(), &cranker.pubkey()).await;
+        let stats = tr.staker_stats(cranker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 1_000);
+        // The pool owner gets half of what is left of the inflation
+        tr.claim_pool_rewards(&stake_pool_owner).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 499_500);
+    }
+}
+
+mod access_delegation {
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_manages_the_members() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let employee = Keypair::new();
+        let other_employee = Keypair::new();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Delegate the access
+        tr.create_access_delegation(&stake_pool_owner.pubkey(), &staker, vec![employee.pubkey()]).await.unwrap();
+        let delegation = tr.access_delegation_stats(stake_pool_owner.pubkey(), staker.pubkey()).await.unwrap().unwrap();
+        assert_eq!(delegation.tag, Tag::AccessDelegation);
+        assert!(delegation.is_member(&employee.pubkey()));
+        assert!(!delegation.is_member(&other_employee.pubkey()));
+        // Only the stake account owner can edit the members
+        assert!(tr.edit_access_delegation(&stake_pool_owner.pubkey(), &employee, vec![employee.pubkey()]).await.is_err());
+        tr.edit_access_delegation(&stake_pool_owner.pubkey(), &staker, vec![other_employee.pubkey()]).await.unwrap();
+        let delegation = tr.access_delegation_stats(stake_pool_owner.pubkey(), staker.pubkey()).await.unwrap().unwrap();
+        assert!(!delegation.is_member(&employee.pubkey()));
+        assert!(delegation.is_member(&other_employee.pubkey()));
+        // The number of members is capped
+        let too_many = (0..MAX_DELEGATION_MEMBERS + 1).map(|_| Pubkey::new_unique()).collect();
+        assert!(tr.edit_access_delegation(&stake_pool_owner.pubkey(), &staker, too_many).await.is_err());
+        // Closing revokes the access of everyone
+        tr.close_access_delegation(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let delegation = tr.access_delegation_stats(stake_pool_owner.pubkey(), staker.pubkey()).await.unwrap();
+        assert!(delegation.is_none());
+    }
+}
+
+mod allowlisted_pools {
+    use super::*;
+
+    #[tokio::test]
+    async fn only_members_can_stake() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let member = tr.create_ata_account().await.unwrap();
+        let outsider = tr.create_ata_account().await.unwrap();
+        tr.mint(&member.pubkey(), 20_400).await.unwrap();
+        // Create an allowlisted pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.set_pool_allowlist(&stake_pool_owner, true).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.allowlisted, 1);
+        // Outsiders cannot join
+        assert!(tr.create_stake_account(&stake_pool_owner.pubkey(), &outsider.pubkey()).await.is_err());
+        // Members can join and stake
+        tr.add_pool_member(&stake_pool_owner, &member.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &member.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &member, 10_000).await.unwrap();
+        // Removed members cannot top up their stake
+        tr.remove_pool_member(&stake_pool_owner, &member.pubkey()).await.unwrap();
+        tr.sleep(1).await.unwrap();
+        assert!(tr.stake(&stake_pool_owner.pubkey(), &member, 10_000).await.is_err());
+        // Everyone can join once the allowlist is turned off
+        tr.set_pool_allowlist(&stake_pool_owner, false).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &outsider.pubkey()).await.unwrap();
+    }
+}
+
+mod referrals {
+    use super::*;
+
+    #[tokio::test]
+    async fn referrer_gets_part_of_the_fees_and_rewards() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        tr.change_referral_rates(50, 10).await.unwrap();
+        let stats = tr.central_state_stats().await.unwrap();
+        assert_eq!(stats.referral_fee_rate, 50);
+        assert_eq!(stats.referral_reward_share, 10);
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let referrer = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Stake through the referrer
+        tr.set_referrer(&stake_pool_owner.pubkey(), &staker.pubkey(), &referrer.pubkey());
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        let stake_account = tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stake_account.referrer, referrer.pubkey());
+        let stats = tr.staker_stats(referrer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 100);
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // The referrer gets 10% of the staker rewards
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 450_000);
+        let stats = tr.staker_stats(referrer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 50_100);
+    }
+}
+
+mod bond_market {
+    use super::*;
+
+    #[tokio::test]
+    async fn listed_bond_is_sold_with_settled_rewards() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let seller = tr.create_ata_account().await.unwrap();
+        let buyer = tr.create_ata_account().await.unwrap();
+        let receiver = tr.create_ata_account().await.unwrap();
+        tr.mint(&seller.pubkey(), 200).await.unwrap();
+        tr.mint(&buyer.pubkey(), 1_000).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Buy a bond
+        tr.create_bond_with_quote(&stake_pool_owner.pubkey(), &seller.pubkey(), 10000, 200, 1).await.unwrap();
+        tr.claim_bond_with_quote(&stake_pool_owner.pubkey(), &seller).await.unwrap();
+        // List and cancel
+        tr.list_bond(&stake_pool_owner.pubkey(), &seller, 500).await.unwrap();
+        let listing = tr.bond_listing_stats(stake_pool_owner.pubkey(), seller.pubkey()).await.unwrap().unwrap();
+        assert_eq!(listing.price, 500);
+        assert_eq!(listing.quote_mint, tr.get_mint());
+        // A listed bond cannot be transferred
+        assert!(tr.transfer_bond(&stake_pool_owner.pubkey(), &seller, &receiver.pubkey()).await.is_err());
+        tr.cancel_bond_listing(&stake_pool_owner.pubkey(), &seller).await.unwrap();
+        assert!(tr.bond_listing_stats(stake_pool_owner.pubkey(), seller.pubkey()).await.unwrap().is_none());
+        // List again and let the rewards accrue
+        tr.list_bond(&stake_pool_owner.pubkey(), &seller, 500).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Fill the listing
+        tr.fill_bond_listing(&stake_pool_owner.pubkey(), &seller.pubkey(), &buyer).await.unwrap();
+        let bond = tr.bond_stats(seller.pubkey(), stake_pool_owner.pubkey(), 10000).await.unwrap();
+        assert_eq!(bond.tag, Tag::BondAccount);
+        assert_eq!(bond.owner, buyer.pubkey());
+        let stats = tr.staker_stats(buyer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 500);
+        // The seller got paid and kept the rewards accrued while listed
+        let stats = tr.staker_stats(seller.pubkey()).await.unwrap();
+        assert!(stats.balance > 500);
+        // The buyer can transfer the bond
+        tr.transfer_bond(&stake_pool_owner.pubkey(), &buyer, &receiver.pubkey()).await.unwrap();
+        let bond = tr.bond_stats(seller.pubkey(), stake_pool_owner.pubkey(), 10000).await.unwrap();
+        assert_eq!(bond.owner, receiver.pubkey());
+    }
+}
+
+mod bond_auctions {
+    use super::*;
+
+    #[tokio::test]
+    async fn price_decays_and_fills_create_bonds() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let early_buyer = tr.create_ata_account().await.unwrap();
+        let late_buyer = tr.create_ata_account().await.unwrap();
+        tr.mint(&early_buyer.pubkey(), 10_000).await.unwrap();
+        tr.mint(&late_buyer.pubkey(), 10_000).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // 10_000 tokens, the price of the whole lot decays from 2_000 to 1_000 over 2 days
+        let auction = tr.create_bond_auction(&stake_pool_owner.pubkey(), 10_000, 2_000, 1_000, 2 * 86400).await.unwrap();
+        // Slippage protection
+        assert!(tr.fill_bond_auction(&stake_pool_owner.pubkey(), &auction, &early_buyer, 4_000, 100).await.is_err());
+        // Fills of the same size create distinct bonds
+        let first_bond_key = tr.fill_bond_auction(&stake_pool_owner.pubkey(), &auction, &early_buyer, 2_000, 400).await.unwrap();
+        let second_bond_key = tr.fill_bond_auction(&stake_pool_owner.pubkey(), &auction, &early_buyer, 2_000, 400).await.unwrap();
+        assert_ne!(first_bond_key, second_bond_key);
+        let first_bond = tr.bond_account_stats(first_bond_key).await.unwrap();
+        assert_eq!(first_bond.tag, Tag::BondAccount);
+        assert_eq!(first_bond.total_staked, 2_000);
+        let second_bond = tr.bond_account_stats(second_bond_key).await.unwrap();
+        assert_eq!(second_bond.tag, Tag::BondAccount);
+        assert_eq!(second_bond.total_staked, 2_000);
+        let stats = tr.staker_stats(early_buyer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 10_000 - first_bond.total_quote_amount - second_bond.total_quote_amount);
+        // The price reached the floor
+        tr.sleep(2 * 86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.fill_bond_auction(&stake_pool_owner.pubkey(), &auction, &late_buyer, 6_000, 600).await.unwrap();
+        let stats = tr.staker_stats(late_buyer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 9_400);
+        let auction_stats = tr.bond_auction_stats(auction).await.unwrap();
+        assert_eq!(auction_stats.remaining_amount, 0);
+        assert_eq!(auction_stats.fill_count, 3);
+        let pool_stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(pool_stats.header.total_staked, 10_000);
+        // Nothing left to sell
+        assert!(tr.fill_bond_auction(&stake_pool_owner.pubkey(), &auction, &early_buyer, 1, 1_000).await.is_err());
+    }
+}
+
+mod bond_rounds {
+    use solana_program::keccak::hashv;
+
+    use access_protocol::state::BondRound;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn buyers_claim_their_bonds_with_a_proof() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let alice = tr.create_ata_account().await.unwrap();
+        let bob = tr.create_ata_account().await.unwrap();
+        tr.mint(&alice.pubkey(), 10_000).await.unwrap();
+        tr.mint(&bob.pubkey(), 10_000).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Three leaves tree, alice bought two bonds of the same size
+        let alice_leaf = BondRound::leaf(0, &alice.pubkey(), 5_000, 1_000);
+        let bob_leaf = BondRound::leaf(1, &bob.pubkey(), 3_000, 500);
+        let second_alice_leaf = BondRound::leaf(2, &alice.pubkey(), 5_000, 1_000);
+        let (a, b) = if alice_leaf <= bob_leaf { (alice_leaf, bob_leaf) } else { (bob_leaf, alice_leaf) };
+        let node = hashv(&[&[1], &a, &b]).0;
+        let (a, b) = if node <= second_alice_leaf { (node, second_alice_leaf) } else { (second_alice_leaf, node) };
+        let root = hashv(&[&[1], &a, &b]).0;
+        let round = tr.create_bond_round(&stake_pool_owner.pubkey(), root, 3).await.unwrap();
+        // The round is already signed by its creator
+        assert!(tr.sign_bond_round(&round).await.is_err());
+        // Wrong amounts
+        assert!(tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 0, 6_000, 1_000, vec![bob_leaf, second_alice_leaf]).await.is_err());
+        // Wrong index
+        assert!(tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 1, 5_000, 1_000, vec![bob_leaf, second_alice_leaf]).await.is_err());
+        // Wrong proof
+        assert!(tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 0, 5_000, 1_000, vec![alice_leaf, second_alice_leaf]).await.is_err());
+        let bond_key = tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 0, 5_000, 1_000, vec![bob_leaf, second_alice_leaf]).await.unwrap();
+        let bond = tr.bond_account_stats(bond_key).await.unwrap();
+        assert_eq!(bond.tag, Tag::BondAccount);
+        assert_eq!(bond.total_staked, 5_000);
+        let stats = tr.staker_stats(alice.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 9_000);
+        // A leaf can only be claimed once
+        assert!(tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 0, 5_000, 1_000, vec![bob_leaf, second_alice_leaf]).await.is_err());
+        // The second bond of alice has its own leaf
+        let second_bond_key = tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &alice, 2, 5_000, 1_000, vec![node]).await.unwrap();
+        assert_ne!(bond_key, second_bond_key);
+        let stats = tr.staker_stats(alice.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 8_000);
+        tr.claim_round_bond(&stake_pool_owner.pubkey(), &round, &bob, 1, 3_000, 500, vec![alice_leaf, second_alice_leaf]).await.unwrap();
+        let stats = tr.staker_stats(bob.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 9_500);
+        let round_stats = tr.bond_round_stats(round).await.unwrap();
+        assert!((0..3).all(|index| round_stats.is_claimed(index)));
+        let pool_stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(pool_stats.header.total_staked, 13_000);
+    }
+}
+
+mod distributions {
+    use solana_program::keccak::hashv;
+
+    use access_protocol::state::Distributor;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn recipients_claim_once_within_budget() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let alice = tr.create_ata_account().await.unwrap();
+        let bob = tr.create_ata_account().await.unwrap();
+        // Two leaves tree
+        let alice_leaf = Distributor::leaf(0, &alice.pubkey(), 1_000);
+        let bob_leaf = Distributor::leaf(1, &bob.pubkey(), 2_000);
+        let (a, b) = if alice_leaf <= bob_leaf { (alice_leaf, bob_leaf) } else { (bob_leaf, alice_leaf) };
+        let root = hashv(&[&[1], &a, &b]).0;
+        // The budget only covers Alice
+        let distributor = tr.create_distributor(root, 1_500, 2).await.unwrap();
+        // Wrong amount
+        assert!(tr.claim_distribution(&distributor, &alice.pubkey(), 0, 2_000, vec![bob_leaf]).await.is_err());
+        // Wrong recipient
+        assert!(tr.claim_distribution(&distributor, &bob.pubkey(), 0, 1_000, vec![bob_leaf]).await.is_err());
+        tr.claim_distribution(&distributor, &alice.pubkey(), 0, 1_000, vec![bob_leaf]).await.unwrap();
+        let stats = tr.staker_stats(alice.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 1_000);
+        // A leaf can only be claimed once
+        tr.sleep(1).await.unwrap();
+        assert!(tr.claim_distribution(&distributor, &alice.pubkey(), 0, 1_000, vec![bob_leaf]).await.is_err());
+        // Over budget
+        assert!(tr.claim_distribution(&distributor, &bob.pubkey(), 1, 2_000, vec![alice_leaf]).await.is_err());
+        let distributor_stats = tr.distributor_stats(distributor).await.unwrap();
+        assert_eq!(distributor_stats.total_claimed, 1_000);
+        assert!(distributor_stats.is_claimed(0));
+        assert!(!distributor_stats.is_claimed(1));
+    }
+}
+
+mod multi_claim {
+    use super::*;
+
+    #[tokio::test]
+    async fn claims_stake_accounts_and_bonds_at_once() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let first_pool_owner = tr.create_ata_account().await.unwrap();
+        let second_pool_owner = tr.create_ata_account().await.unwrap();
+        let multi_claimer = tr.create_ata_account().await.unwrap();
+        let single_claimer = tr.create_ata_account().await.unwrap();
+        tr.mint(&multi_claimer.pubkey(), 20_600).await.unwrap();
+        tr.mint(&single_claimer.pubkey(), 20_600).await.unwrap();
+        // Create and activate the pools
+        for owner in [&first_pool_owner, &second_pool_owner] {
+            tr.create_stake_pool(&owner.pubkey(), 1000).await.unwrap();
+            tr.activate_stake_pool(&owner.pubkey()).await.unwrap();
+        }
+        // Both users stake in both pools and buy a bond in the first one
+        for staker in [&multi_claimer, &single_claimer] {
+            for owner in [&first_pool_owner, &second_pool_owner] {
+                tr.create_stake_account(&owner.pubkey(), &staker.pubkey()).await.unwrap();
+                tr.stake(&owner.pubkey(), staker, 10_000).await.unwrap();
+            }
+            tr.create_bond_with_quote(&first_pool_owner.pubkey(), &staker.pubkey(), 5_000, 200, 1).await.unwrap();
+            tr.claim_bond_with_quote(&first_pool_owner.pubkey(), staker).await.unwrap();
+        }
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&first_pool_owner.pubkey()).await.unwrap();
+        tr.crank_pool(&second_pool_owner.pubkey()).await.unwrap();
+        // At least one account must be claimed
+        assert!(tr.claim_multiple_rewards(&multi_claimer, &[], &[]).await.is_err());
+        // The single claimer claims every account separately
+        tr.claim_staker_rewards(&first_pool_owner.pubkey(), &single_claimer).await.unwrap();
+        tr.claim_staker_rewards(&second_pool_owner.pubkey(), &single_claimer).await.unwrap();
+        tr.claim_bond_rewards(&first_pool_owner.pubkey(), &single_claimer).await.unwrap();
+        tr.claim_multiple_rewards(&multi_claimer, &[first_pool_owner.pubkey(), second_pool_owner.pubkey()], &[first_pool_owner.pubkey()]).await.unwrap();
+        // The same rewards as separate claims
+        let multi_stats = tr.staker_stats(multi_claimer.pubkey()).await.unwrap();
+        let single_stats = tr.staker_stats(single_claimer.pubkey()).await.unwrap();
+        assert_eq!(multi_stats.balance, single_stats.balance);
+        // Nothing left to claim
+        tr.sleep(1).await.unwrap();
+        assert!(tr.claim_multiple_rewards(&multi_claimer, &[first_pool_owner.pubkey(), second_pool_owner.pubkey()], &[first_pool_owner.pubkey()]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn referrers_get_their_share() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        tr.change_referral_rates(50, 10).await.unwrap();
+        // Create users
+        let first_pool_owner = tr.create_ata_account().await.unwrap();
+        let second_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let referrer = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 20_400).await.unwrap();
+        // Create and activate the pools
+        for owner in [&first_pool_owner, &second_pool_owner] {
+            tr.create_stake_pool(&owner.pubkey(), 1000).await.unwrap();
+            tr.activate_stake_pool(&owner.pubkey()).await.unwrap();
+            tr.create_stake_account(&owner.pubkey(), &staker.pubkey()).await.unwrap();
+        }
+        // Only the stake in the first pool is referred
+        tr.set_referrer(&first_pool_owner.pubkey(), &staker.pubkey(), &referrer.pubkey());
+        tr.stake(&first_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.stake(&second_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        let stats = tr.staker_stats(referrer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 100);
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&first_pool_owner.pubkey()).await.unwrap();
+        tr.crank_pool(&second_pool_owner.pubkey()).await.unwrap();
+        tr.claim_multiple_rewards(&staker, &[first_pool_owner.pubkey(), second_pool_owner.pubkey()], &[]).await.unwrap();
+        // The referrer gets 10% of the rewards of the first pool
+        let stats = tr.staker_stats(staker.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 225_000 + 250_000);
+        let stats = tr.staker_stats(referrer.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 100 + 25_000);
+    }
+}
+
+mod user_index {
+    use super::*;
+
+    #[tokio::test]
+    async fn tracks_stake_accounts_and_bonds() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let first_pool_owner = tr.create_ata_account().await.unwrap();
+        let second_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        let other = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 200).await.unwrap();
+        // Create and activate the pools
+        for owner in [&first_pool_owner, &second_pool_owner] {
+            tr.create_stake_pool(&owner.pubkey(), 1000).await.unwrap();
+            tr.activate_stake_pool(&owner.pubkey()).await.unwrap();
+        }
+        // Index the existing stake account
+        tr.create_stake_account(&first_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        let first_pool_key = tr.get_pool_pda(&first_pool_owner.pubkey());
+        let (first_stake_key, _) = tr.get_stake_account_pda(&first_pool_key, &staker.pubkey());
+        // Positions of another wallet cannot be indexed
+        assert!(tr.create_user_index(&other, vec![first_stake_key]).await.is_err());
+        tr.create_user_index(&staker, vec![first_stake_key]).await.unwrap();
+        // New positions are indexed
+        tr.create_stake_account(&second_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.create_bond_with_quote(&first_pool_owner.pubkey(), &staker.pubkey(), 5_000, 200, 1).await.unwrap();
+        tr.claim_bond_with_quote(&first_pool_owner.pubkey(), &staker).await.unwrap();
+        let index = tr.user_index_stats(staker.pubkey()).await.unwrap().unwrap();
+        assert_eq!(index.tag, Tag::UserIndex);
+        assert_eq!(index.owner, staker.pubkey());
+        assert_eq!(index.stake_accounts.len(), 2);
+        assert_eq!(index.stake_accounts[0], first_stake_key);
+        assert_eq!(index.bond_accounts.len(), 1);
+        // Closed stake accounts are removed
+        tr.close_stake_account(&first_pool_owner.pubkey(), &staker).await.unwrap();
+        let index = tr.user_index_stats(staker.pubkey()).await.unwrap().unwrap();
+        assert_eq!(index.stake_accounts.len(), 1);
+        assert_ne!(index.stake_accounts[0], first_stake_key);
+        // Close the index
+        tr.close_user_index(&staker).await.unwrap();
+        assert!(tr.user_index_stats(staker.pubkey()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn follows_bond_sales_and_transfers() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let seller = tr.create_ata_account().await.unwrap();
+        let buyer = tr.create_ata_account().await.unwrap();
+        let receiver = tr.create_ata_account().await.unwrap();
+        tr.mint(&seller.pubkey(), 200).await.unwrap();
+        tr.mint(&buyer.pubkey(), 1_000).await.unwrap();
+        for owner in [&seller, &buyer, &receiver] {
+            tr.create_user_index(owner, vec![]).await.unwrap();
+        }
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 10000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Buy a bond
+        tr.create_bond_with_quote(&stake_pool_owner.pubkey(), &seller.pubkey(), 10000, 200, 1).await.unwrap();
+        tr.claim_bond_with_quote(&stake_pool_owner.pubkey(), &seller).await.unwrap();
+        let index = tr.user_index_stats(seller.pubkey()).await.unwrap().unwrap();
+        assert_eq!(index.bond_accounts.len(), 1);
+        let bond_key = index.bond_accounts[0];
+        // Sell the bond
+        tr.list_bond(&stake_pool_owner.pubkey(), &seller, 500).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.fill_bond_listing(&stake_pool_owner.pubkey(), &seller.pubkey(), &buyer).await.unwrap();
+        let index = tr.user_index_stats(seller.pubkey()).await.unwrap().unwrap();
+        assert!(index.bond_accounts.is_empty());
+        let index = tr.user_index_stats(buyer.pubkey()).await.unwrap().unwrap();
+        assert_eq!(index.bond_accounts, vec![bond_key]);
+        // Transfer the bond
+        tr.transfer_bond(&stake_pool_owner.pubkey(), &buyer, &receiver.pubkey()).await.unwrap();
+        let index = tr.user_index_stats(buyer.pubkey()).await.unwrap().unwrap();
+        assert!(index.bond_accounts.is_empty());
+        let index = tr.user_index_stats(receiver.pubkey()).await.unwrap().unwrap();
+        assert_eq!(index.bond_accounts, vec![bond_key]);
+    }
+}
+
+mod pool_statistics {
+    use super::*;
+
+    #[tokio::test]
+    async fn counters_track_stakers_bonds_rewards_and_fees() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let first_staker = tr.create_ata_account().await.unwrap();
+        let second_staker = tr.create_ata_account().await.unwrap();
+        let bond_holder = tr.create_ata_account().await.unwrap();
+        tr.mint(&first_staker.pubkey(), 10_200).await.unwrap();
+        tr.mint(&second_staker.pubkey(), 10_200).await.unwrap();
+        tr.mint(&bond_holder.pubkey(), 200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Stake
+        for staker in [&first_staker, &second_staker] {
+            tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+            tr.stake(&stake_pool_owner.pubkey(), staker, 10_000).await.unwrap();
+        }
+        // Buy a bond
+        tr.create_bond_with_quote(&stake_pool_owner.pubkey(), &bond_holder.pubkey(), 5_000, 200, 1).await.unwrap();
+        tr.claim_bond_with_quote(&stake_pool_owner.pubkey(), &bond_holder).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.stakers_count, 2);
+        assert_eq!(stats.header.bond_holders_count, 1);
+        assert_eq!(stats.header.lifetime_fees, 400);
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Claim
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &first_staker).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &second_staker).await.unwrap();
+        tr.claim_bond_rewards(&stake_pool_owner.pubkey(), &bond_holder).await.unwrap();
+        tr.claim_pool_rewards(&stake_pool_owner).await.unwrap();
+        let first_stats = tr.staker_stats(first_staker.pubkey()).await.unwrap();
+        let second_stats = tr.staker_stats(second_staker.pubkey()).await.unwrap();
+        let bond_stats = tr.staker_stats(bond_holder.pubkey()).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.lifetime_staker_rewards, first_stats.balance + second_stats.balance + bond_stats.balance);
+        assert_eq!(stats.header.lifetime_owner_rewards, stats.balance);
+        // Unstaking everything removes the staker
+        tr.unstake(&stake_pool_owner.pubkey(), &second_staker, 10_000).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.stakers_count, 1);
+    }
+}
+
+mod pool_deprecation {
+    use super::*;
+
+    #[tokio::test]
+    async fn deprecated_pool_can_be_wound_down() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Stake
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Wait 1 day
+        tr.sleep(86400).await.unwrap();
+        // The pool has to be cranked before being deprecated
+        assert!(tr.deprecate_stake_pool(&stake_pool_owner.pubkey()).await.is_err());
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.deprecate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.tag, Tag::DeprecatedStakePool as u8);
+        assert_eq!(stats.header.pool_weight, 0);
+        assert_eq!(tr.central_state_stats().await.unwrap().total_weighted_staked, 0);
+        // No new stakes
+        assert!(tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.is_err());
+        // The rewards of the past day can still be claimed
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        let balance = tr.staker_stats(staker.pubkey()).await.unwrap().balance;
+        assert!(balance > 10_200);
+        // Cranks don't give any more rewards
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        assert_eq!(tr.staker_stats(staker.pubkey()).await.unwrap().balance, balance);
+        // The pool can't be closed until everybody unstaked
+        assert!(tr.close_stake_pool(&stake_pool_owner).await.is_err());
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.close_stake_pool(&stake_pool_owner).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn frozen_pool_can_be_deprecated() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        // Stake
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        // Freeze the pool and let it miss a crank
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        tr.admin_freeze(&[stake_pool_key], 0, 0).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        assert!(tr.crank_pool(&stake_pool_owner.pubkey()).await.is_err());
+        assert!(tr.freeze_record_stats(&stake_pool_key).await.unwrap().is_some());
+        // The frozen pool is deprecated without being cranked, its freeze record is closed
+        tr.deprecate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        assert!(tr.freeze_record_stats(&stake_pool_key).await.unwrap().is_none());
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.tag, Tag::DeprecatedStakePool as u8);
+        assert_eq!(stats.header.pool_weight, 0);
+        assert_eq!(tr.central_state_stats().await.unwrap().total_weighted_staked, 0);
+        // The stakers can leave
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.close_stake_pool(&stake_pool_owner).await.unwrap();
+    }
+}
+
+mod self_activation {
+    use super::*;
+
+    #[tokio::test]
+    async fn deposit_is_locked_until_the_pool_is_closed() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&stake_pool_owner.pubkey(), 5_000).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        // Self activation is disabled by default
+        assert!(tr.self_activate_stake_pool(&stake_pool_owner).await.is_err());
+        tr.change_activation_deposit(5_000).await.unwrap();
+        tr.self_activate_stake_pool(&stake_pool_owner).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.tag, Tag::StakePool as u8);
+        assert_eq!(stats.header.activation_deposit, 5_000);
+        assert_eq!(stats.balance, 0);
+        // The pool can't be activated twice
+        assert!(tr.self_activate_stake_pool(&stake_pool_owner).await.is_err());
+        // Stake
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert!(tr.close_stake_pool(&stake_pool_owner).await.is_err());
+        // The deposit is refunded once the pool is empty
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.close_stake_pool(&stake_pool_owner).await.unwrap();
+        assert_eq!(tr.staker_stats(stake_pool_owner.pubkey()).await.unwrap().balance, 5_000);
+    }
+}
+
+mod admin_log {
+    use super::*;
+
+    #[tokio::test]
+    async fn admin_actions_are_recorded() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        // Only the admin log address can fill its slot before it is created
+        let (admin_log_key, _) = AdminLog::find_key(&tr.program_id);
+        assert!(tr
+            .change_inflation_with_admin_log(1_000, Some(&stake_pool_key))
+            .await
+            .is_err());
+        tr.change_inflation_with_admin_log(1_000, Some(&admin_log_key))
+            .await
+            .unwrap();
+        // Create the log
+        tr.create_admin_log().await.unwrap();
+        assert!(tr
+            .change_inflation_with_admin_log(1_000, Some(&stake_pool_key))
+            .await
+            .is_err());
+        assert!(tr.create_admin_log().await.is_err());
+        // Admin actions
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_000).await.unwrap();
+        tr.change_inflation(1_000).await.unwrap();
+        tr.admin_freeze(&[stake_pool_key], 0, 0).await.unwrap();
+        tr.sleep(1).await.unwrap();
+        tr.admin_freeze(&[stake_pool_key], 0, 0).await.unwrap();
+        let log = tr.admin_log_stats().await.unwrap();
+        assert_eq!(log.total_entries, 5);
+        let actions = log.entries_in_order().iter().map(|e| e.action).collect::<Vec<_>>();
+        assert_eq!(
+            actions,
+            vec![
+                AdminAction::ActivateStakePool,
+                AdminAction::Mint,
+                AdminAction::ChangeInflation,
+                AdminAction::Freeze,
+                AdminAction::Unfreeze,
+            ]
+        );
+        assert_eq!(log.entries[0].target, stake_pool_key);
+        assert_ne!(log.entries[1].params_hash, log.entries[2].params_hash);
+    }
+}
+
+mod freeze_records {
+    use super::*;
+
+    #[tokio::test]
+    async fn freezes_record_a_reason_and_expire() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let first_staker = tr.create_ata_account().await.unwrap();
+        let second_staker = tr.create_ata_account().await.unwrap();
+        // Create the pool and the stake accounts
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &first_staker.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &second_staker.pubkey()).await.unwrap();
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        let (first_key, _) = tr.get_stake_account_pda(&stake_pool_key, &first_staker.pubkey());
+        let (second_key, _) = tr.get_stake_account_pda(&stake_pool_key, &second_staker.pubkey());
+        // The expiry must be in the future
+        let now = tr.get_current_time().await;
+        assert!(tr.admin_freeze(&[first_key], 3, now - 1).await.is_err());
+        // Bulk freeze
+        tr.admin_freeze(&[first_key, second_key], 3, now + 1_000).await.unwrap();
+        for key in [first_key, second_key] {
+            assert_eq!(tr.account_tag(&key).await.unwrap(), Tag::FrozenStakeAccount as u8);
+            let record = tr.freeze_record_stats(&key).await.unwrap().unwrap();
+            assert_eq!(record.reason, 3);
+            assert_eq!(record.expiry, now + 1_000);
+        }
+        // The freeze can't be lifted by anyone before the expiry
+        assert!(tr.expire_freeze(&first_key).await.is_err());
+        tr.sleep(1_001).await.unwrap();
+        tr.expire_freeze(&first_key).await.unwrap();
+        assert_eq!(tr.account_tag(&first_key).await.unwrap(), Tag::StakeAccount as u8);
+        assert!(tr.freeze_record_stats(&first_key).await.unwrap().is_none());
+        // The authority can unfreeze at any time
+        tr.admin_freeze(&[second_key], 0, 0).await.unwrap();
+        assert_eq!(tr.account_tag(&second_key).await.unwrap(), Tag::StakeAccount as u8);
+        assert!(tr.freeze_record_stats(&second_key).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn expired_pool_freeze_is_lifted() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Freeze the pool until tomorrow
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        let now = tr.get_current_time().await;
+        tr.admin_freeze(&[stake_pool_key], 1, now + 1_000).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.freeze_expiry, now + 1_000);
+        assert!(tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.is_err());
+        // Once the freeze expired the pool is active before the record is closed
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert_eq!(tr.account_tag(&stake_pool_key).await.unwrap(), Tag::FrozenStakePool as u8);
+        // Closing the record clears the expiry
+        tr.expire_freeze(&stake_pool_key).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.header.tag, Tag::StakePool as u8);
+        assert_eq!(stats.header.freeze_expiry, 0);
+        // A freeze without an expiry lasts until it is lifted
+        tr.admin_freeze(&[stake_pool_key], 0, 0).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        assert!(tr.crank_pool(&stake_pool_owner.pubkey()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unfreezing_closes_the_record() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create the pool and the stake account
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        let (stake_account_key, _) = tr.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        // The record can't be left behind by an unfreeze
+        let now = tr.get_current_time().await;
+        tr.admin_freeze(&[stake_account_key], 2, now + 1_000).await.unwrap();
+        assert!(tr.legacy_admin_freeze(&stake_account_key).await.is_err());
+        tr.admin_freeze(&[stake_account_key], 0, 0).await.unwrap();
+        assert!(tr.freeze_record_stats(&stake_account_key).await.unwrap().is_none());
+        // A later freeze without a record can't be lifted by anyone
+        tr.legacy_admin_freeze(&stake_account_key).await.unwrap();
+        tr.sleep(1_001).await.unwrap();
+        assert!(tr.expire_freeze(&stake_account_key).await.is_err());
+        tr.admin_freeze(&[stake_account_key], 0, 0).await.unwrap();
+        // The account can be frozen with a record again
+        let now = tr.get_current_time().await;
+        tr.admin_freeze(&[stake_account_key], 2, now + 1_000).await.unwrap();
+        // Stake accounts stay frozen after the expiry until the freeze is lifted
+        tr.sleep(1_001).await.unwrap();
+        assert!(tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.is_err());
+        tr.expire_freeze(&stake_account_key).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+    }
+}
+
+mod receipts {
+    use super::*;
+
+    #[tokio::test]
+    async fn receipt_follows_the_stake_account() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Only subscribers get a receipt
+        assert!(tr.mint_receipt(&stake_pool_owner.pubkey(), &staker, "https://example.com/receipt/1.json").await.is_err());
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.mint_receipt(&stake_pool_owner.pubkey(), &staker, "https://example.com/receipt/2.json").await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 1);
+        assert!(tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap().has_receipt);
+        tr.sleep(1).await.unwrap();
+        assert!(tr.mint_receipt(&stake_pool_owner.pubkey(), &staker, "https://example.com/receipt/3.json").await.is_err());
+        // The receipt can't be burnt by others while the stake account has access
+        assert!(tr.burn_receipt(&stake_pool_owner.pubkey(), &staker.pubkey(), None).await.is_err());
+        // The receipt must be burnt or passed to the unstake before losing access
+        assert!(tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.is_err());
+        tr.burn_receipt(&stake_pool_owner.pubkey(), &staker.pubkey(), Some(&staker)).await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 0);
+        assert!(!tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap().has_receipt);
+        // A burnt receipt can be minted again
+        tr.mint_receipt(&stake_pool_owner.pubkey(), &staker, "https://example.com/receipt/4.json").await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 1);
+        // Anyone can burn the receipts of a deprecated pool
+        tr.deprecate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.burn_receipt(&stake_pool_owner.pubkey(), &staker.pubkey(), None).await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 0);
+        // The stake account can then be emptied and closed
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.close_stake_account(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn receipt_is_burnt_with_the_access() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 10_200).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        tr.mint_receipt(&stake_pool_owner.pubkey(), &staker, "https://example.com/receipt/1.json").await.unwrap();
+        // Unstaking above the minimum keeps the receipt
+        tr.unstake_with_receipt(&stake_pool_owner.pubkey(), &staker, 8_000).await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 1);
+        assert!(tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap().has_receipt);
+        // Going below the minimum burns the receipt in the same instruction
+        tr.unstake_with_receipt(&stake_pool_owner.pubkey(), &staker, 2_000).await.unwrap();
+        assert_eq!(tr.receipt_balance(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap(), 0);
+        let stats = tr.stake_account_stats(staker.pubkey(), stake_pool_owner.pubkey()).await.unwrap();
+        assert!(!stats.has_receipt);
+        assert_eq!(stats.stake_amount, 0);
+        assert_eq!(tr.staker_stats(staker.pubkey()).await.unwrap().balance, 10_000);
+    }
+}
+
+mod legacy_clients {
+    use super::*;
+
+    #[tokio::test]
+    async fn stake_and_unstake_without_the_mint() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create a pool and a stake account
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_000).await.unwrap();
+        // The legacy encoding still works for SPL token mints
+        tr.stake_legacy(&stake_pool_owner.pubkey(), &staker, 5_000).await.unwrap();
+        assert_eq!(tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap().header.total_staked, 5_000);
+        tr.sleep(1).await.unwrap();
+        tr.unstake_legacy(&stake_pool_owner.pubkey(), &staker, 5_000).await.unwrap();
+        assert_eq!(tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap().header.total_staked, 0);
+    }
+}
+
+mod migrations {
+    use super::*;
+
+    #[tokio::test]
+    async fn legacy_accounts_are_migrated() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // Create a pool with a staker
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        tr.mint(&staker.pubkey(), 10_000).await.unwrap();
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 5_000).await.unwrap();
+        let weighted_staked = tr.central_state_stats().await.unwrap().total_weighted_staked;
+        // Rewrite the accounts with their legacy layout
+        let central_state_key = tr.get_central_state();
+        let stake_pool_key = tr.get_pool_pda(&stake_pool_owner.pubkey());
+        let (stake_account_key, _) = tr.get_stake_account_pda(&stake_pool_key, &staker.pubkey());
+        for key in [central_state_key, stake_pool_key, stake_account_key] {
+            tr.set_legacy_layout(&key).await.unwrap();
+        }
+        // Legacy accounts are rejected until they are migrated
+        tr.sleep(1).await.unwrap();
+        assert!(tr.stake(&stake_pool_owner.pubkey(), &staker, 1_000).await.is_err());
+        // The central state is migrated first
+        assert!(tr.migrate_account(&stake_pool_key).await.is_err());
+        tr.migrate_account(&central_state_key).await.unwrap();
+        assert_eq!(tr.account_len(&central_state_key).await.unwrap(), CentralState::LEN);
+        tr.migrate_account(&stake_pool_key).await.unwrap();
+        assert_eq!(tr.account_len(&stake_pool_key).await.unwrap(), StakePoolHeader::ACCOUNT_LEN);
+        tr.migrate_account(&stake_account_key).await.unwrap();
+        assert_eq!(tr.account_len(&stake_account_key).await.unwrap(), StakeAccount::LEN);
+        // Migrated accounts can't be migrated again
+        assert!(tr.migrate_account(&stake_account_key).await.is_err());
+        // The pool keeps its stake and counts in the weighted stake again
+        let pool_stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(pool_stats.header.total_staked, 5_000);
+        assert_eq!(pool_stats.header.pool_weight, DEFAULT_POOL_WEIGHT);
+        assert_eq!(
+            tr.central_state_stats().await.unwrap().total_weighted_staked,
+            weighted_staked
+        );
+        // The migrated accounts work as before
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 1_000).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        assert!(tr.staker_stats(staker.pubkey()).await.unwrap().balance > 4_000);
+    }
+
+    #[tokio::test]
+    async fn partially_migrated_pools_share_the_inflation() {
+        // Setup the token + basic accounts
+        let mut tr = TestRunner::new(1_000_000).await.unwrap();
+        // Create users
+        let pool_owner = tr.create_ata_account().await.unwrap();
+        let pool_owner2 = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        tr.mint(&staker.pubkey(), 20_400).await.unwrap();
+        // Create two pools with the same stake
+        for owner in [&pool_owner, &pool_owner2] {
+            tr.create_stake_pool(&owner.pubkey(), 1000).await.unwrap();
+            tr.activate_stake_pool(&owner.pubkey()).await.unwrap();
+            tr.create_stake_account(&owner.pubkey(), &staker.pubkey()).await.unwrap();
+            tr.stake(&owner.pubkey(), &staker, 10_000).await.unwrap();
+        }
+        // Rewrite the accounts with their legacy layout
+        let central_state_key = tr.get_central_state();
+        let stake_pool_key = tr.get_pool_pda(&pool_owner.pubkey());
+        let stake_pool_key2 = tr.get_pool_pda(&pool_owner2.pubkey());
+        for key in [central_state_key, stake_pool_key, stake_pool_key2] {
+            tr.set_legacy_layout(&key).await.unwrap();
+        }
+        // The central state migration weights the stake of both pools
+        tr.migrate_account(&central_state_key).await.unwrap();
+        let stats = tr.central_state_stats().await.unwrap();
+        assert_eq!(stats.total_weighted_staked, 20_000 * DEFAULT_POOL_WEIGHT as u128);
+        // Only the first pool is migrated and cranked
+        tr.migrate_account(&stake_pool_key).await.unwrap();
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&pool_owner.pubkey()).await.unwrap();
+        assert!(tr.crank_pool(&pool_owner2.pubkey()).await.is_err());
+        // It only gets its share of the inflation
+        tr.claim_pool_rewards(&pool_owner).await.unwrap();
+        let stats = tr.pool_stats(pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.balance, 250_000);
+    }
+}
+
+mod token_2022 {
+    use super::*;
+
+    #[tokio::test]
+    async fn transfer_fees_are_paid_on_top() {
+        // Setup a Token-2022 ACCESS mint with a 1% transfer fee
+        let mut tr = TestRunner::new_with_transfer_fee(1_000_000, 100).await.unwrap();
+        // Create users
+        let stake_pool_owner = tr.create_ata_account().await.unwrap();
+        let staker = tr.create_ata_account().await.unwrap();
+        // 10_000 staked + 200 stake fees, each transfer pays its own transfer fee
+        tr.mint(&staker.pubkey(), 10_102 + 203).await.unwrap();
+        // Create and activate the pool
+        tr.create_stake_pool(&stake_pool_owner.pubkey(), 1000).await.unwrap();
+        tr.activate_stake_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.create_stake_account(&stake_pool_owner.pubkey(), &staker.pubkey()).await.unwrap();
+        // Token-2022 tokens can't be staked without the mint
+        assert!(tr.stake_legacy(&stake_pool_owner.pubkey(), &staker, 10_000).await.is_err());
+        // The vault receives the exact staked amount
+        tr.stake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.total_pool_staked, 10_000);
+        assert_eq!(stats.header.total_staked, 10_000);
+        assert_eq!(tr.staker_stats(staker.pubkey()).await.unwrap().balance, 0);
+        let authority_ata = tr.get_ata(&tr.get_authority());
+        assert_eq!(tr.token_balance(&authority_ata).await.unwrap(), 200);
+        // Rewards are minted and pay no transfer fee
+        tr.sleep(86400).await.unwrap();
+        tr.crank_pool(&stake_pool_owner.pubkey()).await.unwrap();
+        tr.claim_staker_rewards(&stake_pool_owner.pubkey(), &staker).await.unwrap();
+        assert_eq!(tr.staker_stats(staker.pubkey()).await.unwrap().balance, 500_000);
+        // The transfer fee of the unstaked tokens is withheld from the staker
+        tr.unstake(&stake_pool_owner.pubkey(), &staker, 10_000).await.unwrap();
+        assert_eq!(tr.staker_stats(staker.pubkey()).await.unwrap().balance, 509_900);
+        let stats = tr.pool_stats(stake_pool_owner.pubkey()).await.unwrap();
+        assert_eq!(stats.total_pool_staked, 0);
+    }
+}
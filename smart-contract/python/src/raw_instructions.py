@@ -1,9 +1,16 @@
-from typing import List
-from borsh_construct import U8, String, CStruct
+from typing import List, Optional
+from borsh_construct import U8, U16, U32, U64, U128, I64, Bool, String, Vec, Option, CStruct
 from solana.transaction import TransactionInstruction, AccountMeta
 from solana.publickey import PublicKey
 
 
+VestingSchedule = CStruct(
+    "cliff_date" / I64,
+    "cliff_amount" / U64,
+    "linear_end_date" / I64,
+)
+
+
 class CloseStakePoolInstruction:
     schema = CStruct(
         "tag" / U8,
@@ -12,21 +19,36 @@ class CloseStakePoolInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 10,
+            "tag": 9,
         })
 
     def getInstruction(self,
                        programId: PublicKey,
                        stake_pool_account: PublicKey,
+                       pool_vault: PublicKey,
                        owner: PublicKey,
+                       deposit_destination: Optional[PublicKey],
+                       spl_token_program: Optional[PublicKey],
+                       mint: Optional[PublicKey],
                        ) -> TransactionInstruction:
         data = self.serialize(
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(stake_pool_account,
                                 False, True))
+        keys.append(AccountMeta(pool_vault,
+                                False, True))
         keys.append(AccountMeta(owner,
                                 True, True))
+        if deposit_destination is not None:
+            keys.append(AccountMeta(deposit_destination,
+                                    False, True))
+        if spl_token_program is not None:
+            keys.append(AccountMeta(spl_token_program,
+                                    False, False))
+        if mint is not None:
+            keys.append(AccountMeta(mint,
+                                    False, False))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -40,7 +62,7 @@ class ChangeInflationInstruction:
                   daily_inflation: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 12,
+            "tag": 11,
             "daily_inflation": daily_inflation,
         })
 
@@ -48,6 +70,7 @@ class ChangeInflationInstruction:
                        programId: PublicKey,
                        central_state: PublicKey,
                        authority: PublicKey,
+                       admin_log: Optional[PublicKey],
                        daily_inflation: int,
                        ) -> TransactionInstruction:
         data = self.serialize(
@@ -58,6 +81,9 @@ class ChangeInflationInstruction:
                                 False, True))
         keys.append(AccountMeta(authority,
                                 True, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -69,7 +95,7 @@ class UnlockBondTokensInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 15,
+            "tag": 14,
         })
 
     def getInstruction(self,
@@ -108,12 +134,21 @@ class UnlockBondTokensInstruction:
 class AdminFreezeInstruction:
     schema = CStruct(
         "tag" / U8,
+        "reason" / U8,
+        "expiry" / I64,
+        "has_admin_log" / Bool,
     )
 
     def serialize(self,
+                  reason: int,
+                  expiry: int,
+                  has_admin_log: bool,
                   ) -> str:
         return self.schema.build({
-            "tag": 20,
+            "tag": 19,
+            "reason": reason,
+            "expiry": expiry,
+            "has_admin_log": has_admin_log,
         })
 
     def getInstruction(self,
@@ -121,8 +156,19 @@ class AdminFreezeInstruction:
                        authority: PublicKey,
                        account_to_freeze: PublicKey,
                        central_state: PublicKey,
+                       admin_log: Optional[PublicKey],
+                       system_program: Optional[PublicKey],
+                       fee_payer: Optional[PublicKey],
+                       freeze_record: Optional[PublicKey],
+                       accounts: List[PublicKey],
+                       reason: int,
+                       expiry: int,
+                       has_admin_log: bool,
                        ) -> TransactionInstruction:
         data = self.serialize(
+            reason,
+            expiry,
+            has_admin_log,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(authority,
@@ -131,6 +177,21 @@ class AdminFreezeInstruction:
                                 False, True))
         keys.append(AccountMeta(central_state,
                                 False, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
+        if system_program is not None:
+            keys.append(AccountMeta(system_program,
+                                    False, False))
+        if fee_payer is not None:
+            keys.append(AccountMeta(fee_payer,
+                                    True, True))
+        if freeze_record is not None:
+            keys.append(AccountMeta(freeze_record,
+                                    False, True))
+        for k in accounts:
+            keys.append(AccountMeta(k,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -144,7 +205,7 @@ class ChangePoolMinimumInstruction:
                   new_minimum: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 18,
+            "tag": 17,
             "new_minimum": new_minimum,
         })
 
@@ -173,7 +234,7 @@ class ClaimPoolRewardsInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 7,
+            "tag": 6,
         })
 
     def getInstruction(self,
@@ -213,7 +274,7 @@ class SignBondInstruction:
                   seller_index: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 14,
+            "tag": 13,
             "seller_index": seller_index,
         })
 
@@ -237,12 +298,15 @@ class SignBondInstruction:
 class ClaimRewardsInstruction:
     schema = CStruct(
         "tag" / U8,
+        "allow_zero_rewards" / Bool,
     )
 
     def serialize(self,
+                  allow_zero_rewards: bool,
                   ) -> str:
         return self.schema.build({
-            "tag": 8,
+            "tag": 7,
+            "allow_zero_rewards": allow_zero_rewards,
         })
 
     def getInstruction(self,
@@ -254,8 +318,11 @@ class ClaimRewardsInstruction:
                        central_state: PublicKey,
                        mint: PublicKey,
                        spl_token_program: PublicKey,
+                       referrer_rewards_destination: Optional[PublicKey],
+                       allow_zero_rewards: bool,
                        ) -> TransactionInstruction:
         data = self.serialize(
+            allow_zero_rewards,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(stake_pool,
@@ -272,6 +339,9 @@ class ClaimRewardsInstruction:
                                 False, True))
         keys.append(AccountMeta(spl_token_program,
                                 False, False))
+        if referrer_rewards_destination is not None:
+            keys.append(AccountMeta(referrer_rewards_destination,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -279,14 +349,26 @@ class StakeInstruction:
     schema = CStruct(
         "tag" / U8,
         "amount" / U64,
+        "has_bond_account" / Bool,
+        "has_mint" / Bool,
+        "has_pool_membership" / Bool,
+        "has_referrer_token" / Bool,
     )
 
     def serialize(self,
                   amount: int,
+                  has_bond_account: bool,
+                  has_mint: bool,
+                  has_pool_membership: bool,
+                  has_referrer_token: bool,
                   ) -> str:
         return self.schema.build({
             "tag": 4,
             "amount": amount,
+            "has_bond_account": has_bond_account,
+            "has_mint": has_mint,
+            "has_pool_membership": has_pool_membership,
+            "has_referrer_token": has_referrer_token,
         })
 
     def getInstruction(self,
@@ -298,10 +380,24 @@ class StakeInstruction:
                        source_token: PublicKey,
                        spl_token_program: PublicKey,
                        vault: PublicKey,
+                       fee_account: PublicKey,
+                       bond_account: Optional[PublicKey],
+                       mint: Optional[PublicKey],
+                       pool_membership: Optional[PublicKey],
+                       referrer_token: Optional[PublicKey],
+                       campaign_positions: List[PublicKey],
                        amount: int,
+                       has_bond_account: bool,
+                       has_mint: bool,
+                       has_pool_membership: bool,
+                       has_referrer_token: bool,
                        ) -> TransactionInstruction:
         data = self.serialize(
             amount,
+            has_bond_account,
+            has_mint,
+            has_pool_membership,
+            has_referrer_token,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(central_state_account,
@@ -318,6 +414,23 @@ class StakeInstruction:
                                 False, False))
         keys.append(AccountMeta(vault,
                                 False, True))
+        keys.append(AccountMeta(fee_account,
+                                False, True))
+        if bond_account is not None:
+            keys.append(AccountMeta(bond_account,
+                                    False, False))
+        if mint is not None:
+            keys.append(AccountMeta(mint,
+                                    False, False))
+        if pool_membership is not None:
+            keys.append(AccountMeta(pool_membership,
+                                    False, False))
+        if referrer_token is not None:
+            keys.append(AccountMeta(referrer_token,
+                                    False, True))
+        for k in campaign_positions:
+            keys.append(AccountMeta(k,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -335,12 +448,12 @@ class CreateCentralStateInstruction:
         return self.schema.build({
             "tag": 0,
             "daily_inflation": daily_inflation,
-            "authority": authority,
+            "authority": bytes(authority),
         })
 
     def getInstruction(self,
                        programId: PublicKey,
-                       state_account: PublicKey,
+                       central_state: PublicKey,
                        system_program: PublicKey,
                        fee_payer: PublicKey,
                        mint: PublicKey,
@@ -352,7 +465,7 @@ class CreateCentralStateInstruction:
             authority,
         )
         keys: List[AccountMeta] = []
-        keys.append(AccountMeta(state_account,
+        keys.append(AccountMeta(central_state,
                                 False, True))
         keys.append(AccountMeta(system_program,
                                 False, False))
@@ -373,7 +486,7 @@ class ChangePoolMultiplierInstruction:
                   new_multiplier: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 21,
+            "tag": 20,
             "new_multiplier": new_multiplier,
         })
 
@@ -402,13 +515,14 @@ class CloseStakeAccountInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 11,
+            "tag": 10,
         })
 
     def getInstruction(self,
                        programId: PublicKey,
                        stake_account: PublicKey,
                        owner: PublicKey,
+                       user_index: Optional[PublicKey],
                        ) -> TransactionInstruction:
         data = self.serialize(
         )
@@ -417,6 +531,9 @@ class CloseStakeAccountInstruction:
                                 False, True))
         keys.append(AccountMeta(owner,
                                 True, True))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -428,13 +545,16 @@ class CrankInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 9,
+            "tag": 8,
         })
 
     def getInstruction(self,
                        programId: PublicKey,
                        stake_pool: PublicKey,
                        central_state: PublicKey,
+                       bounty_destination: Optional[PublicKey],
+                       mint: Optional[PublicKey],
+                       spl_token_program: Optional[PublicKey],
                        ) -> TransactionInstruction:
         data = self.serialize(
         )
@@ -442,57 +562,31 @@ class CrankInstruction:
         keys.append(AccountMeta(stake_pool,
                                 False, True))
         keys.append(AccountMeta(central_state,
-                                False, False))
-        return TransactionInstruction(keys, programId, data)
-
-
-class ExecuteUnstakeInstruction:
-    schema = CStruct(
-        "tag" / U8,
-    )
-
-    def serialize(self,
-                  ) -> str:
-        return self.schema.build({
-            "tag": 6,
-        })
-
-    def getInstruction(self,
-                       programId: PublicKey,
-                       stake_account: PublicKey,
-                       stake_pool: PublicKey,
-                       owner: PublicKey,
-                       destination_token: PublicKey,
-                       spl_token_program: PublicKey,
-                       vault: PublicKey,
-                       ) -> TransactionInstruction:
-        data = self.serialize(
-        )
-        keys: List[AccountMeta] = []
-        keys.append(AccountMeta(stake_account,
-                                False, True))
-        keys.append(AccountMeta(stake_pool,
-                                False, True))
-        keys.append(AccountMeta(owner,
-                                True, False))
-        keys.append(AccountMeta(destination_token,
-                                False, True))
-        keys.append(AccountMeta(spl_token_program,
-                                False, False))
-        keys.append(AccountMeta(vault,
                                 False, True))
+        if bounty_destination is not None:
+            keys.append(AccountMeta(bounty_destination,
+                                    False, True))
+        if mint is not None:
+            keys.append(AccountMeta(mint,
+                                    False, True))
+        if spl_token_program is not None:
+            keys.append(AccountMeta(spl_token_program,
+                                    False, False))
         return TransactionInstruction(keys, programId, data)
 
 
 class ClaimBondInstruction:
     schema = CStruct(
         "tag" / U8,
+        "has_quote_mint" / Bool,
     )
 
     def serialize(self,
+                  has_quote_mint: bool,
                   ) -> str:
         return self.schema.build({
-            "tag": 16,
+            "tag": 15,
+            "has_quote_mint": has_quote_mint,
         })
 
     def getInstruction(self,
@@ -506,8 +600,13 @@ class ClaimBondInstruction:
                        pool_vault: PublicKey,
                        central_state: PublicKey,
                        spl_token_program: PublicKey,
+                       quote_mint: Optional[PublicKey],
+                       quote_token_program: Optional[PublicKey],
+                       user_index: Optional[PublicKey],
+                       has_quote_mint: bool,
                        ) -> TransactionInstruction:
         data = self.serialize(
+            has_quote_mint,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(bond_account,
@@ -528,6 +627,15 @@ class ClaimBondInstruction:
                                 False, True))
         keys.append(AccountMeta(spl_token_program,
                                 False, False))
+        if quote_mint is not None:
+            keys.append(AccountMeta(quote_mint,
+                                    False, False))
+        if quote_token_program is not None:
+            keys.append(AccountMeta(quote_token_program,
+                                    False, False))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -545,7 +653,7 @@ class CreateStakeAccountInstruction:
         return self.schema.build({
             "tag": 3,
             "nonce": nonce,
-            "owner": owner,
+            "owner": bytes(owner),
         })
 
     def getInstruction(self,
@@ -554,6 +662,8 @@ class CreateStakeAccountInstruction:
                        system_program: PublicKey,
                        stake_pool: PublicKey,
                        fee_payer: PublicKey,
+                       pool_membership: Optional[PublicKey],
+                       user_index: Optional[PublicKey],
                        nonce: int,
                        owner: PublicKey,
                        ) -> TransactionInstruction:
@@ -570,6 +680,12 @@ class CreateStakeAccountInstruction:
                                 False, False))
         keys.append(AccountMeta(fee_payer,
                                 True, True))
+        if pool_membership is not None:
+            keys.append(AccountMeta(pool_membership,
+                                    False, False))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -581,7 +697,7 @@ class ClaimBondRewardsInstruction:
     def serialize(self,
                   ) -> str:
         return self.schema.build({
-            "tag": 17,
+            "tag": 16,
         })
 
     def getInstruction(self,
@@ -626,6 +742,8 @@ class CreateBondInstruction:
         "unlock_period" / I64,
         "unlock_amount" / U64,
         "seller_index" / U64,
+        "vesting" / Option(VestingSchedule),
+        "expiry_date" / I64,
     )
 
     def serialize(self,
@@ -638,18 +756,22 @@ class CreateBondInstruction:
                   unlock_period: int,
                   unlock_amount: int,
                   seller_index: int,
+                  vesting: Optional[dict],
+                  expiry_date: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 13,
-            "buyer": buyer,
+            "tag": 12,
+            "buyer": bytes(buyer),
             "total_amount_sold": total_amount_sold,
             "total_quote_amount": total_quote_amount,
-            "quote_mint": quote_mint,
-            "seller_token_account": seller_token_account,
+            "quote_mint": bytes(quote_mint),
+            "seller_token_account": bytes(seller_token_account),
             "unlock_start_date": unlock_start_date,
             "unlock_period": unlock_period,
             "unlock_amount": unlock_amount,
             "seller_index": seller_index,
+            "vesting": vesting,
+            "expiry_date": expiry_date,
         })
 
     def getInstruction(self,
@@ -668,6 +790,8 @@ class CreateBondInstruction:
                        unlock_period: int,
                        unlock_amount: int,
                        seller_index: int,
+                       vesting: Optional[dict],
+                       expiry_date: int,
                        ) -> TransactionInstruction:
         data = self.serialize(
             buyer,
@@ -679,6 +803,8 @@ class CreateBondInstruction:
             unlock_period,
             unlock_amount,
             seller_index,
+            vesting,
+            expiry_date,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(seller,
@@ -698,14 +824,23 @@ class UnstakeInstruction:
     schema = CStruct(
         "tag" / U8,
         "amount" / U64,
+        "has_bond_account" / Bool,
+        "has_mint" / Bool,
+        "has_receipt" / Bool,
     )
 
     def serialize(self,
                   amount: int,
+                  has_bond_account: bool,
+                  has_mint: bool,
+                  has_receipt: bool,
                   ) -> str:
         return self.schema.build({
             "tag": 5,
             "amount": amount,
+            "has_bond_account": has_bond_account,
+            "has_mint": has_mint,
+            "has_receipt": has_receipt,
         })
 
     def getInstruction(self,
@@ -714,10 +849,25 @@ class UnstakeInstruction:
                        stake_account: PublicKey,
                        stake_pool: PublicKey,
                        owner: PublicKey,
+                       destination_token: PublicKey,
+                       spl_token_program: PublicKey,
+                       vault: PublicKey,
+                       bond_account: Optional[PublicKey],
+                       mint: Optional[PublicKey],
+                       receipt_mint: Optional[PublicKey],
+                       receipt_token: Optional[PublicKey],
+                       receipt_token_program: Optional[PublicKey],
+                       campaign_positions: List[PublicKey],
                        amount: int,
+                       has_bond_account: bool,
+                       has_mint: bool,
+                       has_receipt: bool,
                        ) -> TransactionInstruction:
         data = self.serialize(
             amount,
+            has_bond_account,
+            has_mint,
+            has_receipt,
         )
         keys: List[AccountMeta] = []
         keys.append(AccountMeta(central_state_account,
@@ -728,6 +878,30 @@ class UnstakeInstruction:
                                 False, True))
         keys.append(AccountMeta(owner,
                                 True, False))
+        keys.append(AccountMeta(destination_token,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(vault,
+                                False, True))
+        if bond_account is not None:
+            keys.append(AccountMeta(bond_account,
+                                    False, False))
+        if mint is not None:
+            keys.append(AccountMeta(mint,
+                                    False, False))
+        if receipt_mint is not None:
+            keys.append(AccountMeta(receipt_mint,
+                                    False, True))
+        if receipt_token is not None:
+            keys.append(AccountMeta(receipt_token,
+                                    False, True))
+        if receipt_token_program is not None:
+            keys.append(AccountMeta(receipt_token_program,
+                                    False, False))
+        for k in campaign_positions:
+            keys.append(AccountMeta(k,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -744,7 +918,7 @@ class CreateStakePoolInstruction:
                   ) -> str:
         return self.schema.build({
             "tag": 1,
-            "owner": owner,
+            "owner": bytes(owner),
             "minimum_stake_amount": minimum_stake_amount,
         })
 
@@ -783,7 +957,7 @@ class AdminMintInstruction:
                   amount: int,
                   ) -> str:
         return self.schema.build({
-            "tag": 19,
+            "tag": 18,
             "amount": amount,
         })
 
@@ -794,6 +968,7 @@ class AdminMintInstruction:
                        access_token_destination: PublicKey,
                        central_state: PublicKey,
                        spl_token_program: PublicKey,
+                       admin_log: Optional[PublicKey],
                        amount: int,
                        ) -> TransactionInstruction:
         data = self.serialize(
@@ -810,6 +985,9 @@ class AdminMintInstruction:
                                 False, False))
         keys.append(AccountMeta(spl_token_program,
                                 False, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
         return TransactionInstruction(keys, programId, data)
 
 
@@ -829,6 +1007,7 @@ class ActivateStakePoolInstruction:
                        authority: PublicKey,
                        stake_pool: PublicKey,
                        central_state: PublicKey,
+                       admin_log: Optional[PublicKey],
                        ) -> TransactionInstruction:
         data = self.serialize(
         )
@@ -839,4 +1018,1942 @@ class ActivateStakePoolInstruction:
                                 False, True))
         keys.append(AccountMeta(central_state,
                                 False, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ChangeCentralStateAuthorityInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "new_authority" / U8[32],
+    )
+
+    def serialize(self,
+                  new_authority: PublicKey,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 21,
+            "new_authority": bytes(new_authority),
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       admin_log: Optional[PublicKey],
+                       new_authority: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            new_authority,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class EditMetadataInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "name" / String,
+        "symbol" / String,
+        "uri" / String,
+    )
+
+    def serialize(self,
+                  name: str,
+                  symbol: str,
+                  uri: str,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 22,
+            "name": name,
+            "symbol": symbol,
+            "uri": uri,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       metadata: PublicKey,
+                       metadata_program: PublicKey,
+                       admin_log: Optional[PublicKey],
+                       name: str,
+                       symbol: str,
+                       uri: str,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            name,
+            symbol,
+            uri,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        keys.append(AccountMeta(metadata,
+                                False, True))
+        keys.append(AccountMeta(metadata_program,
+                                False, False))
+        if admin_log is not None:
+            keys.append(AccountMeta(admin_log,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class SetPoolWeightInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "new_weight" / U64,
+    )
+
+    def serialize(self,
+                  new_weight: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 23,
+            "new_weight": new_weight,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       stake_pool: PublicKey,
+                       new_weight: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            new_weight,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateRewardCampaignInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "daily_rate" / U64,
+        "end_time" / I64,
+    )
+
+    def serialize(self,
+                  daily_rate: int,
+                  end_time: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 24,
+            "daily_rate": daily_rate,
+            "end_time": end_time,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       owner: PublicKey,
+                       reward_campaign: PublicKey,
+                       vault: PublicKey,
+                       mint: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       daily_rate: int,
+                       end_time: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            daily_rate,
+            end_time,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(reward_campaign,
+                                False, True))
+        keys.append(AccountMeta(vault,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class FundRewardCampaignInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "amount" / U64,
+    )
+
+    def serialize(self,
+                  amount: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 25,
+            "amount": amount,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       reward_campaign: PublicKey,
+                       owner: PublicKey,
+                       source_token: PublicKey,
+                       vault: PublicKey,
+                       spl_token_program: PublicKey,
+                       mint: PublicKey,
+                       amount: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            amount,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(reward_campaign,
+                                False, False))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(source_token,
+                                False, True))
+        keys.append(AccountMeta(vault,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ExtendRewardCampaignInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "new_end_time" / I64,
+        "new_daily_rate" / U64,
+    )
+
+    def serialize(self,
+                  new_end_time: int,
+                  new_daily_rate: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 26,
+            "new_end_time": new_end_time,
+            "new_daily_rate": new_daily_rate,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       reward_campaign: PublicKey,
+                       stake_pool: PublicKey,
+                       owner: PublicKey,
+                       vault: PublicKey,
+                       new_end_time: int,
+                       new_daily_rate: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            new_end_time,
+            new_daily_rate,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(reward_campaign,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(vault,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ReclaimCampaignRewardsInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 27,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       reward_campaign: PublicKey,
+                       stake_pool: PublicKey,
+                       owner: PublicKey,
+                       vault: PublicKey,
+                       destination_token: PublicKey,
+                       spl_token_program: PublicKey,
+                       mint: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(reward_campaign,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(vault,
+                                False, True))
+        keys.append(AccountMeta(destination_token,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ClaimCampaignRewardsInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 28,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       reward_campaign: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_account: PublicKey,
+                       owner: PublicKey,
+                       campaign_position: PublicKey,
+                       vault: PublicKey,
+                       rewards_destination: PublicKey,
+                       spl_token_program: PublicKey,
+                       mint: PublicKey,
+                       system_program: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(reward_campaign,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_account,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        keys.append(AccountMeta(campaign_position,
+                                False, True))
+        keys.append(AccountMeta(vault,
+                                False, True))
+        keys.append(AccountMeta(rewards_destination,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ChangeCrankBountyInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "crank_bounty" / U64,
+    )
+
+    def serialize(self,
+                  crank_bounty: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 29,
+            "crank_bounty": crank_bounty,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       crank_bounty: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            crank_bounty,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class GetClaimableRewardsInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 30,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_account: PublicKey,
+                       central_state: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_account,
+                                False, False))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class GetPoolClaimableRewardsInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 31,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       central_state: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class GetBondUnlockableInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 32,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_account: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_account,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class HasAccessInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 33,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_account: PublicKey,
+                       bond_account: Optional[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_account,
+                                False, False))
+        if bond_account is not None:
+            keys.append(AccountMeta(bond_account,
+                                    False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class MintReceiptInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "uri" / String,
+    )
+
+    def serialize(self,
+                  uri: str,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 34,
+            "uri": uri,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_account: PublicKey,
+                       owner: PublicKey,
+                       receipt_mint: PublicKey,
+                       receipt_token: PublicKey,
+                       metadata: PublicKey,
+                       metadata_program: PublicKey,
+                       spl_token_program: PublicKey,
+                       associated_token_program: PublicKey,
+                       system_program: PublicKey,
+                       rent_sysvar: PublicKey,
+                       uri: str,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            uri,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_account,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        keys.append(AccountMeta(receipt_mint,
+                                False, True))
+        keys.append(AccountMeta(receipt_token,
+                                False, True))
+        keys.append(AccountMeta(metadata,
+                                False, True))
+        keys.append(AccountMeta(metadata_program,
+                                False, False))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(associated_token_program,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(rent_sysvar,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class BurnReceiptInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 35,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_account: PublicKey,
+                       receipt_mint: PublicKey,
+                       receipt_token: PublicKey,
+                       spl_token_program: PublicKey,
+                       owner: Optional[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_account,
+                                False, True))
+        keys.append(AccountMeta(receipt_mint,
+                                False, True))
+        keys.append(AccountMeta(receipt_token,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        if owner is not None:
+            keys.append(AccountMeta(owner,
+                                    True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateAccessDelegationInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "members" / Vec(U8[32]),
+    )
+
+    def serialize(self,
+                  members: List[PublicKey],
+                  ) -> str:
+        return self.schema.build({
+            "tag": 36,
+            "members": [bytes(k) for k in members],
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_account: PublicKey,
+                       access_delegation: PublicKey,
+                       owner: PublicKey,
+                       system_program: PublicKey,
+                       members: List[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            members,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_account,
+                                False, False))
+        keys.append(AccountMeta(access_delegation,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class EditAccessDelegationInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "members" / Vec(U8[32]),
+    )
+
+    def serialize(self,
+                  members: List[PublicKey],
+                  ) -> str:
+        return self.schema.build({
+            "tag": 37,
+            "members": [bytes(k) for k in members],
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       access_delegation: PublicKey,
+                       owner: PublicKey,
+                       members: List[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            members,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(access_delegation,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CloseAccessDelegationInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 38,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       access_delegation: PublicKey,
+                       owner: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(access_delegation,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class SetPoolAllowlistInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "allowlisted" / Bool,
+    )
+
+    def serialize(self,
+                  allowlisted: bool,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 39,
+            "allowlisted": allowlisted,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_pool_owner: PublicKey,
+                       allowlisted: bool,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            allowlisted,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(stake_pool_owner,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class AddPoolMemberInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "member" / U8[32],
+    )
+
+    def serialize(self,
+                  member: PublicKey,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 40,
+            "member": bytes(member),
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_pool_owner: PublicKey,
+                       pool_membership: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       member: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            member,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_pool_owner,
+                                True, False))
+        keys.append(AccountMeta(pool_membership,
+                                False, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class RemovePoolMemberInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 41,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       stake_pool_owner: PublicKey,
+                       pool_membership: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(stake_pool_owner,
+                                True, True))
+        keys.append(AccountMeta(pool_membership,
+                                False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ChangeReferralRatesInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "referral_fee_rate" / U64,
+        "referral_reward_share" / U64,
+    )
+
+    def serialize(self,
+                  referral_fee_rate: int,
+                  referral_reward_share: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 42,
+            "referral_fee_rate": referral_fee_rate,
+            "referral_reward_share": referral_reward_share,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       referral_fee_rate: int,
+                       referral_reward_share: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            referral_fee_rate,
+            referral_reward_share,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class TransferBondInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "new_owner" / U8[32],
+    )
+
+    def serialize(self,
+                  new_owner: PublicKey,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 43,
+            "new_owner": bytes(new_owner),
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_account: PublicKey,
+                       bond_owner: PublicKey,
+                       stake_pool: PublicKey,
+                       central_state: PublicKey,
+                       mint: PublicKey,
+                       rewards_destination: PublicKey,
+                       spl_token_program: PublicKey,
+                       user_indexes: List[PublicKey],
+                       new_owner: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            new_owner,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(bond_owner,
+                                True, False))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, True))
+        keys.append(AccountMeta(rewards_destination,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        for k in user_indexes:
+            keys.append(AccountMeta(k,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ListBondInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "price" / U64,
+    )
+
+    def serialize(self,
+                  price: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 44,
+            "price": price,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_account: PublicKey,
+                       seller: PublicKey,
+                       bond_listing: PublicKey,
+                       seller_token_account: PublicKey,
+                       system_program: PublicKey,
+                       price: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            price,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(seller,
+                                True, True))
+        keys.append(AccountMeta(bond_listing,
+                                False, True))
+        keys.append(AccountMeta(seller_token_account,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CancelBondListingInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 45,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_listing: PublicKey,
+                       bond_account: PublicKey,
+                       seller: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_listing,
+                                False, True))
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(seller,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class FillBondListingInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 46,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_listing: PublicKey,
+                       bond_account: PublicKey,
+                       buyer: PublicKey,
+                       buyer_token_account: PublicKey,
+                       seller: PublicKey,
+                       seller_token_account: PublicKey,
+                       seller_rewards_destination: PublicKey,
+                       stake_pool: PublicKey,
+                       central_state: PublicKey,
+                       mint: PublicKey,
+                       spl_token_program: PublicKey,
+                       quote_mint: PublicKey,
+                       quote_token_program: PublicKey,
+                       user_indexes: List[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_listing,
+                                False, True))
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(buyer,
+                                True, False))
+        keys.append(AccountMeta(buyer_token_account,
+                                False, True))
+        keys.append(AccountMeta(seller,
+                                False, True))
+        keys.append(AccountMeta(seller_token_account,
+                                False, True))
+        keys.append(AccountMeta(seller_rewards_destination,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(quote_mint,
+                                False, False))
+        keys.append(AccountMeta(quote_token_program,
+                                False, False))
+        for k in user_indexes:
+            keys.append(AccountMeta(k,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CancelBondInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "seller_index" / U64,
+    )
+
+    def serialize(self,
+                  seller_index: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 47,
+            "seller_index": seller_index,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       seller: PublicKey,
+                       bond_account: PublicKey,
+                       fee_payer: PublicKey,
+                       user_index: Optional[PublicKey],
+                       seller_index: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            seller_index,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(seller,
+                                True, False))
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(fee_payer,
+                                False, True))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ChangeEarlyUnlockPenaltyInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "early_unlock_penalty" / U64,
+    )
+
+    def serialize(self,
+                  early_unlock_penalty: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 48,
+            "early_unlock_penalty": early_unlock_penalty,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       early_unlock_penalty: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            early_unlock_penalty,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class EarlyUnlockBondInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "amount" / U64,
+    )
+
+    def serialize(self,
+                  amount: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 49,
+            "amount": amount,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_account: PublicKey,
+                       bond_owner: PublicKey,
+                       mint: PublicKey,
+                       access_token_destination: PublicKey,
+                       fee_account: PublicKey,
+                       central_state: PublicKey,
+                       stake_pool: PublicKey,
+                       pool_vault: PublicKey,
+                       spl_token_program: PublicKey,
+                       amount: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            amount,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(bond_owner,
+                                True, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        keys.append(AccountMeta(access_token_destination,
+                                False, True))
+        keys.append(AccountMeta(fee_account,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(pool_vault,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateBondAuctionInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "total_amount" / U64,
+        "quote_mint" / U8[32],
+        "seller_token_account" / U8[32],
+        "start_price" / U64,
+        "floor_price" / U64,
+        "start_time" / I64,
+        "end_time" / I64,
+        "unlock_start_date" / I64,
+        "unlock_period" / I64,
+        "unlock_periods" / U64,
+        "vesting" / Option(VestingSchedule),
+        "seller_index" / U64,
+    )
+
+    def serialize(self,
+                  total_amount: int,
+                  quote_mint: PublicKey,
+                  seller_token_account: PublicKey,
+                  start_price: int,
+                  floor_price: int,
+                  start_time: int,
+                  end_time: int,
+                  unlock_start_date: int,
+                  unlock_period: int,
+                  unlock_periods: int,
+                  vesting: Optional[dict],
+                  seller_index: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 50,
+            "total_amount": total_amount,
+            "quote_mint": bytes(quote_mint),
+            "seller_token_account": bytes(seller_token_account),
+            "start_price": start_price,
+            "floor_price": floor_price,
+            "start_time": start_time,
+            "end_time": end_time,
+            "unlock_start_date": unlock_start_date,
+            "unlock_period": unlock_period,
+            "unlock_periods": unlock_periods,
+            "vesting": vesting,
+            "seller_index": seller_index,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       seller: PublicKey,
+                       bond_auction: PublicKey,
+                       stake_pool: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       total_amount: int,
+                       quote_mint: PublicKey,
+                       seller_token_account: PublicKey,
+                       start_price: int,
+                       floor_price: int,
+                       start_time: int,
+                       end_time: int,
+                       unlock_start_date: int,
+                       unlock_period: int,
+                       unlock_periods: int,
+                       vesting: Optional[dict],
+                       seller_index: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            total_amount,
+            quote_mint,
+            seller_token_account,
+            start_price,
+            floor_price,
+            start_time,
+            end_time,
+            unlock_start_date,
+            unlock_period,
+            unlock_periods,
+            vesting,
+            seller_index,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(seller,
+                                True, False))
+        keys.append(AccountMeta(bond_auction,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class FillBondAuctionInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "amount" / U64,
+        "max_quote_amount" / U64,
+    )
+
+    def serialize(self,
+                  amount: int,
+                  max_quote_amount: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 51,
+            "amount": amount,
+            "max_quote_amount": max_quote_amount,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_auction: PublicKey,
+                       bond_account: PublicKey,
+                       buyer: PublicKey,
+                       quote_token_source: PublicKey,
+                       quote_token_destination: PublicKey,
+                       stake_pool: PublicKey,
+                       access_mint: PublicKey,
+                       pool_vault: PublicKey,
+                       central_state: PublicKey,
+                       spl_token_program: PublicKey,
+                       quote_mint: PublicKey,
+                       quote_token_program: PublicKey,
+                       system_program: PublicKey,
+                       user_index: Optional[PublicKey],
+                       amount: int,
+                       max_quote_amount: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            amount,
+            max_quote_amount,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_auction,
+                                False, True))
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(buyer,
+                                True, True))
+        keys.append(AccountMeta(quote_token_source,
+                                False, True))
+        keys.append(AccountMeta(quote_token_destination,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(access_mint,
+                                False, True))
+        keys.append(AccountMeta(pool_vault,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(quote_mint,
+                                False, False))
+        keys.append(AccountMeta(quote_token_program,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class MoveBondStakeInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 52,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_account: PublicKey,
+                       bond_owner: PublicKey,
+                       source_stake_pool: PublicKey,
+                       source_pool_vault: PublicKey,
+                       destination_stake_pool: PublicKey,
+                       destination_pool_vault: PublicKey,
+                       central_state: PublicKey,
+                       spl_token_program: PublicKey,
+                       mint: PublicKey,
+                       pool_membership: Optional[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(bond_owner,
+                                True, False))
+        keys.append(AccountMeta(source_stake_pool,
+                                False, True))
+        keys.append(AccountMeta(source_pool_vault,
+                                False, True))
+        keys.append(AccountMeta(destination_stake_pool,
+                                False, True))
+        keys.append(AccountMeta(destination_pool_vault,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        if pool_membership is not None:
+            keys.append(AccountMeta(pool_membership,
+                                    False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateBondRoundInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "merkle_root" / U8[32],
+        "quote_mint" / U8[32],
+        "seller_token_account" / U8[32],
+        "unlock_start_date" / I64,
+        "unlock_period" / I64,
+        "unlock_periods" / U64,
+        "seller_index" / U64,
+        "num_nodes" / U64,
+    )
+
+    def serialize(self,
+                  merkle_root: bytes,
+                  quote_mint: PublicKey,
+                  seller_token_account: PublicKey,
+                  unlock_start_date: int,
+                  unlock_period: int,
+                  unlock_periods: int,
+                  seller_index: int,
+                  num_nodes: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 53,
+            "merkle_root": merkle_root,
+            "quote_mint": bytes(quote_mint),
+            "seller_token_account": bytes(seller_token_account),
+            "unlock_start_date": unlock_start_date,
+            "unlock_period": unlock_period,
+            "unlock_periods": unlock_periods,
+            "seller_index": seller_index,
+            "num_nodes": num_nodes,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       seller: PublicKey,
+                       bond_round: PublicKey,
+                       stake_pool: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       merkle_root: bytes,
+                       quote_mint: PublicKey,
+                       seller_token_account: PublicKey,
+                       unlock_start_date: int,
+                       unlock_period: int,
+                       unlock_periods: int,
+                       seller_index: int,
+                       num_nodes: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            merkle_root,
+            quote_mint,
+            seller_token_account,
+            unlock_start_date,
+            unlock_period,
+            unlock_periods,
+            seller_index,
+            num_nodes,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(seller,
+                                True, False))
+        keys.append(AccountMeta(bond_round,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class SignBondRoundInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "seller_index" / U64,
+    )
+
+    def serialize(self,
+                  seller_index: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 54,
+            "seller_index": seller_index,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       seller: PublicKey,
+                       bond_round: PublicKey,
+                       seller_index: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            seller_index,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(seller,
+                                True, False))
+        keys.append(AccountMeta(bond_round,
+                                False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ClaimRoundBondInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "index" / U64,
+        "total_amount_sold" / U64,
+        "total_quote_amount" / U64,
+        "proof" / Vec(U8[32]),
+    )
+
+    def serialize(self,
+                  index: int,
+                  total_amount_sold: int,
+                  total_quote_amount: int,
+                  proof: List[bytes],
+                  ) -> str:
+        return self.schema.build({
+            "tag": 55,
+            "index": index,
+            "total_amount_sold": total_amount_sold,
+            "total_quote_amount": total_quote_amount,
+            "proof": proof,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       bond_round: PublicKey,
+                       bond_account: PublicKey,
+                       buyer: PublicKey,
+                       quote_token_source: PublicKey,
+                       quote_token_destination: PublicKey,
+                       stake_pool: PublicKey,
+                       access_mint: PublicKey,
+                       pool_vault: PublicKey,
+                       central_state: PublicKey,
+                       spl_token_program: PublicKey,
+                       quote_mint: PublicKey,
+                       quote_token_program: PublicKey,
+                       system_program: PublicKey,
+                       user_index: Optional[PublicKey],
+                       index: int,
+                       total_amount_sold: int,
+                       total_quote_amount: int,
+                       proof: List[bytes],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            index,
+            total_amount_sold,
+            total_quote_amount,
+            proof,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(bond_round,
+                                False, True))
+        keys.append(AccountMeta(bond_account,
+                                False, True))
+        keys.append(AccountMeta(buyer,
+                                True, True))
+        keys.append(AccountMeta(quote_token_source,
+                                False, True))
+        keys.append(AccountMeta(quote_token_destination,
+                                False, True))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(access_mint,
+                                False, True))
+        keys.append(AccountMeta(pool_vault,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(quote_mint,
+                                False, False))
+        keys.append(AccountMeta(quote_token_program,
+                                False, False))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        if user_index is not None:
+            keys.append(AccountMeta(user_index,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateDistributorInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "merkle_root" / U8[32],
+        "max_total_claim" / U64,
+        "num_nodes" / U64,
+    )
+
+    def serialize(self,
+                  merkle_root: bytes,
+                  max_total_claim: int,
+                  num_nodes: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 56,
+            "merkle_root": merkle_root,
+            "max_total_claim": max_total_claim,
+            "num_nodes": num_nodes,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       distributor: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       merkle_root: bytes,
+                       max_total_claim: int,
+                       num_nodes: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            merkle_root,
+            max_total_claim,
+            num_nodes,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        keys.append(AccountMeta(distributor,
+                                False, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ClaimDistributionInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "index" / U64,
+        "amount" / U64,
+        "proof" / Vec(U8[32]),
+    )
+
+    def serialize(self,
+                  index: int,
+                  amount: int,
+                  proof: List[bytes],
+                  ) -> str:
+        return self.schema.build({
+            "tag": 57,
+            "index": index,
+            "amount": amount,
+            "proof": proof,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       distributor: PublicKey,
+                       recipient: PublicKey,
+                       recipient_token_account: PublicKey,
+                       central_state: PublicKey,
+                       mint: PublicKey,
+                       spl_token_program: PublicKey,
+                       index: int,
+                       amount: int,
+                       proof: List[bytes],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            index,
+            amount,
+            proof,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(distributor,
+                                False, True))
+        keys.append(AccountMeta(recipient,
+                                False, False))
+        keys.append(AccountMeta(recipient_token_account,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ClaimMultipleRewardsInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 58,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       owner: PublicKey,
+                       rewards_destination: PublicKey,
+                       central_state: PublicKey,
+                       mint: PublicKey,
+                       spl_token_program: PublicKey,
+                       claim_accounts: List[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(rewards_destination,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, True))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        for k in claim_accounts:
+            keys.append(AccountMeta(k,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateUserIndexInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 59,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       user_index: PublicKey,
+                       owner: PublicKey,
+                       system_program: PublicKey,
+                       positions: List[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(user_index,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        for k in positions:
+            keys.append(AccountMeta(k,
+                                    False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CloseUserIndexInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 60,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       user_index: PublicKey,
+                       owner: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(user_index,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class DeprecateStakePoolInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 61,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       stake_pool: PublicKey,
+                       freeze_record: PublicKey,
+                       fee_payer: Optional[PublicKey],
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(freeze_record,
+                                False, True))
+        if fee_payer is not None:
+            keys.append(AccountMeta(fee_payer,
+                                    False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ChangeActivationDepositInstruction:
+    schema = CStruct(
+        "tag" / U8,
+        "activation_deposit" / U64,
+    )
+
+    def serialize(self,
+                  activation_deposit: int,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 62,
+            "activation_deposit": activation_deposit,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       activation_deposit: int,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+            activation_deposit,
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class SelfActivateStakePoolInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 63,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       stake_pool: PublicKey,
+                       owner: PublicKey,
+                       source_token: PublicKey,
+                       pool_vault: PublicKey,
+                       central_state: PublicKey,
+                       spl_token_program: PublicKey,
+                       mint: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(stake_pool,
+                                False, True))
+        keys.append(AccountMeta(owner,
+                                True, False))
+        keys.append(AccountMeta(source_token,
+                                False, True))
+        keys.append(AccountMeta(pool_vault,
+                                False, True))
+        keys.append(AccountMeta(central_state,
+                                False, False))
+        keys.append(AccountMeta(spl_token_program,
+                                False, False))
+        keys.append(AccountMeta(mint,
+                                False, False))
+        return TransactionInstruction(keys, programId, data)
+
+
+class CreateAdminLogInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 64,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       authority: PublicKey,
+                       admin_log: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(authority,
+                                True, False))
+        keys.append(AccountMeta(admin_log,
+                                False, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class ExpireFreezeInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 65,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       frozen_account: PublicKey,
+                       freeze_record: PublicKey,
+                       fee_payer: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(frozen_account,
+                                False, True))
+        keys.append(AccountMeta(freeze_record,
+                                False, True))
+        keys.append(AccountMeta(fee_payer,
+                                False, True))
+        return TransactionInstruction(keys, programId, data)
+
+
+class MigrateAccountInstruction:
+    schema = CStruct(
+        "tag" / U8,
+    )
+
+    def serialize(self,
+                  ) -> str:
+        return self.schema.build({
+            "tag": 66,
+        })
+
+    def getInstruction(self,
+                       programId: PublicKey,
+                       central_state: PublicKey,
+                       account: PublicKey,
+                       system_program: PublicKey,
+                       fee_payer: PublicKey,
+                       ) -> TransactionInstruction:
+        data = self.serialize(
+        )
+        keys: List[AccountMeta] = []
+        keys.append(AccountMeta(central_state,
+                                False, True))
+        keys.append(AccountMeta(account,
+                                False, True))
+        keys.append(AccountMeta(system_program,
+                                False, False))
+        keys.append(AccountMeta(fee_payer,
+                                True, True))
         return TransactionInstruction(keys, programId, data)